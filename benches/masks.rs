@@ -0,0 +1,192 @@
+//! Visited sets as `HashSet`s against grid masks, on the workloads the days run.
+//!
+//! Run with `cargo bench --bench masks`. Uses the real inputs when they can be read and the
+//! examples otherwise.
+
+use advent_of_code::{
+    budget::Budget,
+    days::{self, day06},
+    fixtures,
+    grid::mask::{GridMask, LayeredMask},
+    simulation,
+};
+use std::{
+    collections::{HashSet, VecDeque},
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+const SAMPLES: usize = 5;
+
+/// Median of `SAMPLES` runs.
+fn bench<T>(name: &str, mut f: impl FnMut() -> T) -> Duration {
+    let mut times: Vec<Duration> = (0..SAMPLES)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    times.sort();
+    let median = times[SAMPLES / 2];
+    println!("  {name:<28} {median:>12.2?}");
+    median
+}
+
+fn compare(title: &str, hash_set: impl FnMut() -> usize, mask: impl FnMut() -> usize) {
+    println!("{title}");
+    let hash_set = bench("HashSet", hash_set);
+    let mask = bench("mask", mask);
+    println!(
+        "  {:<28} {:>11.1}x\n",
+        "speedup",
+        hash_set.as_secs_f64() / mask.as_secs_f64()
+    );
+}
+
+fn input(day: u8) -> String {
+    fixtures::input_or_example(days::get(day).unwrap()).unwrap()
+}
+
+fn grid(input: &str) -> Vec<Vec<char>> {
+    input.lines().map(|line| line.chars().collect()).collect()
+}
+
+/// Splits the grid into regions of equal cells, like day 12, returning how many there are.
+fn regions(grid: &[Vec<char>], mut visit: impl FnMut(usize, usize) -> bool) -> usize {
+    let mut regions = 0;
+    let mut queue = VecDeque::new();
+    for (y, row) in grid.iter().enumerate() {
+        for (x, &plant) in row.iter().enumerate() {
+            if !visit(x, y) {
+                continue;
+            }
+            regions += 1;
+            queue.push_back((x, y));
+            while let Some((x, y)) = queue.pop_front() {
+                let neighbors = [
+                    (x.wrapping_sub(1), y),
+                    (x + 1, y),
+                    (x, y.wrapping_sub(1)),
+                    (x, y + 1),
+                ];
+                for (nx, ny) in neighbors {
+                    let same = grid.get(ny).and_then(|row| row.get(nx)) == Some(&plant);
+                    if same && visit(nx, ny) {
+                        queue.push_back((nx, ny));
+                    }
+                }
+            }
+        }
+    }
+    regions
+}
+
+fn flood_fill() {
+    let grid = grid(&input(12));
+    compare(
+        "flood fill (day 12 regions)",
+        || {
+            let mut visited = HashSet::new();
+            regions(&grid, |x, y| visited.insert((x, y)))
+        },
+        || {
+            let mut visited = GridMask::for_grid(&grid);
+            regions(&grid, |x, y| visited.insert(x, y))
+        },
+    );
+}
+
+/// Every candidate obstacle of day 6 part 2, with loops found by `detect`.
+fn guard_loops(
+    map: &day06::map::Map,
+    mut detect: impl FnMut(&day06::guard::Patrol) -> bool,
+) -> usize {
+    use day06::{guard::Position, map::Element};
+
+    let (i, j, direction) = map.guard().unwrap();
+    let start = Position::Map(i, j);
+
+    let mut loops = 0;
+    for (i, row) in map.0.iter().enumerate() {
+        for (j, el) in row.iter().enumerate() {
+            let patrol = day06::guard::Patrol {
+                map,
+                obstacle: Some((i, j)),
+                position: start.clone(),
+                direction,
+            };
+            if *el == Element::Empty && detect(&patrol) {
+                loops += 1;
+            }
+        }
+    }
+    loops
+}
+
+fn loop_detection() {
+    use advent_of_code::simulation::Simulation;
+    use day06::guard::Position;
+
+    let map: day06::map::Map = input(6).parse().unwrap();
+    let budget = Budget::unlimited();
+    println!("loop detection (day 6 part 2)");
+    let hash_set = bench("HashSet of (cell, heading)", || {
+        guard_loops(&map, |patrol| {
+            let mut patrol = patrol.clone();
+            let mut seen = HashSet::new();
+            while let Position::Map(i, j) = patrol.position {
+                if !seen.insert((i, j, patrol.direction)) {
+                    return true;
+                }
+                patrol.step();
+            }
+            false
+        })
+    });
+    let brent = bench("Brent's cycle detection", || {
+        guard_loops(&map, |patrol| {
+            simulation::brent(patrol, &budget).unwrap().is_some()
+        })
+    });
+    let mut seen: LayeredMask<4> = LayeredMask::for_grid(&map.0);
+    let mask = bench("LayeredMask<4>", || {
+        guard_loops(&map, |patrol| {
+            let mut patrol = patrol.clone();
+            seen.clear();
+            while let Position::Map(i, j) = patrol.position {
+                if !seen.insert(j, i, patrol.direction as usize) {
+                    return true;
+                }
+                patrol.step();
+            }
+            false
+        })
+    });
+    println!(
+        "  {:<28} {:>11.1}x over HashSet, {:.1}x over Brent\n",
+        "speedup",
+        hash_set.as_secs_f64() / mask.as_secs_f64(),
+        brent.as_secs_f64() / mask.as_secs_f64()
+    );
+}
+
+fn days() {
+    println!("days using masks");
+    for number in [6, 8, 10, 12, 16] {
+        let day = days::get(number).unwrap();
+        let input = input(number);
+        let budget = Budget::unlimited();
+        for (part, solve) in day.parts() {
+            bench(&format!("day {number:02} part {part}"), || {
+                solve(&input, &budget).unwrap()
+            });
+        }
+    }
+}
+
+fn main() {
+    flood_fill();
+    loop_detection();
+    days();
+}
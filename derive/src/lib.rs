@@ -0,0 +1,285 @@
+//! `#[derive(GridCell)]` for the enums the days keep in their grids.
+//!
+//! Unit variants name their character with `#[cell('#')]`. A variant holding one value, like
+//! `Antenna(AntennaId)`, leaves rendering and parsing to that value, which must implement
+//! `GridCell` and `FromStr`. The derive implements `GridCell`, `Display` and `FromStr` for the
+//! enum, and adds a `Parse<Enum>Error(pub String)` next to it holding the rejected text.
+//!
+//! Parsing tries the `#[cell]` characters first, then the values in declaration order. The
+//! generated code names the trait as `crate::grid::GridCell`, so it only works inside the
+//! `advent_of_code` crate.
+//!
+//! There is no `syn` here; the input is walked token by token, which is enough for plain enums.
+
+use proc_macro::{Delimiter, TokenStream, TokenTree};
+
+#[proc_macro_derive(GridCell, attributes(cell))]
+pub fn derive_grid_cell(input: TokenStream) -> TokenStream {
+    let code = match parse_enum(input) {
+        Ok(cell_enum) => generate(&cell_enum),
+        Err(message) => format!("compile_error!({message:?});"),
+    };
+    code.parse().expect("generated code should be valid tokens")
+}
+
+struct Enum {
+    vis: String,
+    name: String,
+    variants: Vec<Variant>,
+}
+
+enum Variant {
+    Unit { name: String, cell: char },
+    Value { name: String, field: String },
+}
+
+fn parse_enum(input: TokenStream) -> Result<Enum, String> {
+    let mut tokens = input.into_iter().peekable();
+    let mut vis = String::new();
+
+    let name = loop {
+        match tokens.next() {
+            // Attributes on the enum itself, including its doc comment.
+            Some(TokenTree::Punct(punct)) if punct.as_char() == '#' => {
+                tokens.next();
+            }
+            Some(TokenTree::Ident(ident)) if ident.to_string() == "pub" => {
+                vis = "pub".to_owned();
+                if let Some(TokenTree::Group(group)) = tokens.peek() {
+                    if group.delimiter() == Delimiter::Parenthesis {
+                        vis.push_str(&group.to_string());
+                        tokens.next();
+                    }
+                }
+            }
+            Some(TokenTree::Ident(ident)) if ident.to_string() == "enum" => match tokens.next() {
+                Some(TokenTree::Ident(name)) => break name.to_string(),
+                _ => return Err("expected the enum's name".to_owned()),
+            },
+            _ => return Err("GridCell can only be derived for enums".to_owned()),
+        }
+    };
+
+    let body = match tokens.next() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => group.stream(),
+        _ => {
+            return Err(format!(
+                "GridCell can not be derived for generic enums like {name}"
+            ))
+        }
+    };
+
+    let variants = split_commas(body)
+        .into_iter()
+        .map(parse_variant)
+        .collect::<Result<Vec<Variant>, String>>()?;
+    if variants.is_empty() {
+        return Err(format!("{name} has no variants to render"));
+    }
+
+    let mut cells: Vec<(char, &str)> = Vec::new();
+    for variant in &variants {
+        if let Variant::Unit { name, cell } = variant {
+            if let Some((_, other)) = cells.iter().find(|(c, _)| c == cell) {
+                return Err(format!("{other} and {name} are both #[cell({cell:?})]"));
+            }
+            cells.push((*cell, name));
+        }
+    }
+
+    Ok(Enum {
+        vis,
+        name,
+        variants,
+    })
+}
+
+/// The comma separated items of a group's contents, skipping a trailing comma.
+fn split_commas(stream: TokenStream) -> Vec<Vec<TokenTree>> {
+    let mut items = vec![Vec::new()];
+    for token in stream {
+        match token {
+            TokenTree::Punct(punct) if punct.as_char() == ',' => items.push(Vec::new()),
+            token => items.last_mut().unwrap().push(token),
+        }
+    }
+    items.retain(|item| !item.is_empty());
+    items
+}
+
+fn parse_variant(tokens: Vec<TokenTree>) -> Result<Variant, String> {
+    let mut tokens = tokens.into_iter();
+    let mut cell = None;
+
+    let name = loop {
+        match tokens.next() {
+            Some(TokenTree::Punct(punct)) if punct.as_char() == '#' => {
+                let Some(TokenTree::Group(attribute)) = tokens.next() else {
+                    return Err("expected an attribute after #".to_owned());
+                };
+                if let Some(c) = cell_attribute(attribute.stream())? {
+                    cell = Some(c);
+                }
+            }
+            Some(TokenTree::Ident(name)) => break name.to_string(),
+            _ => return Err("expected a variant".to_owned()),
+        }
+    };
+
+    match (tokens.next(), cell) {
+        (None, Some(cell)) => Ok(Variant::Unit { name, cell }),
+        (None, None) => Err(format!("{name} needs a #[cell('c')] attribute")),
+        (Some(TokenTree::Group(field)), None)
+            if field.delimiter() == Delimiter::Parenthesis
+                && tokens.next().is_none()
+                && split_commas(field.stream()).len() == 1 =>
+        {
+            Ok(Variant::Value {
+                name,
+                field: field.stream().to_string(),
+            })
+        }
+        (Some(TokenTree::Group(_)), Some(_)) => Err(format!(
+            "{name} holds a value, which renders and parses itself, so it takes no #[cell]"
+        )),
+        _ => Err(format!(
+            "{name} must be a unit variant with #[cell('c')] or hold exactly one value"
+        )),
+    }
+}
+
+/// The character of a `cell('c')` attribute, or `None` for other attributes like doc comments.
+fn cell_attribute(stream: TokenStream) -> Result<Option<char>, String> {
+    let tokens: Vec<TokenTree> = stream.into_iter().collect();
+    match tokens.as_slice() {
+        [TokenTree::Ident(ident), TokenTree::Group(args)] if ident.to_string() == "cell" => {
+            let args: Vec<TokenTree> = args.stream().into_iter().collect();
+            match args.as_slice() {
+                [TokenTree::Literal(literal)] => char_literal(&literal.to_string()).map(Some),
+                _ => Err("#[cell(...)] takes a single char literal like '#'".to_owned()),
+            }
+        }
+        [TokenTree::Ident(ident), ..] if ident.to_string() == "cell" => {
+            Err("expected #[cell('c')]".to_owned())
+        }
+        _ => Ok(None),
+    }
+}
+
+/// The value of a char literal as written in source, e.g. `'#'` or `'\\'`.
+fn char_literal(literal: &str) -> Result<char, String> {
+    let error = || format!("#[cell(...)] takes a char literal like '#', not {literal}");
+    let inner = literal
+        .strip_prefix('\'')
+        .and_then(|rest| rest.strip_suffix('\''))
+        .ok_or_else(error)?;
+
+    let mut chars = inner.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if c != '\\' => Ok(c),
+        (Some('\\'), Some(escape)) => match (escape, chars.as_str()) {
+            ('\\', "") => Ok('\\'),
+            ('\'', "") => Ok('\''),
+            ('"', "") => Ok('"'),
+            ('t', "") => Ok('\t'),
+            ('u', code) => code
+                .strip_prefix('{')
+                .and_then(|code| code.strip_suffix('}'))
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .and_then(char::from_u32)
+                .ok_or_else(error),
+            _ => Err(error()),
+        },
+        _ => Err(error()),
+    }
+}
+
+fn generate(cell_enum: &Enum) -> String {
+    let Enum {
+        vis,
+        name,
+        variants,
+    } = cell_enum;
+    let error = format!("Parse{name}Error");
+
+    let mut to_char = String::new();
+    let mut parse_cells = String::new();
+    let mut parse_values = String::new();
+    for variant in variants {
+        match variant {
+            Variant::Unit {
+                name: variant,
+                cell,
+            } => {
+                to_char.push_str(&format!("Self::{variant} => {cell:?},"));
+                parse_cells.push_str(&format!("{:?} => Ok(Self::{variant}),", cell.to_string()));
+            }
+            Variant::Value {
+                name: variant,
+                field,
+            } => {
+                to_char.push_str(&format!(
+                    "Self::{variant}(value) => crate::grid::GridCell::to_char(value),"
+                ));
+                parse_values.push_str(&format!(
+                    "if let Ok(value) = <{field} as ::std::str::FromStr>::from_str(s) {{
+                        return Ok(Self::{variant}(value));
+                    }}"
+                ));
+            }
+        }
+    }
+
+    format!(
+        "
+        /// A string that is not a single cell character of [`{name}`].
+        #[derive(Debug, Clone, PartialEq)]
+        {vis} struct {error}(pub String);
+
+        #[automatically_derived]
+        impl crate::grid::GridCell for {name} {{
+            fn to_char(&self) -> char {{
+                match self {{ {to_char} }}
+            }}
+        }}
+
+        #[automatically_derived]
+        impl ::std::fmt::Display for {name} {{
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {{
+                ::std::fmt::Write::write_char(f, crate::grid::GridCell::to_char(self))
+            }}
+        }}
+
+        #[automatically_derived]
+        impl ::std::str::FromStr for {name} {{
+            type Err = {error};
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {{
+                match s {{
+                    {parse_cells}
+                    _ => {{
+                        {parse_values}
+                        Err({error}(s.to_owned()))
+                    }}
+                }}
+            }}
+        }}
+        "
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::char_literal;
+
+    #[test]
+    fn char_literals() {
+        assert_eq!(Ok('#'), char_literal("'#'"));
+        assert_eq!(Ok('\\'), char_literal(r"'\\'"));
+        assert_eq!(Ok('\''), char_literal(r"'\''"));
+        assert_eq!(Ok('█'), char_literal(r"'\u{2588}'"));
+        assert!(char_literal("\"#\"").is_err());
+        assert!(char_literal("'ab'").is_err());
+        assert!(char_literal("5").is_err());
+    }
+}
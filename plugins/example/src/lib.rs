@@ -0,0 +1,53 @@
+//! Day 1 as a plugin, solving part 2 by walking both sorted lists instead of counting into a
+//! map. Build it with `cargo build -p aoc_plugin_example`, copy
+//! `target/debug/libaoc_plugin_example.so` into a directory of its own and pass that to
+//! `run --plugins DIR`.
+
+use advent_of_code::days::{Day, Input, PartError};
+
+fn read_lists(input: &str) -> Result<(Vec<u32>, Vec<u32>), String> {
+    let mut left = Vec::new();
+    let mut right = Vec::new();
+    for line in input.lines() {
+        let numbers: Vec<u32> = line
+            .split_whitespace()
+            .map(|n| n.parse().map_err(|err| format!("{err} in {line:?}")))
+            .collect::<Result<_, _>>()?;
+        let [l, r] = numbers[..] else {
+            return Err(format!("expected two numbers in {line:?}"));
+        };
+        left.push(l);
+        right.push(r);
+    }
+    left.sort_unstable();
+    right.sort_unstable();
+    Ok((left, right))
+}
+
+fn similarity(left: &[u32], right: &[u32]) -> u64 {
+    let mut score = 0;
+    let mut j = 0;
+    for &n in left {
+        while right.get(j).is_some_and(|&r| r < n) {
+            j += 1;
+        }
+        let count = right[j..].iter().take_while(|&&r| r == n).count();
+        score += u64::from(n) * count as u64;
+    }
+    score
+}
+
+advent_of_code::export_day!(Day {
+    number: 1,
+    input: Input::File("./files/01/lists.txt"),
+    parse: |input| read_lists(input).map(drop),
+    part1: |input, _| {
+        let (left, right) = read_lists(input).map_err(PartError::InvalidInput)?;
+        let distance: u32 = left.iter().zip(&right).map(|(l, r)| l.abs_diff(*r)).sum();
+        Ok(distance.to_string())
+    },
+    part2: Some(|input, _| {
+        let (left, right) = read_lists(input).map_err(PartError::InvalidInput)?;
+        Ok(similarity(&left, &right).to_string())
+    }),
+});
@@ -0,0 +1,225 @@
+//! Runs one day over a directory of inputs, e.g. one file per team member.
+//!
+//! A file's expected answers can sit next to it in an `answers` sidecar, see
+//! [`Answers::for_input`]. Answers that differ from it are reported as mismatches.
+
+use crate::{
+    days::Day,
+    fixtures::Answers,
+    runner::{self, Limits, Outcome, PartResult},
+    table::Table,
+};
+use std::{
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    time::Instant,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    pub name: String,
+    pub parse: Result<(), String>,
+    pub parts: Vec<(u8, PartResult)>,
+    pub answers: Answers,
+}
+
+impl Entry {
+    /// Parts with a known answer that came out different, or not at all.
+    pub fn mismatches(&self) -> Vec<u8> {
+        self.parts
+            .iter()
+            .filter(|(n, result)| {
+                self.answers.get(*n).is_some_and(|expected| {
+                    !matches!(&result.outcome, Outcome::Answer(answer) if answer == expected)
+                })
+            })
+            .map(|(n, _)| *n)
+            .collect()
+    }
+}
+
+/// Solves every file in `dir` except sidecars and hidden files, sorted by name. A panic in
+/// the parser or a part is reported in that file's row instead of ending the batch.
+pub fn run(day: &Day, dir: &Path, limits: Limits) -> Result<Vec<Entry>, String> {
+    run_with(dir, |input| {
+        let parse = panic::catch_unwind(|| runner::run_parse(day.parse, input).result)
+            .unwrap_or_else(|payload| {
+                Err(format!("panicked: {}", runner::panic_message(&*payload)))
+            });
+        let parts = match parse {
+            Ok(()) => day
+                .parts()
+                .map(|(n, part)| {
+                    let start = Instant::now();
+                    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                        runner::run_part(part, input, limits)
+                    }))
+                    .unwrap_or_else(|payload| PartResult {
+                        outcome: Outcome::Panicked(runner::panic_message(&*payload)),
+                        elapsed: start.elapsed(),
+                        allocations: None,
+                    });
+                    (n, result)
+                })
+                .collect(),
+            Err(_) => Vec::new(),
+        };
+        (parse, parts)
+    })
+}
+
+/// Like [`run`], with `solve` turning each input into its parse result and part results.
+pub fn run_with(
+    dir: &Path,
+    solve: impl Fn(&str) -> (Result<(), String>, Vec<(u8, PartResult)>),
+) -> Result<Vec<Entry>, String> {
+    let entries =
+        std::fs::read_dir(dir).map_err(|err| format!("Could not read {}: {err}", dir.display()))?;
+
+    let mut paths = Vec::new();
+    for entry in entries {
+        let path = entry.map_err(|err| err.to_string())?.path();
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        if path.is_file()
+            && !name.starts_with('.')
+            && path.extension().is_none_or(|ext| ext != "answers")
+        {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    paths
+        .iter()
+        .map(|path| {
+            let input = std::fs::read_to_string(path)
+                .map_err(|err| format!("Could not read {}: {err}", path.display()))?;
+            let (parse, parts) = solve(&input);
+
+            Ok(Entry {
+                name: path.file_name().unwrap().to_string_lossy().into_owned(),
+                parse,
+                parts,
+                answers: Answers::for_input(path)?,
+            })
+        })
+        .collect()
+}
+
+/// One row per file with every part's answer, checked against the sidecar when there is one.
+pub fn table(day: &Day, entries: &[Entry]) -> Table {
+    let mut headers = vec!["file".to_owned()];
+    headers.extend(day.parts().map(|(n, _)| format!("part {n}")));
+    headers.push("time".to_owned());
+
+    let mut table = Table::new(headers);
+    for entry in entries {
+        let mut row = vec![entry.name.clone()];
+        match &entry.parse {
+            Ok(()) => {
+                for (n, result) in &entry.parts {
+                    let answer = result.outcome.to_string();
+                    row.push(match entry.answers.get(*n) {
+                        Some(expected) if expected == answer => format!("{answer} ok"),
+                        Some(expected) => format!("{answer} MISMATCH (expected {expected})"),
+                        None => answer,
+                    });
+                }
+            }
+            Err(err) => row.extend(day.parts().map(|_| format!("parse error: {err}"))),
+        }
+
+        let elapsed: std::time::Duration = entry.parts.iter().map(|(_, part)| part.elapsed).sum();
+        row.push(format!("{elapsed:?}"));
+        table.push(row);
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{run, table};
+    use crate::{
+        budget::Budget,
+        days::{self, Day, Input, PartError},
+        fixtures,
+        runner::{Limits, Outcome},
+    };
+
+    #[test]
+    fn fixtures_match() {
+        let day = days::get(16).unwrap();
+        let entries = run(day, &fixtures::dir(16), Limits::default()).unwrap();
+
+        assert_eq!(
+            vec!["example.txt", "example2.txt"],
+            entries
+                .iter()
+                .map(|entry| entry.name.as_str())
+                .collect::<Vec<_>>()
+        );
+        assert!(entries.iter().all(|entry| entry.mismatches().is_empty()));
+    }
+
+    #[test]
+    fn mismatches() {
+        let dir = std::env::temp_dir().join(format!("aoc-batch-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("alice"), days::day17::EXAMPLE).unwrap();
+        std::fs::write(dir.join("alice.answers"), "part1: 4,6,3,5,6,3,5,2,1,1").unwrap();
+        std::fs::write(dir.join("bob"), "Register A: x").unwrap();
+
+        let day = days::get(17).unwrap();
+        let entries = run(day, &dir, Limits::default()).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(2, entries.len());
+        assert_eq!(vec![1], entries[0].mismatches());
+        assert!(entries[1].parse.is_err());
+
+        let table = table(day, &entries).to_string();
+        assert!(table.contains("4,6,3,5,6,3,5,2,1,0 MISMATCH (expected 4,6,3,5,6,3,5,2,1,1)"));
+        assert!(table.contains("parse error"));
+    }
+
+    #[test]
+    fn panics() {
+        fn part1(input: &str, _: &Budget) -> Result<String, PartError> {
+            Ok(input.lines().nth(1).unwrap().to_owned())
+        }
+        fn part2(input: &str, _: &Budget) -> Result<String, PartError> {
+            Ok(input.len().to_string())
+        }
+        let day = Day {
+            number: 0,
+            input: Input::Inline(""),
+            parse: |_| Ok(()),
+            part1,
+            part2: Some(part2),
+        };
+
+        let dir = std::env::temp_dir().join(format!("aoc-batch-panics-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("one-line"), "a").unwrap();
+        std::fs::write(dir.join("two-lines"), "a\nb").unwrap();
+        let entries = run(&day, &dir, Limits::default()).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(matches!(
+            &entries[0].parts[0].1.outcome,
+            Outcome::Panicked(_)
+        ));
+        assert_eq!(
+            Outcome::Answer("1".to_owned()),
+            entries[0].parts[1].1.outcome
+        );
+        assert_eq!(
+            Outcome::Answer("b".to_owned()),
+            entries[1].parts[0].1.outcome
+        );
+        assert!(table(&day, &entries)
+            .to_string()
+            .contains("panicked: called `Option::unwrap()`"));
+    }
+}
@@ -1,5 +0,0 @@
-#[derive(Debug, Clone, PartialEq)]
-pub struct Size {
-    pub width: usize,
-    pub height: usize,
-}
@@ -1,57 +0,0 @@
-use std::str::FromStr;
-
-use crate::{direction::Direction, map::Map};
-
-pub struct Input {
-    pub map: Map,
-    pub directions: Vec<Direction>,
-}
-
-impl FromStr for Input {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (map_str, directions_str) = s.split_once("\n\n").unwrap();
-        let map: Map = map_str.trim().parse().unwrap();
-        let directions: Vec<Direction> = directions_str
-            .trim()
-            .replace("\n", "")
-            .chars()
-            .map(|ch| Direction::from_str(&ch.to_string()).unwrap())
-            .collect();
-
-        Ok(Input { map, directions })
-    }
-}
-
-pub const INPUT_EXAMPLE: &str = "##########
-#..O..O.O#
-#......O.#
-#.OO..O.O#
-#..O@..O.#
-#O#..O...#
-#O..O..O.#
-#.OO.O.OO#
-#....O...#
-##########
-
-<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
-vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
-><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
-<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
-^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
-^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
->^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
-<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
-^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
-v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
-
-#[cfg(test)]
-mod tests {
-    use super::{Input, INPUT_EXAMPLE};
-
-    #[test]
-    fn parse() {
-        assert!(INPUT_EXAMPLE.parse::<Input>().is_ok());
-    }
-}
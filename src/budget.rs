@@ -0,0 +1,206 @@
+use std::{
+    cell::Cell,
+    time::{Duration, Instant},
+};
+
+/// About how long [`Budget::tick`] lets pass between looks at the clock.
+///
+/// Reading the clock on every step slows down the days with cheap steps
+/// tenfold, so the number of steps between reads adapts to how long steps take.
+const CLOCK_CHECK_PERIOD: Duration = Duration::from_millis(1);
+
+/// Cooperative wall-clock and step limits for a running solution.
+///
+/// Simulations call [`Budget::tick`] once per step and stop with
+/// [`BudgetExceeded`] as soon as one of the limits is reached.
+#[derive(Debug)]
+pub struct Budget {
+    started: Instant,
+    time_limit: Option<Duration>,
+    step_limit: Option<u64>,
+    steps: Cell<u64>,
+    /// The step at which to read the clock next.
+    next_check: Cell<u64>,
+    check_interval: Cell<u64>,
+    last_check: Cell<Duration>,
+}
+
+impl Budget {
+    pub fn new(time_limit: Option<Duration>, step_limit: Option<u64>) -> Budget {
+        Budget {
+            started: Instant::now(),
+            time_limit,
+            step_limit,
+            steps: Cell::new(0),
+            next_check: Cell::new(1),
+            check_interval: Cell::new(1),
+            last_check: Cell::new(Duration::ZERO),
+        }
+    }
+
+    pub fn unlimited() -> Budget {
+        Budget::new(None, None)
+    }
+
+    pub fn steps(&self) -> u64 {
+        self.steps.get()
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    pub fn time_limit(&self) -> Option<Duration> {
+        self.time_limit
+    }
+
+    pub fn step_limit(&self) -> Option<u64> {
+        self.step_limit
+    }
+
+    pub fn tick(&self) -> Result<(), BudgetExceeded> {
+        let steps = self.steps.get() + 1;
+        self.steps.set(steps);
+
+        if let Some(step_limit) = self.step_limit {
+            if steps > step_limit {
+                return Err(self.exceeded(Limit::Steps(step_limit)));
+            }
+        }
+
+        if let Some(time_limit) = self.time_limit {
+            if steps >= self.next_check.get() {
+                return self.check_clock(steps, time_limit);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Schedules the next clock read by doubling the steps until it while reads
+    /// come quicker than [`CLOCK_CHECK_PERIOD`], and halving them once slower.
+    #[cold]
+    fn check_clock(&self, steps: u64, time_limit: Duration) -> Result<(), BudgetExceeded> {
+        let elapsed = self.elapsed();
+        if elapsed >= time_limit {
+            return Err(self.exceeded(Limit::Time(time_limit)));
+        }
+
+        let since_last = elapsed.saturating_sub(self.last_check.replace(elapsed));
+        let interval = self.check_interval.get();
+        let interval = match since_last < CLOCK_CHECK_PERIOD {
+            true => interval.saturating_mul(2),
+            false => (interval / 2).max(1),
+        };
+        self.check_interval.set(interval);
+        self.next_check.set(steps.saturating_add(interval));
+        Ok(())
+    }
+
+    fn exceeded(&self, limit: Limit) -> BudgetExceeded {
+        BudgetExceeded {
+            limit,
+            steps: self.steps.get(),
+            elapsed: self.elapsed(),
+            progress: String::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Limit {
+    Time(Duration),
+    Steps(u64),
+}
+
+impl std::fmt::Display for Limit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Limit::Time(duration) => write!(f, "time limit of {duration:?}"),
+            Limit::Steps(steps) => write!(f, "step limit of {steps}"),
+        }
+    }
+}
+
+/// A solution gave up because its [`Budget`] ran out.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BudgetExceeded {
+    pub limit: Limit,
+    pub steps: u64,
+    pub elapsed: Duration,
+    /// What the solution had achieved when it stopped.
+    pub progress: String,
+}
+
+impl BudgetExceeded {
+    pub fn with_progress(mut self, progress: impl Into<String>) -> BudgetExceeded {
+        self.progress = progress.into();
+        self
+    }
+}
+
+impl std::fmt::Display for BudgetExceeded {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "budget exceeded: {} reached after {} steps in {:?}",
+            self.limit, self.steps, self.elapsed
+        )?;
+
+        if !self.progress.is_empty() {
+            write!(f, " ({})", self.progress)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Budget, Limit};
+    use std::time::Duration;
+
+    #[test]
+    fn step_limit() {
+        let budget = Budget::new(None, Some(3));
+        assert!(budget.tick().is_ok());
+        assert!(budget.tick().is_ok());
+        assert!(budget.tick().is_ok());
+
+        let exceeded = budget.tick().unwrap_err();
+        assert_eq!(Limit::Steps(3), exceeded.limit);
+        assert_eq!(4, exceeded.steps);
+    }
+
+    #[test]
+    fn time_limit() {
+        let budget = Budget::new(Some(Duration::ZERO), None);
+        let exceeded = budget.tick().unwrap_err();
+        assert_eq!(Limit::Time(Duration::ZERO), exceeded.limit);
+        assert_eq!(1, exceeded.steps);
+    }
+
+    #[test]
+    fn slow_steps() {
+        let budget = Budget::new(Some(Duration::from_millis(50)), None);
+        let exceeded = std::iter::repeat_with(|| {
+            std::thread::sleep(Duration::from_millis(2));
+            budget.tick().err()
+        })
+        .flatten()
+        .next()
+        .unwrap();
+
+        // Steps this slow get the clock read after each of them.
+        assert!(exceeded.steps <= 26, "{} steps", exceeded.steps);
+    }
+
+    #[test]
+    fn unlimited() {
+        let budget = Budget::unlimited();
+        for _ in 0..10_000 {
+            budget.tick().unwrap();
+        }
+        assert_eq!(10_000, budget.steps());
+    }
+}
@@ -0,0 +1,116 @@
+//! The ChaCha20 stream cipher from RFC 8439.
+
+pub const KEY_LEN: usize = 32;
+pub const NONCE_LEN: usize = 12;
+
+const BLOCK_LEN: usize = 64;
+/// "expand 32-byte k"
+const CONSTANTS: [u32; 4] = [0x6170_7865, 0x3320_646e, 0x7962_2d32, 0x6b20_6574];
+
+fn quarter_round(state: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] = (state[d] ^ state[a]).rotate_left(16);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_left(12);
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] = (state[d] ^ state[a]).rotate_left(8);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_left(7);
+}
+
+fn words<const N: usize>(bytes: &[u8]) -> [u32; N] {
+    std::array::from_fn(|i| u32::from_le_bytes(bytes[i * 4..i * 4 + 4].try_into().unwrap()))
+}
+
+/// One 64-byte block of keystream.
+pub fn block(key: &[u8; KEY_LEN], counter: u32, nonce: &[u8; NONCE_LEN]) -> [u8; BLOCK_LEN] {
+    let key: [u32; 8] = words(key);
+    let nonce: [u32; 3] = words(nonce);
+
+    let mut initial = [0u32; 16];
+    initial[..4].copy_from_slice(&CONSTANTS);
+    initial[4..12].copy_from_slice(&key);
+    initial[12] = counter;
+    initial[13..].copy_from_slice(&nonce);
+
+    let mut state = initial;
+    for _ in 0..10 {
+        quarter_round(&mut state, 0, 4, 8, 12);
+        quarter_round(&mut state, 1, 5, 9, 13);
+        quarter_round(&mut state, 2, 6, 10, 14);
+        quarter_round(&mut state, 3, 7, 11, 15);
+        quarter_round(&mut state, 0, 5, 10, 15);
+        quarter_round(&mut state, 1, 6, 11, 12);
+        quarter_round(&mut state, 2, 7, 8, 13);
+        quarter_round(&mut state, 3, 4, 9, 14);
+    }
+
+    let mut out = [0u8; BLOCK_LEN];
+    for (i, (word, initial)) in state.iter().zip(initial).enumerate() {
+        out[i * 4..i * 4 + 4].copy_from_slice(&word.wrapping_add(initial).to_le_bytes());
+    }
+    out
+}
+
+/// XORs `data` with the keystream starting at block `counter`. Encrypting and
+/// decrypting are the same operation.
+pub fn apply(key: &[u8; KEY_LEN], nonce: &[u8; NONCE_LEN], counter: u32, data: &mut [u8]) {
+    for (i, chunk) in data.chunks_mut(BLOCK_LEN).enumerate() {
+        let keystream = block(key, counter.wrapping_add(i as u32), nonce);
+        for (byte, key) in chunk.iter_mut().zip(keystream) {
+            *byte ^= key;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply, block};
+
+    fn hex(s: &str) -> Vec<u8> {
+        let s: String = s.split_whitespace().collect();
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn key() -> [u8; 32] {
+        std::array::from_fn(|i| i as u8)
+    }
+
+    /// RFC 8439, section 2.3.2.
+    #[test]
+    fn block_function() {
+        let nonce = hex("000000090000004a00000000").try_into().unwrap();
+        assert_eq!(
+            hex(
+                "10f1e7e4d13b5915500fdd1fa32071c4c7d1f4c733c068030422aa9ac3d46c4e
+                 d2826446079faa0914c2d705d98b02a2b5129cd1de164eb9cbd083e8a2503c4e"
+            ),
+            block(&key(), 1, &nonce)
+        );
+    }
+
+    /// RFC 8439, section 2.4.2.
+    #[test]
+    fn encryption() {
+        let nonce = hex("000000000000004a00000000").try_into().unwrap();
+        let plaintext = "Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, sunscreen would be it.";
+        let mut data = plaintext.as_bytes().to_vec();
+
+        apply(&key(), &nonce, 1, &mut data);
+        assert_eq!(
+            hex(
+                "6e2e359a2568f98041ba0728dd0d6981e97e7aec1d4360c20a27afccfd9fae0b
+                 f91b65c5524733ab8f593dabcd62b3571639d624e65152ab8f530c359f0861d8
+                 07ca0dbf500d6a6156a38e088a22b65e52bc514d16ccf806818ce91ab7793736
+                 5af90bbf74a35be6b40b8eedf2785e42874d"
+            ),
+            data
+        );
+
+        apply(&key(), &nonce, 1, &mut data);
+        assert_eq!(plaintext.as_bytes(), data);
+    }
+}
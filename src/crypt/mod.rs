@@ -0,0 +1,244 @@
+//! Puzzle inputs encrypted at rest, so they can be committed without publishing them.
+//!
+//! `files/NN.txt` is stored as `files/NN.txt.enc`: a magic header, a random nonce and the
+//! ChaCha20 ciphertext of a check value followed by the input. The check value catches a
+//! wrong key; nothing protects against deliberate tampering.
+//!
+//! The key is 32 bytes written as hex, taken from the first of:
+//! - the `AOC_INPUT_KEY` environment variable,
+//! - the file named by `AOC_INPUT_KEY_FILE`,
+//! - `./.aoc-key`.
+
+use crate::normalize::normalize;
+use chacha20::{KEY_LEN, NONCE_LEN};
+use std::{
+    borrow::Cow,
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+pub mod chacha20;
+
+pub const KEY_VAR: &str = "AOC_INPUT_KEY";
+pub const KEY_FILE_VAR: &str = "AOC_INPUT_KEY_FILE";
+pub const DEFAULT_KEY_FILE: &str = "./.aoc-key";
+
+const MAGIC: &[u8; 8] = b"aoc-enc1";
+const CHECK: &[u8; 8] = b"aoc-key!";
+const HEADER_LEN: usize = MAGIC.len() + NONCE_LEN;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CryptError {
+    MissingKey,
+    InvalidKey(String),
+    NotEncrypted,
+    WrongKey,
+    NotUtf8,
+    Io(String),
+}
+
+impl std::fmt::Display for CryptError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CryptError::MissingKey => write!(
+                f,
+                "No key found, set {KEY_VAR} or {KEY_FILE_VAR}, or create {DEFAULT_KEY_FILE}"
+            ),
+            CryptError::InvalidKey(reason) => write!(f, "Invalid key: {reason}"),
+            CryptError::NotEncrypted => f.write_str("Not an encrypted input"),
+            CryptError::WrongKey => f.write_str("Wrong key for this input"),
+            CryptError::NotUtf8 => f.write_str("Decrypted input is not UTF-8"),
+            CryptError::Io(err) => f.write_str(err),
+        }
+    }
+}
+
+impl std::error::Error for CryptError {}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct Key([u8; KEY_LEN]);
+
+/// Keeps keys out of logs and panic messages.
+impl std::fmt::Debug for Key {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Key(..)")
+    }
+}
+
+impl Key {
+    pub fn from_hex(hex: &str) -> Result<Key, CryptError> {
+        let hex = hex.trim();
+        if hex.len() != KEY_LEN * 2 {
+            return Err(CryptError::InvalidKey(format!(
+                "expected {} hex digits, got {}",
+                KEY_LEN * 2,
+                hex.len()
+            )));
+        }
+
+        let mut key = [0; KEY_LEN];
+        for (i, byte) in key.iter_mut().enumerate() {
+            *byte = hex
+                .get(i * 2..i * 2 + 2)
+                .and_then(|digits| u8::from_str_radix(digits, 16).ok())
+                .ok_or_else(|| CryptError::InvalidKey("not hex".to_owned()))?;
+        }
+        Ok(Key(key))
+    }
+
+    pub fn to_hex(&self) -> String {
+        self.0.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    pub fn generate() -> Result<Key, CryptError> {
+        random().map(Key)
+    }
+
+    /// The configured key, or `None` when there is none anywhere.
+    pub fn load() -> Result<Option<Key>, CryptError> {
+        if let Ok(hex) = std::env::var(KEY_VAR) {
+            return Key::from_hex(&hex).map(Some);
+        }
+
+        let path = std::env::var(KEY_FILE_VAR).unwrap_or_else(|_| DEFAULT_KEY_FILE.to_owned());
+        match std::fs::read_to_string(&path) {
+            Ok(hex) => Key::from_hex(&hex).map(Some),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(CryptError::Io(format!("Could not read {path}: {err}"))),
+        }
+    }
+}
+
+fn random<const N: usize>() -> Result<[u8; N], CryptError> {
+    let mut bytes = [0; N];
+    std::fs::File::open("/dev/urandom")
+        .and_then(|mut file| file.read_exact(&mut bytes))
+        .map_err(|err| CryptError::Io(format!("Could not read /dev/urandom: {err}")))?;
+    Ok(bytes)
+}
+
+pub fn encrypt(key: &Key, plaintext: &str) -> Result<Vec<u8>, CryptError> {
+    let nonce: [u8; NONCE_LEN] = random()?;
+
+    let mut body = Vec::with_capacity(CHECK.len() + plaintext.len());
+    body.extend_from_slice(CHECK);
+    body.extend_from_slice(plaintext.as_bytes());
+    chacha20::apply(&key.0, &nonce, 1, &mut body);
+
+    let mut encrypted = Vec::with_capacity(HEADER_LEN + body.len());
+    encrypted.extend_from_slice(MAGIC);
+    encrypted.extend_from_slice(&nonce);
+    encrypted.extend_from_slice(&body);
+    Ok(encrypted)
+}
+
+pub fn decrypt(key: &Key, encrypted: &[u8]) -> Result<String, CryptError> {
+    let (magic, rest) = encrypted
+        .split_first_chunk::<8>()
+        .ok_or(CryptError::NotEncrypted)?;
+    if magic != MAGIC {
+        return Err(CryptError::NotEncrypted);
+    }
+    let (nonce, body) = rest
+        .split_first_chunk::<NONCE_LEN>()
+        .ok_or(CryptError::NotEncrypted)?;
+
+    let mut body = body.to_vec();
+    chacha20::apply(&key.0, nonce, 1, &mut body);
+    let plaintext = body.strip_prefix(CHECK).ok_or(CryptError::WrongKey)?;
+    String::from_utf8(plaintext.to_vec()).map_err(|_| CryptError::NotUtf8)
+}
+
+/// Where the encrypted copy of `path` lives, e.g. `files/06.txt.enc`.
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut encrypted = path.as_os_str().to_owned();
+    encrypted.push(".enc");
+    PathBuf::from(encrypted)
+}
+
+/// Reads the input at `path`, decrypting its `.enc` copy when there is no plain file,
+/// and normalizes it, see [`normalize`].
+pub fn read_input(path: impl AsRef<Path>) -> std::io::Result<String> {
+    read_raw_input(path.as_ref()).map(|input| match normalize(&input) {
+        Cow::Borrowed(_) => input,
+        Cow::Owned(normalized) => normalized,
+    })
+}
+
+fn read_raw_input(path: &Path) -> std::io::Result<String> {
+    match std::fs::read_to_string(path) {
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+        result => return result,
+    }
+
+    let encrypted = match std::fs::read(encrypted_path(path)) {
+        Ok(encrypted) => encrypted,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("Neither {0} nor {0}.enc exists", path.display()),
+            ))
+        }
+        Err(err) => return Err(err),
+    };
+
+    let key = Key::load()
+        .and_then(|key| key.ok_or(CryptError::MissingKey))
+        .map_err(std::io::Error::other)?;
+    decrypt(&key, &encrypted).map_err(std::io::Error::other)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decrypt, encrypt, encrypted_path, read_input, CryptError, Key};
+    use std::path::Path;
+
+    fn key(byte: u8) -> Key {
+        Key([byte; 32])
+    }
+
+    #[test]
+    fn round_trip() {
+        let input = "3   4\n4   3";
+        let encrypted = encrypt(&key(7), input).unwrap();
+
+        assert!(!encrypted.windows(5).any(|window| window == b"3   4"));
+        assert_eq!(Ok(input.to_owned()), decrypt(&key(7), &encrypted));
+        assert_eq!(Err(CryptError::WrongKey), decrypt(&key(8), &encrypted));
+        assert_eq!(Err(CryptError::NotEncrypted), decrypt(&key(7), b"3   4"));
+        // Every file gets its own nonce.
+        assert_ne!(encrypted, encrypt(&key(7), input).unwrap());
+    }
+
+    #[test]
+    fn hex_keys() {
+        let hex = "00112233445566778899aabbccddeeff00112233445566778899aabbccddeeff";
+        assert_eq!(hex, Key::from_hex(&format!("{hex}\n")).unwrap().to_hex());
+        assert!(matches!(
+            Key::from_hex("abc"),
+            Err(CryptError::InvalidKey(_))
+        ));
+        assert!(matches!(
+            Key::from_hex(&"zz".repeat(32)),
+            Err(CryptError::InvalidKey(_))
+        ));
+        assert_eq!("Key(..)", format!("{:?}", Key::from_hex(hex).unwrap()));
+    }
+
+    #[test]
+    fn plain_file_wins() {
+        assert_eq!(
+            Path::new("files/06.txt.enc"),
+            encrypted_path(Path::new("files/06.txt"))
+        );
+        assert!(read_input("./fixtures/06/example.txt").is_ok());
+        assert!(read_input("./fixtures/06/missing.txt").is_err());
+    }
+
+    #[test]
+    fn inputs_are_normalized() {
+        // The fixture ends with a newline, as saved from the browser.
+        let input = read_input("./fixtures/06/example.txt").unwrap();
+        assert!(input.ends_with("......#..."));
+    }
+}
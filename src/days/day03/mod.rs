@@ -0,0 +1,54 @@
+use crate::parse::{alt, find_all, map, pair, preceded, tag, terminated, unsigned, Input, Parsed};
+
+pub mod part_1;
+pub mod part_2;
+
+pub fn main() {
+    let input = super::input(3);
+
+    println!("Part 1 sum: {}", part_1::calc_input_muls(&input));
+    println!("Part 2 sum: {}", part_2::calc_input_muls(&input));
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Mul(pub u32, pub u32);
+
+#[derive(Debug, PartialEq)]
+pub enum Instruction {
+    Mul(Mul),
+    Do,
+    Dont,
+}
+
+/// `mul(X,Y)` with nothing else inside the parentheses.
+fn mul<'a>() -> impl Fn(Input<'a>) -> Parsed<'a, Mul> {
+    map(
+        preceded(
+            tag("mul("),
+            pair(
+                terminated(unsigned(), tag(",")),
+                terminated(unsigned(), tag(")")),
+            ),
+        ),
+        |(x, y)| Mul(x, y),
+    )
+}
+
+/// The intact instructions in the corrupted memory, in order.
+pub fn instructions(input: &str) -> Vec<Instruction> {
+    find_all(
+        input,
+        alt(
+            map(mul(), Instruction::Mul),
+            alt(
+                map(tag("do()"), |_| Instruction::Do),
+                map(tag("don't()"), |_| Instruction::Dont),
+            ),
+        ),
+    )
+}
+
+/// Only the `mul` instructions.
+pub fn muls(input: &str) -> Vec<Mul> {
+    find_all(input, mul())
+}
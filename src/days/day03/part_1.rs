@@ -0,0 +1,38 @@
+use super::Mul;
+
+pub fn calc_mul(mul: &Mul) -> u32 {
+    mul.0 * mul.1
+}
+
+pub fn calc_input_muls(input: &str) -> u32 {
+    let muls = find_correct_muls(input);
+    muls.iter().map(calc_mul).sum()
+}
+
+pub fn find_correct_muls(input: &str) -> Vec<Mul> {
+    super::muls(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::day03::Mul;
+
+    #[test]
+    fn find_correct_muls() {
+        let s = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+        assert_eq!(
+            super::find_correct_muls(s),
+            vec![Mul(2, 4), Mul(5, 5), Mul(11, 8), Mul(8, 5)]
+        );
+    }
+
+    #[test]
+    fn calc_input_muls() {
+        assert_eq!(
+            161,
+            super::calc_input_muls(
+                "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))"
+            )
+        )
+    }
+}
@@ -0,0 +1,45 @@
+use super::{Instruction, Mul};
+
+pub fn calc_mul(mul: &Mul) -> u32 {
+    mul.0 * mul.1
+}
+
+pub fn calc_input_muls(input: &str) -> u32 {
+    let muls = find_correct_muls(input);
+    muls.iter().map(calc_mul).sum()
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Command {
+    #[default]
+    Do,
+    Dont,
+}
+
+/// The `mul` instructions that come after a `do()` or before any `don't()`.
+pub fn find_correct_muls(input: &str) -> Vec<Mul> {
+    let mut muls: Vec<Mul> = vec![];
+    let mut command = Command::default();
+
+    for instruction in super::instructions(input) {
+        match (instruction, command) {
+            (Instruction::Do, _) => command = Command::Do,
+            (Instruction::Dont, _) => command = Command::Dont,
+            (Instruction::Mul(mul), Command::Do) => muls.push(mul),
+            (Instruction::Mul(_), Command::Dont) => {}
+        }
+    }
+
+    muls
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::day03::Mul;
+
+    #[test]
+    fn find_correct_muls() {
+        let s = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+        assert_eq!(super::find_correct_muls(s), vec![Mul(2, 4), Mul(8, 5)]);
+    }
+}
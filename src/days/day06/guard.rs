@@ -1,4 +1,8 @@
-use crate::map::{Element, Map};
+use crate::{
+    days::day06::map::{Element, Map},
+    grid::GridCell,
+    simulation::Simulation,
+};
 
 #[derive(Debug)]
 pub struct Guard<'map> {
@@ -7,7 +11,7 @@ pub struct Guard<'map> {
     pub direction: Direction,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Position {
     Map(usize, usize),
     OutOfMap,
@@ -56,11 +60,54 @@ impl Guard<'_> {
     }
 }
 
-#[derive(Debug, PartialEq, Clone, Copy)]
+/// The guard walking without marking the map, so that it is cheap to clone for loop detection.
+#[derive(Debug, Clone)]
+pub struct Patrol<'map> {
+    pub map: &'map Map,
+    /// An obstacle placed on top of the map.
+    pub obstacle: Option<(usize, usize)>,
+    pub position: Position,
+    pub direction: Direction,
+}
+
+impl Simulation for Patrol<'_> {
+    type Key = (Position, Direction);
+
+    fn key(&self) -> Self::Key {
+        (self.position.clone(), self.direction)
+    }
+
+    fn step(&mut self) {
+        let Position::Map(i, j) = self.position else {
+            return;
+        };
+
+        let next =
+            next_i_j(i, j, self.direction).and_then(|(i, j)| Some((i, j, self.map.get(i, j)?)));
+        match next {
+            None => self.position = Position::OutOfMap,
+            Some((i, j, _)) if self.obstacle == Some((i, j)) => {
+                self.direction = self.direction.rotate_90deg()
+            }
+            Some((_, _, Element::Obstacle)) => self.direction = self.direction.rotate_90deg(),
+            Some((i, j, _)) => self.position = Position::Map(i, j),
+        }
+    }
+
+    fn is_terminal(&self) -> bool {
+        self.position == Position::OutOfMap
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, GridCell)]
 pub enum Direction {
+    #[cell('<')]
     Left,
+    #[cell('^')]
     Up,
+    #[cell('>')]
     Right,
+    #[cell('v')]
     Down,
 }
 
@@ -1,13 +1,18 @@
 use std::{fmt::Write, str::FromStr};
 
-use crate::guard::Direction;
+use crate::days::day06::guard::{Direction, ParseDirectionError};
+use crate::grid::GridCell;
 
 #[derive(Debug, Clone)]
 pub struct Map(pub Vec<Vec<Element>>);
 
 #[allow(unused)]
 #[derive(Debug)]
-pub struct ParseMapError(pub ParseElementError);
+pub enum ParseMapError {
+    Element(ParseElementError),
+    /// A map needs exactly one guard; this is how many it has.
+    Guards(usize),
+}
 
 impl FromStr for Map {
     type Err = ParseMapError;
@@ -26,9 +31,13 @@ impl FromStr for Map {
                 result
             })
             .collect::<Result<Vec<Vec<Element>>, ParseElementError>>()
-            .map_err(ParseMapError)?;
+            .map_err(ParseMapError::Element)?;
 
-        Ok(Map(matrix))
+        let map = Map(matrix);
+        match map.guards().count() {
+            1 => Ok(map),
+            guards => Err(ParseMapError::Guards(guards)),
+        }
     }
 }
 
@@ -36,6 +45,22 @@ impl Map {
     pub fn get(&self, i: usize, j: usize) -> Option<Element> {
         self.0.get(i).and_then(|row| row.get(j)).copied()
     }
+
+    /// Where the guard starts and which way it faces. Parsed maps always have one.
+    pub fn guard(&self) -> Option<(usize, usize, Direction)> {
+        self.guards().next()
+    }
+
+    fn guards(&self) -> impl Iterator<Item = (usize, usize, Direction)> + '_ {
+        self.0.iter().enumerate().flat_map(|(i, row)| {
+            row.iter()
+                .enumerate()
+                .filter_map(move |(j, element)| match element {
+                    Element::Guard(guard) => Some((i, j, guard.direction)),
+                    _ => None,
+                })
+        })
+    }
 }
 
 impl std::fmt::Display for Map {
@@ -43,7 +68,7 @@ impl std::fmt::Display for Map {
         let latest_row = self.0.len() - 1;
         for (i, row) in self.0.iter().enumerate() {
             for element in row.iter() {
-                let _ = f.write_char(element.as_char());
+                let _ = f.write_char(element.to_char());
             }
             if i != latest_row {
                 let _ = f.write_str("\n");
@@ -59,79 +84,38 @@ pub struct GuardElement {
     pub direction: Direction,
 }
 
-#[allow(unused)]
-#[derive(Debug)]
-pub struct GuardElementParseError(pub String);
+impl GridCell for GuardElement {
+    fn to_char(&self) -> char {
+        self.direction.to_char()
+    }
+}
+
 impl FromStr for GuardElement {
-    type Err = GuardElementParseError;
+    type Err = ParseDirectionError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let direction = match s {
-            "^" => Direction::Up,
-            ">" => Direction::Right,
-            "v" => Direction::Down,
-            "<" => Direction::Left,
-            _ => return Err(GuardElementParseError(s.to_owned())),
-        };
-
+        let direction = s.parse()?;
         Ok(GuardElement { direction })
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, GridCell)]
 pub enum Element {
+    #[cell('.')]
     Empty,
+    #[cell('#')]
     Obstacle,
     Guard(GuardElement),
+    #[cell('X')]
     Visited,
 }
 
-impl Element {
-    pub fn as_char(&self) -> char {
-        match self {
-            Element::Empty => '.',
-            Element::Obstacle => '#',
-            Element::Guard(guard_element) => match guard_element.direction {
-                Direction::Left => '<',
-                Direction::Up => '^',
-                Direction::Right => '>',
-                Direction::Down => 'v',
-            },
-            Element::Visited => 'X',
-        }
-    }
-}
-
-impl std::fmt::Display for Element {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_char(self.as_char())
-    }
-}
-
-#[allow(unused)]
-#[derive(Debug)]
-pub struct ParseElementError(String);
-
-impl FromStr for Element {
-    type Err = ParseElementError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "." => Ok(Element::Empty),
-            "#" => Ok(Element::Obstacle),
-            _ => match GuardElement::from_str(s) {
-                Ok(guard) => Ok(Element::Guard(guard)),
-                Err(_) => Err(ParseElementError(s.to_owned())),
-            },
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
-    use crate::{guard::Direction, map::Element};
+    use crate::days::day06::{guard::Direction, map::Element};
+    use crate::{grid::assert_grid_eq, snapshot::assert_snapshot};
 
-    use super::{GuardElement, Map};
+    use super::{GuardElement, Map, ParseMapError};
 
     const EXAMPLE_DATA: &str = r"#....#.....
 .........#
@@ -147,29 +131,44 @@ mod tests {
     #[test]
     fn parse_map_from_str() {
         let map: Map = EXAMPLE_DATA.parse().unwrap();
-        assert_eq!(
-            map.0[6],
-            vec![
+        assert_grid_eq(
+            &[vec![
                 Element::Empty,
                 Element::Obstacle,
                 Element::Empty,
                 Element::Empty,
                 Element::Guard(GuardElement {
-                    direction: Direction::Up
+                    direction: Direction::Up,
                 }),
                 Element::Empty,
                 Element::Empty,
                 Element::Empty,
                 Element::Empty,
                 Element::Empty,
-            ]
+            ]],
+            &map.0[6..7],
         )
     }
 
     #[test]
     fn print_map() {
         let map: Map = EXAMPLE_DATA.parse().unwrap();
-        println!("{map}");
-        assert_eq!(EXAMPLE_DATA, map.to_string());
+        assert_snapshot("day06_map_example", map);
+    }
+
+    #[test]
+    fn one_guard() {
+        assert!(matches!(
+            "#..\n...".parse::<Map>(),
+            Err(ParseMapError::Guards(0))
+        ));
+        assert!(matches!(
+            "^..\n..>".parse::<Map>(),
+            Err(ParseMapError::Guards(2))
+        ));
+        assert_eq!(
+            Some((1, 2, Direction::Right)),
+            "...\n..>".parse::<Map>().unwrap().guard()
+        );
     }
 }
@@ -0,0 +1,162 @@
+use crate::{
+    budget::{Budget, BudgetExceeded},
+    grid::mask::LayeredMask,
+    simulation::Simulation,
+};
+use guard::{Guard, Patrol, Position};
+use map::{Element, Map};
+
+pub mod guard;
+pub mod map;
+pub fn main() {
+    let map: Map = super::input(6).parse().unwrap();
+
+    println!("Day 6");
+    match part1(&map) {
+        Some(visited) => println!("Part 1: {visited}"),
+        None => println!("Part 1: the guard walks in a loop"),
+    }
+    println!("Part 2: {}", part2(&map));
+}
+
+/// How many cells the guard visits before leaving the map, or `None` when it walks in a loop.
+pub fn part1(map: &Map) -> Option<usize> {
+    part1_with_budget(map, &Budget::unlimited()).unwrap()
+}
+
+pub fn part1_with_budget(map: &Map, budget: &Budget) -> Result<Option<usize>, BudgetExceeded> {
+    let mut map = map.clone();
+    let mut seen: LayeredMask<4> = LayeredMask::for_grid(&map.0);
+
+    let (i, j, direction) = map.guard().expect("parsed maps have a guard");
+
+    let mut guard = Guard {
+        position: Position::Map(i, j),
+        map: &mut map,
+        direction,
+    };
+
+    // The same loop check as part 2's.
+    let mut steps = 0;
+    while let Position::Map(i, j) = guard.position {
+        if !seen.insert(j, i, guard.direction as usize) {
+            return Ok(None);
+        }
+        budget
+            .tick()
+            .map_err(|err| err.with_progress(format!("the guard took {steps} steps")))?;
+        if guard.step().is_err() {
+            break;
+        }
+        steps += 1;
+    }
+
+    Ok(Some(
+        map.0
+            .iter()
+            .flat_map(|row| row.iter())
+            .filter(|element| **element == Element::Visited)
+            .count(),
+    ))
+}
+
+pub fn part2(map: &Map) -> usize {
+    part2_with_budget(map, &Budget::unlimited()).unwrap()
+}
+
+pub fn part2_with_budget(map: &Map, budget: &Budget) -> Result<usize, BudgetExceeded> {
+    let (i, j, direction) = map.guard().expect("parsed maps have a guard");
+
+    let empty_positions: Vec<(usize, usize)> = map
+        .0
+        .iter()
+        .enumerate()
+        .flat_map(|(i, row)| {
+            row.iter()
+                .enumerate()
+                .map(move |(j, element)| (i, j, element))
+        })
+        .filter(|(_, _, element)| **element == Element::Empty)
+        .map(|(i, j, _)| (i, j))
+        .collect();
+
+    let mut loops = 0;
+    let mut seen: LayeredMask<4> = LayeredMask::for_grid(&map.0);
+
+    for (checked, obstacle) in empty_positions.iter().enumerate() {
+        let mut patrol = Patrol {
+            map,
+            obstacle: Some(*obstacle),
+            position: Position::Map(i, j),
+            direction,
+        };
+
+        // The guard loops once it stands on a cell facing a way it has faced there before.
+        seen.clear();
+        while let Position::Map(i, j) = patrol.position {
+            if !seen.insert(j, i, patrol.direction as usize) {
+                loops += 1;
+                break;
+            }
+            budget.tick().map_err(|err| {
+                err.with_progress(format!(
+                    "checked {checked} of {} candidate obstacles, found {loops} loops",
+                    empty_positions.len()
+                ))
+            })?;
+            patrol.step();
+        }
+    }
+
+    Ok(loops)
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::map::Map;
+    use crate::budget::{Budget, Limit};
+
+    const EXAMPLE_DATA: &str = r"....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+    fn map() -> Map {
+        EXAMPLE_DATA.parse().unwrap()
+    }
+
+    #[test]
+    fn part1() {
+        assert_eq!(Some(41), super::part1(&map()));
+    }
+
+    #[test]
+    fn part1_loop() {
+        let looping: Map = ".#...\n....#\n.^...\n#....\n...#.".parse().unwrap();
+        assert_eq!(None, super::part1(&looping));
+
+        let budget = Budget::new(None, Some(10));
+        let exceeded = super::part1_with_budget(&map(), &budget).unwrap_err();
+        assert_eq!(Limit::Steps(10), exceeded.limit);
+        assert_eq!("the guard took 10 steps", exceeded.progress);
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(6, super::part2(&map()));
+    }
+
+    #[test]
+    fn part2_with_budget() {
+        let budget = Budget::new(None, Some(100));
+        let exceeded = super::part2_with_budget(&map(), &budget).unwrap_err();
+        assert_eq!(Limit::Steps(100), exceeded.limit);
+        assert!(exceeded.progress.contains("of 91 candidate obstacles"));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::operation::Operation;
+use crate::days::day07::operation::Operation;
 use std::{num::ParseIntError, str::FromStr};
 
 pub fn parse_equations(input: &str) -> Result<Vec<Equation>, ParseEquationError> {
@@ -13,11 +13,27 @@ pub struct Equation {
 
 impl Equation {
     pub fn is_possible(&self) -> bool {
-        crate::operation::generate_combinations(self.values.len() - 1)
+        crate::days::day07::operation::generate_combinations(self.values.len() - 1)
             .iter()
             .any(|operations| self.test_value == self.eval(operations))
     }
 
+    /// The first operators that make the equation true.
+    pub fn find_operations(&self) -> Option<Vec<Operation>> {
+        crate::days::day07::operation::generate_combinations(self.values.len() - 1)
+            .into_iter()
+            .find(|operations| self.test_value == self.eval(operations))
+    }
+
+    /// Renders the values joined by `operations`, e.g. `81 + 40 * 27`.
+    pub fn expression(&self, operations: &[Operation]) -> String {
+        let mut expression = self.values.first().map(u64::to_string).unwrap_or_default();
+        for (op, value) in operations.iter().zip(self.values.iter().skip(1)) {
+            expression.push_str(&format!(" {} {value}", op.symbol()));
+        }
+        expression
+    }
+
     pub fn eval(&self, operations: &[Operation]) -> u64 {
         let Some(mut result) = self.values.first().copied() else {
             return 0;
@@ -72,7 +88,7 @@ impl FromStr for Equation {
 
 #[cfg(test)]
 mod tests {
-    use crate::equation::Equation;
+    use crate::days::day07::equation::Equation;
 
     #[test]
     fn is_possible() {
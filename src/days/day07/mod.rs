@@ -0,0 +1,112 @@
+use crate::trace;
+use equation::Equation;
+
+pub mod equation;
+pub mod operation;
+pub mod part2;
+
+pub fn main() {
+    let input = super::input(7);
+
+    println!("Day 7");
+
+    println!(
+        "Part 1: {}",
+        part1(&equation::parse_equations(&input).unwrap())
+    );
+    println!(
+        "Part 2: {}",
+        part2(&part2::equation::parse_equations(&input).unwrap())
+    );
+}
+
+pub fn part1(equations: &[Equation]) -> u64 {
+    equations
+        .iter()
+        .filter(|equation| {
+            traced(
+                equation.is_possible(),
+                equation.test_value,
+                &equation.values,
+                || equation.expression(&equation.find_operations().unwrap()),
+            )
+        })
+        .map(|equation| equation.test_value)
+        .sum()
+}
+
+pub fn part2(equations: &[part2::equation::Equation]) -> u64 {
+    equations
+        .iter()
+        .filter(|equation| {
+            traced(
+                equation.is_possible(),
+                equation.test_value,
+                &equation.values,
+                || equation.expression(&equation.find_operations().unwrap()),
+            )
+        })
+        .map(|equation| equation.test_value)
+        .sum()
+}
+
+/// Emits whether an equation can be made true, with the expression that does it, and returns
+/// `possible`. The expression is only searched for while tracing.
+fn traced(
+    possible: bool,
+    test_value: u64,
+    values: &[u64],
+    expression: impl FnOnce() -> String,
+) -> bool {
+    match possible {
+        true => trace!(
+            "equation matched",
+            test_value = test_value,
+            expression = expression()
+        ),
+        false => trace!(
+            "equation impossible",
+            test_value = test_value,
+            values = format!("{values:?}")
+        ),
+    }
+    possible
+}
+
+#[cfg(test)]
+mod tests {
+    const EXAMPLE: &str = "190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20";
+
+    #[test]
+    fn explain() {
+        let (answer, events) = crate::trace::collect(|| {
+            super::part2(&super::part2::equation::parse_equations(EXAMPLE).unwrap())
+        });
+        let matched: Vec<String> = events
+            .iter()
+            .filter(|event| event.name == "equation matched")
+            .map(ToString::to_string)
+            .collect();
+
+        assert_eq!(11387, answer);
+        assert_eq!(
+            vec![
+                "equation matched: test_value=190 expression=\"10 * 19\"",
+                "equation matched: test_value=3267 expression=\"81 + 40 * 27\"",
+                "equation matched: test_value=156 expression=\"15 || 6\"",
+                "equation matched: test_value=7290 expression=\"6 * 8 || 6 * 15\"",
+                "equation matched: test_value=192 expression=\"17 || 8 + 14\"",
+                "equation matched: test_value=292 expression=\"11 + 6 * 16 + 20\"",
+            ],
+            matched
+        );
+    }
+}
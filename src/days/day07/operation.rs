@@ -4,6 +4,15 @@ pub enum Operation {
     Mul,
 }
 
+impl Operation {
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Operation::Add => "+",
+            Operation::Mul => "*",
+        }
+    }
+}
+
 pub fn generate_combinations(n: usize) -> Vec<Vec<Operation>> {
     let mut out: Vec<Vec<Operation>> = Vec::new();
 
@@ -5,6 +5,16 @@ pub enum Operation {
     Concat,
 }
 
+impl Operation {
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Operation::Add => "+",
+            Operation::Mul => "*",
+            Operation::Concat => "||",
+        }
+    }
+}
+
 pub fn generate_combinations(n: usize) -> Vec<Vec<Operation>> {
     let mut out: Vec<Vec<Operation>> = Vec::new();
 
@@ -1,3 +1,4 @@
+use crate::grid::GridCell;
 use std::{fmt::Write, str::FromStr};
 
 #[derive(Debug, Clone)]
@@ -41,7 +42,7 @@ impl std::fmt::Display for Map {
         let latest_row = self.0.len() - 1;
         for (i, row) in self.0.iter().enumerate() {
             for element in row.iter() {
-                let _ = f.write_char(element.as_char());
+                let _ = f.write_char(element.to_char());
             }
             if i != latest_row {
                 let _ = f.write_str("\n");
@@ -52,61 +53,44 @@ impl std::fmt::Display for Map {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, GridCell)]
 pub enum Element {
+    #[cell('.')]
     Empty,
+    #[cell('#')]
     Antinode,
     Antenna(AntennaId),
 }
 
+/// An antenna's frequency, any character that isn't taken by another element.
 #[derive(Debug, Clone, Copy, PartialEq, Hash, Eq)]
 pub struct AntennaId(pub char);
 
-impl Element {
-    pub fn as_char(&self) -> char {
-        match self {
-            Element::Empty => '.',
-            Element::Antinode => '#',
-            Element::Antenna(id) => id.0,
-        }
+impl GridCell for AntennaId {
+    fn to_char(&self) -> char {
+        self.0
     }
 }
 
-impl std::fmt::Display for Element {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_char(self.as_char())
-    }
-}
-
-#[allow(unused)]
 #[derive(Debug)]
-pub struct ParseElementError(String);
+pub struct ParseAntennaIdError(pub String);
 
-impl FromStr for Element {
-    type Err = ParseElementError;
+impl FromStr for AntennaId {
+    type Err = ParseAntennaIdError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "." => Ok(Element::Empty),
-            "#" => Ok(Element::Antinode),
-            s => {
-                let Some(ch) = s.chars().next() else {
-                    return Err(ParseElementError(s.to_owned()));
-                };
-
-                if s.len() != 1 {
-                    return Err(ParseElementError(s.to_owned()));
-                }
-
-                Ok(Element::Antenna(AntennaId(ch)))
-            }
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(ch), None) => Ok(AntennaId(ch)),
+            _ => Err(ParseAntennaIdError(s.to_owned())),
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::map::{AntennaId, Element};
+    use crate::days::day08::map::{AntennaId, Element};
+    use crate::grid::{assert_grid_eq, assert_grid_str_eq};
 
     use super::Map;
 
@@ -126,9 +110,8 @@ mod tests {
     #[test]
     fn parse_map_from_str() {
         let map: Map = EXAMPLE_DATA.parse().unwrap();
-        assert_eq!(
-            map.0[4],
-            vec![
+        assert_grid_eq(
+            &[vec![
                 Element::Empty,
                 Element::Empty,
                 Element::Empty,
@@ -141,13 +124,14 @@ mod tests {
                 Element::Empty,
                 Element::Empty,
                 Element::Empty,
-            ]
+            ]],
+            &map.0[4..5],
         )
     }
 
     #[test]
     fn print_map() {
         let map: Map = EXAMPLE_DATA.parse().unwrap();
-        assert_eq!(EXAMPLE_DATA, map.to_string());
+        assert_grid_str_eq(EXAMPLE_DATA, &map.to_string());
     }
 }
@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+
+use crate::grid::{
+    mask::GridMask,
+    sparse::{Bounds, Point, SparseGrid},
+};
+use map::{AntennaId, Element, Map};
+
+pub mod map;
+
+pub fn main() {
+    let map: Map = super::input(8).parse().unwrap();
+
+    println!("Day 8");
+
+    println!("Part 1: {}", part1(&map));
+    println!("Part 2: {}", part2(&map));
+}
+
+/// Antenna positions by frequency, kept sparse since most of the map is empty.
+fn antennas(map: &Map) -> HashMap<AntennaId, Vec<Point>> {
+    let mut antennas: HashMap<AntennaId, Vec<Point>> = HashMap::new();
+    for (point, element) in SparseGrid::from_dense(&map.0, |el| *el != Element::Empty).iter() {
+        if let Element::Antenna(id) = element {
+            antennas.entry(*id).or_default().push(point);
+        }
+    }
+    antennas
+}
+
+fn pairs(positions: &[Point]) -> impl Iterator<Item = (Point, Point)> + '_ {
+    positions
+        .iter()
+        .enumerate()
+        .flat_map(|(i, a)| positions[i + 1..].iter().map(move |b| (*a, *b)))
+}
+
+pub fn part1(map: &Map) -> usize {
+    let Some(bounds) = Bounds::of_dense(&map.0) else {
+        return 0;
+    };
+
+    let mut antinodes = GridMask::for_grid(&map.0);
+    for positions in antennas(map).values() {
+        for (a, b) in pairs(positions) {
+            let step = b - a;
+            for antinode in [a - step, b + step] {
+                if bounds.contains(antinode) {
+                    antinodes.insert(antinode.x as usize, antinode.y as usize);
+                }
+            }
+        }
+    }
+
+    antinodes.count()
+}
+
+pub fn part2(map: &Map) -> usize {
+    let Some(bounds) = Bounds::of_dense(&map.0) else {
+        return 0;
+    };
+
+    let mut antinodes = GridMask::for_grid(&map.0);
+    for positions in antennas(map).values() {
+        for (a, b) in pairs(positions) {
+            let step = b - a;
+
+            let mut antinode = a;
+            while bounds.contains(antinode) {
+                antinodes.insert(antinode.x as usize, antinode.y as usize);
+                antinode -= step;
+            }
+
+            let mut antinode = b;
+            while bounds.contains(antinode) {
+                antinodes.insert(antinode.x as usize, antinode.y as usize);
+                antinode += step;
+            }
+        }
+    }
+
+    antinodes.count()
+}
+
+#[cfg(test)]
+pub mod tests {
+
+    const EXAMPLE: &str = r#"............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............"#;
+
+    #[test]
+    fn part1() {
+        assert_eq!(14, super::part1(&EXAMPLE.parse().unwrap()));
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(34, super::part2(&EXAMPLE.parse().unwrap()));
+    }
+}
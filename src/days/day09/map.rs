@@ -55,7 +55,7 @@ impl Map {
                 };
                 Some(i)
             })
-            .last()
+            .next_back()
         else {
             return Err(CompressionError::NoFiles);
         };
@@ -106,7 +106,10 @@ pub struct FileId(pub u128);
 
 #[cfg(test)]
 mod tests {
-    use crate::map::{Element, FileId};
+    use crate::{
+        days::day09::map::{Element, FileId},
+        snapshot::assert_snapshot,
+    };
 
     use super::Map;
 
@@ -123,9 +126,9 @@ mod tests {
                 .to_string()
         );
 
-        assert_eq!(
-            "0099811188827773336446555566..............",
-            Map::from_input(EXAMPLE).unwrap().compress().to_string()
+        assert_snapshot(
+            "day09_compressed_example",
+            Map::from_input(EXAMPLE).unwrap().compress(),
         )
     }
 
@@ -0,0 +1,47 @@
+pub mod map;
+pub mod part2;
+
+pub fn main() {
+    let input = super::input(9);
+    println!("Day 9");
+
+    println!("Part 1: {}", part1(map::Map::from_input(&input).unwrap()));
+    println!("Part 2: {}", part2(part2::Map::from_input(&input).unwrap()));
+}
+
+pub fn part1(mut map: map::Map) -> u128 {
+    map.compress()
+        .0
+        .iter()
+        .enumerate()
+        .map(|(i, el)| match el {
+            map::Element::File(file_id) => i as u128 * file_id.0,
+            map::Element::Empty => 0,
+        })
+        .sum()
+}
+
+pub fn part2(mut map: part2::Map) -> u128 {
+    map.compress()
+        .0
+        .iter()
+        .enumerate()
+        .map(|(i, el)| match el {
+            part2::Element::File(file_id) => i as u128 * file_id.0,
+            part2::Element::Empty => 0,
+        })
+        .sum()
+}
+
+#[cfg(test)]
+pub mod tests {
+    const EXAMPLE: &str = "2333133121414131402";
+
+    #[test]
+    fn part1() {
+        assert_eq!(
+            1928,
+            super::part1(super::map::Map::from_input(EXAMPLE).unwrap())
+        )
+    }
+}
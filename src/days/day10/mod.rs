@@ -0,0 +1,105 @@
+use crate::grid::mask::GridMask;
+use map::Map;
+use walker::{Direction, Path, PathStatus, Position, Walker};
+
+pub mod map;
+pub mod walker;
+
+pub fn main() {
+    let map: Map = super::input(10).parse().unwrap();
+
+    println!("Day 10");
+
+    println!("Part 1: {}", part1(&map));
+    println!("Part 2: {}", part2(&map));
+}
+
+pub fn part1(map: &Map) -> usize {
+    map.0
+        .iter()
+        .enumerate()
+        .flat_map(|(i, row)| row.iter().enumerate().map(move |(j, digit)| (i, j, digit)))
+        .filter(|(_, _, digit)| **digit == 0)
+        .map(|(i, j, _)| Position { i, j })
+        .map(|position| {
+            let walker = Walker {
+                position,
+                map,
+                path: Path {
+                    start: position,
+                    directions: vec![],
+                },
+                status: PathStatus::Target(1),
+            };
+
+            let mut peaks = GridMask::for_grid(&map.0);
+            for walker in explore(walker) {
+                peaks.insert(walker.position.j, walker.position.i);
+            }
+            peaks.count()
+        })
+        .sum()
+}
+
+pub fn part2(map: &Map) -> usize {
+    map.0
+        .iter()
+        .enumerate()
+        .flat_map(|(i, row)| row.iter().enumerate().map(move |(j, digit)| (i, j, digit)))
+        .filter(|(_, _, digit)| **digit == 0)
+        .map(|(i, j, _)| Position { i, j })
+        .map(|position| {
+            let walker = Walker {
+                position,
+                map,
+                path: Path {
+                    start: position,
+                    directions: vec![],
+                },
+                status: PathStatus::Target(1),
+            };
+
+            explore(walker).len()
+        })
+        .sum()
+}
+
+fn explore(walker: Walker) -> Vec<Walker> {
+    let mut successful_walkers = Vec::new();
+    if walker.status == PathStatus::Done {
+        successful_walkers.push(walker);
+        return successful_walkers;
+    }
+
+    for direction in Direction::directions() {
+        let mut walker = walker.clone();
+        if walker.step(direction).is_ok() {
+            let walkers = explore(walker);
+            successful_walkers.extend(walkers);
+        }
+    }
+
+    successful_walkers
+}
+
+#[cfg(test)]
+pub mod tests {
+    const EXAMPLE: &str = "89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732";
+
+    #[test]
+    fn part1() {
+        assert_eq!(36, super::part1(&EXAMPLE.parse().unwrap()));
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(81, super::part2(&EXAMPLE.parse().unwrap()));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::map::Map;
+use crate::days::day10::map::Map;
 
 #[derive(Debug, Clone)]
 pub struct Walker<'map> {
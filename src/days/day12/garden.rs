@@ -20,18 +20,19 @@ impl Garden {
             .copied()
     }
 
-    pub fn regions(&self) -> Vec<Region> {
+    pub fn regions(&self) -> Vec<Region<'_>> {
+        use crate::grid::mask::GridMask;
         use direction::Direction;
-        use std::collections::{HashSet, VecDeque};
+        use std::collections::VecDeque;
 
         const DIRECTIONS: [Direction; 4] = Direction::directions();
-        let mut visited = HashSet::new();
+        let mut visited = GridMask::for_grid(&self.0);
         let mut regions = Vec::new();
 
         self.0.iter().enumerate().for_each(|(y, row)| {
             row.iter().enumerate().for_each(|(x, plant)| {
                 let position = Position { x, y };
-                if visited.contains(&position) {
+                if visited.contains(x, y) {
                     return;
                 }
 
@@ -39,15 +40,16 @@ impl Garden {
                 let mut positions = Vec::new();
 
                 queue.push_back(position);
-                visited.insert(position);
+                visited.insert(x, y);
 
                 while let Some(pos) = queue.pop_front() {
                     positions.push(pos);
 
                     for direction in DIRECTIONS {
                         if let Some(neighbor) = direction::next_position(pos, direction) {
-                            if self.get(neighbor) == Some(*plant) && !visited.contains(&neighbor) {
-                                visited.insert(neighbor);
+                            if self.get(neighbor) == Some(*plant)
+                                && visited.insert(neighbor.x, neighbor.y)
+                            {
                                 queue.push_back(neighbor);
                             }
                         }
@@ -129,7 +131,7 @@ pub struct Position {
 pub struct Plant(pub char);
 
 mod direction {
-    use crate::garden::Position;
+    use crate::days::day12::garden::Position;
 
     #[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
     pub enum Direction {
@@ -170,7 +172,7 @@ mod direction {
 #[cfg(test)]
 mod tests {
     use super::{Garden, Region};
-    use crate::garden::{Plant, Position};
+    use crate::days::day12::garden::{Plant, Position};
     use std::collections::HashMap;
 
     const SIMPLE_EXAMPLE: &str = "AAAA
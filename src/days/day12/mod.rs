@@ -0,0 +1,11 @@
+use garden::Garden;
+
+pub mod garden;
+
+pub fn main() {
+    let input = super::input(12);
+
+    println!("Day 12");
+
+    println!("Part 1: {}", input.parse::<Garden>().unwrap().fence_price());
+}
@@ -0,0 +1,104 @@
+use crate::trace;
+
+pub mod part1;
+
+pub fn main() {
+    let input = super::input(13);
+
+    println!("Day 13");
+
+    println!("Part 1: {}", part1(&part1::parse_input(&input).unwrap()));
+}
+
+pub fn part1(machines: &[part1::Group]) -> usize {
+    machines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, group)| {
+            let solution = group.find_solution();
+            match solution {
+                Some(tokens) => {
+                    let (a, b) = group.find_presses().unwrap_or_default();
+                    trace!(
+                        "machine solvable",
+                        machine = i + 1,
+                        a = a,
+                        b = b,
+                        tokens = tokens
+                    )
+                }
+                None => trace!("machine unsolvable", machine = i + 1),
+            }
+            solution
+        })
+        .sum()
+}
+
+#[cfg(test)]
+pub mod tests {
+
+    const EXAMPLE: &str = "Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279";
+
+    #[test]
+    pub fn part1() {
+        assert_eq!(
+            480,
+            super::part1(&super::part1::parse_input(EXAMPLE).unwrap())
+        );
+    }
+
+    #[test]
+    pub fn collinear_buttons() {
+        let machines = super::part1::parse_input(
+            "Button A: X+2, Y+2\nButton B: X+3, Y+3\nPrize: X=12, Y=12\n\n\
+             Button A: X+4, Y+4\nButton B: X+1, Y+1\nPrize: X=8, Y=8\n\n\
+             Button A: X+2, Y+4\nButton B: X+4, Y+8\nPrize: X=7, Y=14\n\n\
+             Button A: X+0, Y+0\nButton B: X+1, Y+1\nPrize: X=3, Y=5\n\n\
+             Button A: X+0, Y+0\nButton B: X+1, Y+1\nPrize: X=3, Y=3",
+        )
+        .unwrap();
+        let presses: Vec<_> = machines.iter().map(|m| m.find_presses()).collect();
+        assert_eq!(
+            vec![Some((0, 4)), Some((2, 0)), None, None, Some((0, 3))],
+            presses
+        );
+    }
+
+    #[test]
+    pub fn parse_error() {
+        let err = super::part1::parse_input(&EXAMPLE.replace("Y+66", "Y:66")).unwrap_err();
+        assert_eq!("\", Y+\"", err.expected);
+        assert_eq!((5, 15), (err.line, err.column));
+    }
+
+    #[test]
+    pub fn explain() {
+        let (_, events) =
+            crate::trace::collect(|| super::part1(&super::part1::parse_input(EXAMPLE).unwrap()));
+        let events: Vec<String> = events.iter().map(ToString::to_string).collect();
+
+        assert_eq!(
+            vec![
+                "machine solvable: machine=1 a=80 b=40 tokens=280",
+                "machine unsolvable: machine=2",
+                "machine solvable: machine=3 a=38 b=86 tokens=200",
+                "machine unsolvable: machine=4",
+            ],
+            events
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use crate::math;
+use crate::parse::{
+    blocks, map, pair, parse_all, preceded, tag, terminated, unsigned, Input, ParseError, Parsed,
+};
+
+pub fn parse_input(input: &str) -> Result<Vec<Group>, ParseError> {
+    parse_all(input, blocks(group()))
+}
+
+/// `X+94, Y+34` for a button or `X=8400, Y=5400` for the prize, after `x`'s label.
+fn coordinates<'a>(x: &'static str, y: &'static str) -> impl Fn(Input<'a>) -> Parsed<'a, (X, Y)> {
+    pair(
+        preceded(tag(x), map(unsigned(), X)),
+        preceded(tag(y), map(unsigned(), Y)),
+    )
+}
+
+/// Both buttons and the prize, one per line.
+fn group<'a>() -> impl Fn(Input<'a>) -> Parsed<'a, Group> {
+    let a = terminated(coordinates("Button A: X+", ", Y+"), tag("\n"));
+    let b = terminated(coordinates("Button B: X+", ", Y+"), tag("\n"));
+    let prize = coordinates("Prize: X=", ", Y=");
+
+    map(
+        pair(a, pair(b, prize)),
+        |((ax, ay), ((bx, by), (px, py)))| Group {
+            a: ButtonA { x: ax, y: ay },
+            b: ButtonB { x: bx, y: by },
+            prize: Prize { x: px, y: py },
+        },
+    )
+}
+
+#[derive(Debug, Clone)]
+pub struct Group {
+    pub a: ButtonA,
+    pub b: ButtonB,
+    pub prize: Prize,
+}
+
+impl Group {
+    /// Tokens needed to win the prize, see [`Group::find_presses`].
+    pub fn find_solution(&self) -> Option<usize> {
+        self.find_presses()
+            .map(|(a_count, b_count)| self.token_cost(a_count, b_count))
+    }
+
+    pub fn token_cost(&self, a_count: usize, b_count: usize) -> usize {
+        a_count * self.a.token_cost() + b_count * self.b.token_cost()
+    }
+
+    /// Presses of buttons A and B that win the prize for the fewest tokens.
+    pub fn find_presses(&self) -> Option<(usize, usize)> {
+        let [ax, ay, bx, by, px, py] = [
+            self.a.x.0,
+            self.a.y.0,
+            self.b.x.0,
+            self.b.y.0,
+            self.prize.x.0,
+            self.prize.y.0,
+        ]
+        .map(|n| n as i64);
+
+        let determinant = ax * by - ay * bx;
+        if determinant != 0 {
+            // Cramer's rule gives the only solution, which may not be whole or positive.
+            let a = px * by - py * bx;
+            let b = ax * py - ay * px;
+            if a % determinant != 0 || b % determinant != 0 {
+                return None;
+            }
+            let (a, b) = (a / determinant, b / determinant);
+            return (a >= 0 && b >= 0).then_some((a as usize, b as usize));
+        }
+
+        // Both buttons move along the same line, which the prize has to be on. Then one axis
+        // says it all, except when a button doesn't move and the line is B's alone, so the
+        // presses are checked against the other axis too.
+        if ax * py != ay * px {
+            return None;
+        }
+        let presses = match ax != 0 || bx != 0 {
+            true => self.cheapest_presses(ax, bx, px),
+            false => self.cheapest_presses(ay, by, py),
+        }?;
+        let (a, b) = (presses.0 as i64, presses.1 as i64);
+        (a * ax + b * bx == px && a * ay + b * by == py).then_some(presses)
+    }
+
+    /// The non-negative `(a, b)` with `a * step_a + b * step_b == target` that costs the
+    /// fewest tokens.
+    fn cheapest_presses(&self, step_a: i64, step_b: i64, target: i64) -> Option<(usize, usize)> {
+        match (step_a, step_b) {
+            (0, 0) => return (target == 0).then_some((0, 0)),
+            (0, _) => return (target % step_b == 0).then(|| (0, (target / step_b) as usize)),
+            (_, 0) => return (target % step_a == 0).then(|| ((target / step_a) as usize, 0)),
+            _ => {}
+        }
+
+        let (g, x, y) = math::extended_gcd(step_a, step_b);
+        if target % g != 0 {
+            return None;
+        }
+        // Every solution is (a0 + t * da, b0 - t * db) for some whole t.
+        let (a0, b0) = (x.checked_mul(target / g)?, y.checked_mul(target / g)?);
+        let (da, db) = (step_b / g, step_a / g);
+        let t_min = math::div_ceil(-a0, da);
+        let t_max = math::div_floor(b0, db);
+        if t_min > t_max {
+            return None;
+        }
+
+        // The cost is linear in t, so one of the ends is cheapest.
+        [t_min, t_max]
+            .into_iter()
+            .map(|t| ((a0 + t * da) as usize, (b0 - t * db) as usize))
+            .min_by_key(|&(a, b)| self.token_cost(a, b))
+    }
+}
+
+trait TokenCost {
+    fn token_cost(&self) -> usize;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ButtonA {
+    pub x: X,
+    pub y: Y,
+}
+
+impl TokenCost for ButtonA {
+    fn token_cost(&self) -> usize {
+        3
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ButtonB {
+    pub x: X,
+    pub y: Y,
+}
+
+impl TokenCost for ButtonB {
+    fn token_cost(&self) -> usize {
+        1
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Prize {
+    pub x: X,
+    pub y: Y,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd)]
+pub struct X(pub usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd)]
+pub struct Y(pub usize);
@@ -1,4 +1,8 @@
-use crate::{position::Position, robot::Robot, size::Size};
+use crate::{
+    days::day14::{position::Position, robot::Robot, size::Size},
+    parse::ParseError,
+    simulation::Simulation,
+};
 use std::collections::HashMap;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -9,17 +13,17 @@ pub struct Grid {
 }
 
 impl Grid {
-    pub fn from_robots_input(input: &str, size: Size) -> Grid {
+    pub fn from_robots_input(input: &str, size: Size) -> Result<Grid, ParseError> {
         let grid: Vec<Vec<Element>> = (0..size.height)
             .map(|_| (0..size.width).map(|_| Element::Empty).collect::<Vec<_>>())
             .collect();
 
-        let robots = Robot::from_input(input, size.clone());
+        let robots = Robot::from_input(input, size.clone())?;
 
         let mut grid = Grid { size, grid, robots };
         grid.update_grid_elements();
 
-        grid
+        Ok(grid)
     }
 }
 
@@ -35,8 +39,8 @@ impl Grid {
         )
     }
 
-    pub fn quadrants(&self) -> Option<[Quadrant; 4]> {
-        if self.size.width % 2 == 0 || self.size.height % 2 == 0 {
+    pub fn quadrants(&self) -> Option<[Quadrant<'_>; 4]> {
+        if self.size.width.is_multiple_of(2) || self.size.height.is_multiple_of(2) {
             return None;
         }
 
@@ -89,10 +93,13 @@ impl Grid {
         Some([q1, q2, q3, q4])
     }
 
+    /// Moves every robot `secs` seconds ahead at once.
     pub fn wait_secs(&mut self, secs: u32) {
-        for _ in 0..secs {
-            self.wait_one_second();
-        }
+        self.robots.iter_mut().for_each(|robot| {
+            robot.move_secs(secs as usize);
+        });
+
+        self.update_grid_elements();
     }
 
     pub fn wait_one_second(&mut self) {
@@ -121,6 +128,35 @@ impl Grid {
     }
 }
 
+impl Grid {
+    /// Whether some robot has ten more stacked right below it, the trunk of the tree.
+    pub fn has_vertical_line(&self) -> bool {
+        self.robots.iter().any(|robot| {
+            let Position { x, y } = robot.position;
+            (y + 1..=y + 10).all(|y| {
+                matches!(
+                    self.grid.get(y).and_then(|row| row.get(x)),
+                    Some(Element::Robots(_))
+                )
+            })
+        })
+    }
+}
+
+/// Robots wrap around the grid, so every formation comes back after at most
+/// `width * height` seconds.
+impl Simulation for Grid {
+    type Key = Vec<Position>;
+
+    fn key(&self) -> Vec<Position> {
+        self.robots.iter().map(|robot| robot.position).collect()
+    }
+
+    fn step(&mut self) {
+        self.wait_one_second();
+    }
+}
+
 impl std::fmt::Display for Grid {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for row in self.grid.iter() {
@@ -189,7 +225,12 @@ impl Quadrant<'_> {
 #[cfg(test)]
 mod tests {
     use super::{Grid, Quadrant};
-    use crate::{position::Position, robot::ROBOTS_INPUT_EXAMPLE, size::Size};
+    use crate::days::day14::{position::Position, robot::ROBOTS_INPUT_EXAMPLE, size::Size};
+    use crate::snapshot::assert_snapshot;
+    use crate::{
+        budget::Budget,
+        simulation::{self, Cycle},
+    };
 
     #[test]
     fn quadrants() {
@@ -199,7 +240,8 @@ mod tests {
                 width: 11,
                 height: 7,
             },
-        );
+        )
+        .unwrap();
 
         let q1 = Quadrant {
             grid: &grid,
@@ -226,10 +268,10 @@ mod tests {
         };
 
         let quadrants = grid.quadrants().unwrap();
-        assert!(quadrants.iter().any(|q| *q == q1));
-        assert!(quadrants.iter().any(|q| *q == q2));
-        assert!(quadrants.iter().any(|q| *q == q3));
-        assert!(quadrants.iter().any(|q| *q == q4));
+        assert!(quadrants.contains(&q1));
+        assert!(quadrants.contains(&q2));
+        assert!(quadrants.contains(&q3));
+        assert!(quadrants.contains(&q4));
     }
 
     #[test]
@@ -240,8 +282,49 @@ mod tests {
                 width: 11,
                 height: 7,
             },
-        );
+        )
+        .unwrap();
         grid.wait_secs(100);
         assert_eq!(Some(12), grid.product_of_quadrants_robots_counts());
     }
+
+    #[test]
+    fn robots_are_periodic() {
+        let grid = Grid::from_robots_input(
+            ROBOTS_INPUT_EXAMPLE,
+            Size {
+                width: 11,
+                height: 7,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            Some(Cycle {
+                start: 0,
+                length: 77
+            }),
+            simulation::brent(&grid, &Budget::unlimited()).unwrap()
+        );
+    }
+
+    #[test]
+    fn wait_one_second_snapshots() {
+        let mut grid = Grid::from_robots_input(
+            ROBOTS_INPUT_EXAMPLE,
+            Size {
+                width: 11,
+                height: 7,
+            },
+        )
+        .unwrap();
+
+        for seconds in 0..=5 {
+            assert_snapshot(&format!("day14_grid_example_{seconds}s"), &grid);
+            grid.wait_one_second();
+        }
+
+        grid.wait_secs(94);
+        assert_snapshot("day14_grid_example_100s", &grid);
+    }
 }
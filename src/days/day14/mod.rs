@@ -0,0 +1,59 @@
+use crate::{
+    budget::{Budget, BudgetExceeded},
+    simulation::{RunEnd, Simulation},
+};
+use grid::Grid;
+use size::Size;
+
+pub mod grid;
+pub mod position;
+pub mod robot;
+pub mod size;
+
+/// The room the real robots move in.
+pub const SIZE: Size = Size {
+    width: 101,
+    height: 103,
+};
+
+pub fn main() {
+    let grid = Grid::from_robots_input(&super::input(14), SIZE).unwrap();
+
+    println!("Day 14");
+
+    println!("Part 1: {}", part1(grid.clone()));
+    match part2(grid) {
+        Some((grid, seconds_passed)) => {
+            println!("Part 2: {grid}\nSeconds passed: {seconds_passed}")
+        }
+        None => println!("Part 2: the robots never draw a tree"),
+    }
+}
+
+pub fn part1(mut grid: Grid) -> u32 {
+    grid.wait_secs(100);
+    grid.product_of_quadrants_robots_counts().unwrap()
+}
+
+pub fn part2(grid: Grid) -> Option<(Grid, u32)> {
+    part2_with_budget(grid, &Budget::unlimited()).unwrap()
+}
+
+/// The first frame showing the tree and the seconds it took, or `None` when the robots
+/// return to a formation they were already in without ever drawing it.
+pub fn part2_with_budget(
+    mut grid: Grid,
+    budget: &Budget,
+) -> Result<Option<(Grid, u32)>, BudgetExceeded> {
+    let end = grid
+        .run_until(Grid::has_vertical_line, budget)
+        .map_err(|err| {
+            let progress = format!("simulated {} seconds without finding a tree", err.steps);
+            err.with_progress(progress)
+        })?;
+
+    Ok(match end {
+        RunEnd::Matched { steps } => Some((grid, steps as u32)),
+        RunEnd::Terminal { .. } | RunEnd::Cycle { .. } => None,
+    })
+}
@@ -0,0 +1,152 @@
+use crate::days::day14::{position::Position, size::Size};
+use crate::grid::sparse::{Bounds, Point};
+use crate::parse::{
+    lines, map, pair, parse_all, preceded, signed, tag, unsigned, Input, ParseError, Parsed,
+};
+
+pub const ROBOTS_INPUT_EXAMPLE: &str = "p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Robot {
+    pub position: Position,
+    pub velocity: Velocity,
+    pub grid_size: Size,
+}
+
+/// One `p=0,4 v=3,-3` line.
+fn robot<'a>() -> impl Fn(Input<'a>) -> Parsed<'a, (Position, Velocity)> {
+    let position = map(
+        pair(unsigned(), preceded(tag(","), unsigned())),
+        |(x, y)| Position { x, y },
+    );
+    let velocity = map(pair(signed(), preceded(tag(","), signed())), |(x, y)| {
+        Velocity { x, y }
+    });
+    pair(
+        preceded(tag("p="), position),
+        preceded(tag(" v="), velocity),
+    )
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<(Position, Velocity)>, ParseError> {
+    parse_all(input, lines(robot()))
+}
+
+impl Robot {
+    pub fn from_input(input: &str, grid_size: Size) -> Result<Vec<Robot>, ParseError> {
+        Ok(parse_input(input)?
+            .into_iter()
+            .map(|(position, velocity)| Robot {
+                position,
+                velocity,
+                grid_size: grid_size.clone(),
+            })
+            .collect())
+    }
+
+    pub fn move_one_second(&mut self) {
+        self.position = next_position(&self.position, &self.velocity, &self.grid_size);
+    }
+
+    pub fn move_secs(&mut self, secs: usize) {
+        self.position = position_after(&self.position, &self.velocity, &self.grid_size, secs);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Velocity {
+    pub x: i32,
+    pub y: i32,
+}
+
+pub fn next_position(position: &Position, velocity: &Velocity, grid_size: &Size) -> Position {
+    position_after(position, velocity, grid_size, 1)
+}
+
+/// Where a robot is `secs` seconds later, in one jump.
+pub fn position_after(
+    position: &Position,
+    velocity: &Velocity,
+    grid_size: &Size,
+    secs: usize,
+) -> Position {
+    let bounds = Bounds {
+        min: Point::new(0, 0),
+        max: Point::new(grid_size.width as i64 - 1, grid_size.height as i64 - 1),
+    };
+    let secs = (secs % grid_size.period()) as i64;
+    let next = bounds.wrap(
+        Point::new(position.x as i64, position.y as i64)
+            + Point::new(i64::from(velocity.x) * secs, i64::from(velocity.y) * secs),
+    );
+
+    Position {
+        x: next.x as usize,
+        y: next.y as usize,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::day14::{position::Position, robot::Velocity, size::Size};
+
+    #[test]
+    fn next_position() {
+        let mut position = Position { x: 2, y: 4 };
+        let velocity = Velocity { x: 2, y: -3 };
+        let grid_size = Size {
+            width: 11,
+            height: 7,
+        };
+
+        position = super::next_position(&position, &velocity, &grid_size);
+        assert_eq!(Position { x: 4, y: 1 }, position);
+
+        position = super::next_position(&position, &velocity, &grid_size);
+        assert_eq!(Position { x: 6, y: 5 }, position);
+
+        position = super::next_position(&position, &velocity, &grid_size);
+        assert_eq!(Position { x: 8, y: 2 }, position);
+
+        position = super::next_position(&position, &velocity, &grid_size);
+        assert_eq!(Position { x: 10, y: 6 }, position);
+
+        position = super::next_position(&position, &velocity, &grid_size);
+        assert_eq!(Position { x: 1, y: 3 }, position);
+    }
+
+    #[test]
+    fn position_after() {
+        let start = Position { x: 2, y: 4 };
+        let velocity = Velocity { x: 2, y: -3 };
+        let grid_size = Size {
+            width: 11,
+            height: 7,
+        };
+        assert_eq!(77, grid_size.period());
+
+        let mut position = start;
+        for secs in 1..=200 {
+            position = super::next_position(&position, &velocity, &grid_size);
+            assert_eq!(
+                position,
+                super::position_after(&start, &velocity, &grid_size, secs)
+            );
+        }
+        assert_eq!(
+            start,
+            super::position_after(&start, &velocity, &grid_size, 77 * 1000)
+        );
+    }
+}
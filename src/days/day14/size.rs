@@ -0,0 +1,15 @@
+use crate::math;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Size {
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Size {
+    /// Seconds after which every robot is back where it started, since x repeats every
+    /// `width` seconds and y every `height`.
+    pub fn period(&self) -> usize {
+        math::lcm(self.width as u64, self.height as u64).expect("grid sizes are small") as usize
+    }
+}
@@ -1,6 +1,6 @@
 use std::str::FromStr;
 
-use crate::position::Position;
+use crate::days::day15::position::Position;
 
 #[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
 pub enum Direction {
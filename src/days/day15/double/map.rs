@@ -1,9 +1,10 @@
 use std::{fmt::Write, str::FromStr};
 
-use crate::{
+use crate::days::day15::{
     map::{Element as Part1Element, Map as Part1Map},
     position::Position,
 };
+use crate::grid::GridCell;
 
 impl Map {
     pub fn get(&self, position: Position) -> Option<Element> {
@@ -94,63 +95,31 @@ impl std::fmt::Display for Map {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, GridCell)]
 pub enum Element {
+    #[cell('.')]
     Empty,
+    #[cell('#')]
     Wall,
     Box(BoxEl),
+    #[cell('@')]
     Robot,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, GridCell)]
 pub enum BoxEl {
+    #[cell('[')]
     Opening,
+    #[cell(']')]
     Closing,
 }
 
-impl Element {
-    fn as_char(&self) -> char {
-        match self {
-            Element::Empty => '.',
-            Element::Wall => '#',
-            Element::Robot => '@',
-            Element::Box(box_el) => match box_el {
-                BoxEl::Opening => '[',
-                BoxEl::Closing => ']',
-            },
-        }
-    }
-}
-
-#[derive(Debug)]
-pub struct ParseElementError(pub String);
-
-impl FromStr for Element {
-    type Err = ParseElementError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "." => Ok(Element::Empty),
-            "#" => Ok(Element::Wall),
-            "[" => Ok(Element::Box(BoxEl::Opening)),
-            "]" => Ok(Element::Box(BoxEl::Closing)),
-            "@" => Ok(Element::Robot),
-            _ => Err(ParseElementError(s.to_owned())),
-        }
-    }
-}
-
-impl std::fmt::Display for Element {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_char(self.as_char())
-    }
-}
-
 #[cfg(test)]
 mod tests {
-    use crate::map::Map as Part1Map;
+    use crate::days::day15::map::Map as Part1Map;
 
     use super::Map;
+    use crate::grid::assert_grid_str_eq;
 
     #[test]
     fn from_part1_map() {
@@ -167,7 +136,7 @@ mod tests {
             .parse()
             .unwrap();
 
-        assert_eq!(
+        assert_grid_str_eq(
             "####################
 ##....[]....[]..[]##
 ##............[]..##
@@ -178,7 +147,7 @@ mod tests {
 ##..[][]..[]..[][]##
 ##........[]......##
 ####################",
-            Map::from(part1_map).to_string().as_str()
+            &Map::from(part1_map).to_string(),
         );
     }
 }
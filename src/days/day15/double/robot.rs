@@ -2,8 +2,11 @@ use std::collections::{HashSet, VecDeque};
 
 use super::map::{BoxEl, Element, Map};
 use crate::{
-    direction::{self, Direction},
-    position::Position,
+    days::day15::{
+        direction::{self, Direction},
+        position::Position,
+    },
+    simulation::Simulation,
 };
 
 #[derive(Debug)]
@@ -13,10 +16,13 @@ pub struct Robot<'map> {
     pub steps_made: u32,
 }
 
+/// Shows only the map's size: the whole map is as big as the puzzle input and buries the
+/// rest of the error. `Display` the map when it's needed.
 impl std::fmt::Debug for StepError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = self.map.0.first().map_or(0, Vec::len);
         f.debug_struct("StepError")
-            .field("map", &format_args!("{}", self.map))
+            .field("map", &format_args!("{width}x{}", self.map.0.len()))
             .field("steps_made", &self.steps_made)
             .field("kind", &self.kind)
             .finish()
@@ -225,7 +231,7 @@ impl Robot<'_> {
                     }
 
                     let mut visited: Vec<BoxPartPosition> = Vec::from_iter(visited);
-                    visited.sort_by(|a, b| b.level.cmp(&a.level));
+                    visited.sort_by_key(|b| std::cmp::Reverse(b.level));
 
                     let can_move = visited.iter().all(|BoxPartPosition { position, .. }| {
                         direction::next_position(*position, direction)
@@ -299,10 +305,59 @@ pub struct StepError {
     pub kind: StepErrorKind,
 }
 
+/// The robot making its list of moves one per tick, on a twice-as-wide warehouse of its own.
+#[derive(Debug, Clone)]
+pub struct Moves {
+    pub map: Map,
+    pub position: Position,
+    pub directions: Vec<Direction>,
+    pub made: usize,
+}
+
+impl Moves {
+    pub fn new(map: Map, directions: Vec<Direction>) -> Option<Moves> {
+        Some(Moves {
+            position: map.find_robot_position()?,
+            map,
+            directions,
+            made: 0,
+        })
+    }
+}
+
+/// Every move is made once, so states of one run differ at least in the moves made.
+impl Simulation for Moves {
+    type Key = (usize, Position);
+
+    fn key(&self) -> Self::Key {
+        (self.made, self.position)
+    }
+
+    fn step(&mut self) {
+        let mut robot = Robot {
+            position: self.position,
+            map: &mut self.map,
+            steps_made: self.made as u32,
+        };
+        robot.step(self.directions[self.made]).unwrap();
+        self.position = robot.position;
+        self.made += 1;
+    }
+
+    fn is_terminal(&self) -> bool {
+        self.made == self.directions.len()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Robot;
-    use crate::{direction::Direction, double::map::Map};
+    use crate::days::day15::{
+        direction::Direction,
+        double::map::Map,
+        input::{Input, INPUT_EXAMPLE},
+    };
+    use crate::snapshot::assert_snapshot;
 
     #[test]
     fn push_boxes_left() {
@@ -314,9 +369,9 @@ mod tests {
         };
 
         robot.step(Direction::Left).unwrap();
-        assert_eq!("#.[][][]@.", robot.map.to_string().as_str());
+        assert_snapshot("day15_double_push_boxes_left_1", &robot.map);
         robot.step(Direction::Left).unwrap();
-        assert_eq!("#[][][]@..", robot.map.to_string().as_str());
+        assert_snapshot("day15_double_push_boxes_left_2", &robot.map);
     }
 
     #[test]
@@ -329,9 +384,9 @@ mod tests {
         };
 
         robot.step(Direction::Right).unwrap();
-        assert_eq!("#.@[][][].", robot.map.to_string().as_str());
+        assert_snapshot("day15_double_push_boxes_right_1", &robot.map);
         robot.step(Direction::Right).unwrap();
-        assert_eq!("#..@[][][]", robot.map.to_string().as_str());
+        assert_snapshot("day15_double_push_boxes_right_2", &robot.map);
     }
 
     #[test]
@@ -352,24 +407,10 @@ mod tests {
         };
 
         robot.step(Direction::Up).unwrap();
-        assert_eq!(
-            "...##
-[][].
-.[]..
-..@..
-.....",
-            robot.map.to_string().as_str()
-        );
+        assert_snapshot("day15_double_push_boxes_up_1", &robot.map);
 
         robot.step(Direction::Up).unwrap();
-        assert_eq!(
-            "...##
-[][].
-.[]..
-..@..
-.....",
-            robot.map.to_string().as_str()
-        );
+        assert_snapshot("day15_double_push_boxes_up_2", &robot.map);
     }
 
     #[test]
@@ -390,23 +431,32 @@ mod tests {
         };
 
         robot.step(Direction::Down).unwrap();
-        assert_eq!(
-            ".....
-..@..
-.[]..
-[][].
-...##",
-            robot.map.to_string().as_str()
-        );
+        assert_snapshot("day15_double_push_boxes_down_1", &robot.map);
 
         robot.step(Direction::Down).unwrap();
-        assert_eq!(
-            ".....
-..@..
-.[]..
-[][].
-...##",
-            robot.map.to_string().as_str()
-        );
+        assert_snapshot("day15_double_push_boxes_down_2", &robot.map);
+    }
+
+    #[test]
+    fn example_snapshots() {
+        let Input { map, directions } = INPUT_EXAMPLE.parse().unwrap();
+        let mut map = Map::from(map);
+        let mut robot = Robot {
+            position: map.find_robot_position().unwrap(),
+            map: &mut map,
+            steps_made: 0,
+        };
+
+        assert_snapshot("day15_double_map_example_start", &robot.map);
+        for (i, direction) in directions.into_iter().enumerate() {
+            robot.step(direction).unwrap();
+            if i + 1 == 10 || i + 1 == 100 {
+                assert_snapshot(
+                    &format!("day15_double_map_example_{}_moves", i + 1),
+                    &robot.map,
+                );
+            }
+        }
+        assert_snapshot("day15_double_map_example_final", &robot.map);
     }
 }
@@ -0,0 +1,127 @@
+use std::str::FromStr;
+
+use crate::days::day15::{
+    direction::{Direction, ParseDirectionError},
+    map::{Element, Map, ParseElementError},
+};
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub map: Map,
+    pub directions: Vec<Direction>,
+}
+
+#[derive(Debug)]
+pub enum ParseInputError {
+    MissingDirections,
+    Map(ParseElementError),
+    Direction(ParseDirectionError),
+    /// The warehouse needs exactly one robot; this is how many it has.
+    Robots(usize),
+    /// The warehouse isn't a rectangle enclosed by walls, so the robot could walk off it.
+    NotWalled,
+}
+
+impl FromStr for Input {
+    type Err = ParseInputError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (map_str, directions_str) = s
+            .split_once("\n\n")
+            .ok_or(ParseInputError::MissingDirections)?;
+        let map: Map = map_str.trim().parse().map_err(ParseInputError::Map)?;
+        let robots = map
+            .0
+            .iter()
+            .flatten()
+            .filter(|element| **element == Element::Robot)
+            .count();
+        if robots != 1 {
+            return Err(ParseInputError::Robots(robots));
+        }
+        if !is_walled(&map) {
+            return Err(ParseInputError::NotWalled);
+        }
+        let directions: Vec<Direction> = directions_str
+            .trim()
+            .replace("\n", "")
+            .chars()
+            .map(|ch| Direction::from_str(&ch.to_string()))
+            .collect::<Result<_, _>>()
+            .map_err(ParseInputError::Direction)?;
+
+        Ok(Input { map, directions })
+    }
+}
+
+fn is_walled(map: &Map) -> bool {
+    let wall = |element: &Element| *element == Element::Wall;
+    let (Some(first), Some(last)) = (map.0.first(), map.0.last()) else {
+        return false;
+    };
+
+    first.iter().all(wall)
+        && last.iter().all(wall)
+        && map.0.iter().all(|row| {
+            row.len() == first.len()
+                && row.first().is_some_and(wall)
+                && row.last().is_some_and(wall)
+        })
+}
+
+pub const INPUT_EXAMPLE: &str = "##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
+
+#[cfg(test)]
+mod tests {
+    use super::{Input, ParseInputError, INPUT_EXAMPLE};
+
+    #[test]
+    fn parse() {
+        assert!(INPUT_EXAMPLE.parse::<Input>().is_ok());
+    }
+
+    #[test]
+    fn one_robot() {
+        assert!(matches!(
+            "#####\n#...#\n#####\n\n<<".parse::<Input>(),
+            Err(ParseInputError::Robots(0))
+        ));
+        assert!(matches!(
+            "#####\n#@.@#\n#####\n\n<<".parse::<Input>(),
+            Err(ParseInputError::Robots(2))
+        ));
+    }
+
+    #[test]
+    fn walled() {
+        assert!(matches!(
+            "#@#\n\n<>^v".parse::<Input>(),
+            Err(ParseInputError::NotWalled)
+        ));
+        assert!(matches!(
+            "####\n#@.#\n#..\n####\n\n<".parse::<Input>(),
+            Err(ParseInputError::NotWalled)
+        ));
+        assert!("###\n#@#\n###\n\n<>^v".parse::<Input>().is_ok());
+    }
+}
@@ -1,4 +1,5 @@
-use crate::position::Position;
+use crate::days::day15::position::Position;
+use crate::grid::GridCell;
 use std::{fmt::Write, str::FromStr};
 
 #[derive(Debug, Clone)]
@@ -67,51 +68,21 @@ impl FromStr for Map {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, GridCell)]
 pub enum Element {
+    #[cell('.')]
     Empty,
+    #[cell('#')]
     Wall,
+    #[cell('O')]
     Box,
+    #[cell('@')]
     Robot,
 }
 
-impl Element {
-    fn as_char(&self) -> char {
-        match self {
-            Element::Empty => '.',
-            Element::Wall => '#',
-            Element::Box => 'O',
-            Element::Robot => '@',
-        }
-    }
-}
-
-impl std::fmt::Display for Element {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_char(self.as_char())
-    }
-}
-
-#[derive(Debug)]
-pub struct ParseElementError(pub String);
-
-impl FromStr for Element {
-    type Err = ParseElementError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "." => Ok(Element::Empty),
-            "#" => Ok(Element::Wall),
-            "O" => Ok(Element::Box),
-            "@" => Ok(Element::Robot),
-            _ => Err(ParseElementError(s.to_owned())),
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
-    use crate::map::Element;
+    use crate::days::day15::map::Element;
 
     use super::Map;
 
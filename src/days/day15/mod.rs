@@ -0,0 +1,79 @@
+use crate::{
+    budget::{Budget, BudgetExceeded},
+    simulation::Simulation,
+};
+use double::{map::Map as DoubleMap, robot::Moves as DoubleMoves};
+use input::Input;
+use robot::Moves;
+
+pub mod direction;
+pub mod double;
+pub mod input;
+pub mod map;
+pub mod position;
+pub mod robot;
+
+pub fn main() {
+    let input: Input = super::input(15).parse().unwrap();
+
+    println!("Day 15");
+    println!("Part 1: {}", part1(input.clone()));
+    println!("Part 2: {}", part2(input));
+}
+
+pub fn part1(input: Input) -> usize {
+    part1_with_budget(input, &Budget::unlimited()).unwrap()
+}
+
+pub fn part1_with_budget(input: Input, budget: &Budget) -> Result<usize, BudgetExceeded> {
+    let Input { map, directions } = input;
+    let mut moves = Moves::new(map, directions).unwrap();
+    make_all(&mut moves, budget)?;
+    Ok(moves.map.boxes_gps())
+}
+
+pub fn part2(input: Input) -> usize {
+    part2_with_budget(input, &Budget::unlimited()).unwrap()
+}
+
+pub fn part2_with_budget(input: Input, budget: &Budget) -> Result<usize, BudgetExceeded> {
+    Ok(final_double_map_with_budget(input, budget)?.boxes_gps())
+}
+
+/// The doubled warehouse after the robot made every move.
+pub fn final_double_map(input: Input) -> DoubleMap {
+    final_double_map_with_budget(input, &Budget::unlimited()).unwrap()
+}
+
+fn final_double_map_with_budget(
+    input: Input,
+    budget: &Budget,
+) -> Result<DoubleMap, BudgetExceeded> {
+    let Input { map, directions } = input;
+    let mut moves = DoubleMoves::new(DoubleMap::from(map), directions).unwrap();
+    make_all(&mut moves, budget)?;
+    Ok(moves.map)
+}
+
+/// Makes every remaining move, one budget step each.
+fn make_all<S: Simulation>(moves: &mut S, budget: &Budget) -> Result<(), BudgetExceeded> {
+    moves.run_n(u64::MAX, budget).map(drop).map_err(|err| {
+        let progress = format!("made {} moves", err.steps.saturating_sub(1));
+        err.with_progress(progress)
+    })
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::days::day15::{input::INPUT_EXAMPLE, part1, part2};
+
+    #[test]
+    fn p1() {
+        assert_eq!(10092, part1(INPUT_EXAMPLE.parse().unwrap()));
+    }
+
+    #[test]
+    fn p2() {
+        assert_eq!(9021, part2(INPUT_EXAMPLE.parse().unwrap()))
+    }
+}
@@ -1,7 +1,10 @@
 use crate::{
-    direction::{self, Direction},
-    map::{Element, Map},
-    position::Position,
+    days::day15::{
+        direction::{self, Direction},
+        map::{Element, Map},
+        position::Position,
+    },
+    simulation::Simulation,
 };
 
 #[derive(Debug)]
@@ -28,10 +31,13 @@ pub struct StepError {
     pub kind: StepErrorKind,
 }
 
+/// Shows only the map's size: the whole map is as big as the puzzle input and buries the
+/// rest of the error. `Display` the map when it's needed.
 impl std::fmt::Debug for StepError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = self.map.0.first().map_or(0, Vec::len);
         f.debug_struct("StepError")
-            .field("map", &format_args!("{}", self.map))
+            .field("map", &format_args!("{width}x{}", self.map.0.len()))
             .field("steps_made", &self.steps_made)
             .field("kind", &self.kind)
             .finish()
@@ -44,7 +50,7 @@ impl Robot<'_> {
         let next_position = direction::next_position(self.position, direction).unwrap();
         let el = self.map.get(next_position).unwrap();
         match el {
-            crate::map::Element::Empty => {
+            crate::days::day15::map::Element::Empty => {
                 // println!(
                 //     "Down. Position before: {position_before:?}. Current position: {next_position:?}"
                 // );
@@ -52,8 +58,8 @@ impl Robot<'_> {
                 self.position = next_position;
                 self.map.0[robot_position_before.y][robot_position_before.x] = Element::Empty;
             }
-            crate::map::Element::Wall => {}
-            crate::map::Element::Box => {
+            crate::days::day15::map::Element::Wall => {}
+            crate::days::day15::map::Element::Box => {
                 let mut position = next_position;
                 loop {
                     position = direction::next_position(position, direction).ok_or(StepError {
@@ -81,7 +87,7 @@ impl Robot<'_> {
                     }
                 }
             }
-            crate::map::Element::Robot => {
+            crate::days::day15::map::Element::Robot => {
                 return Err(StepError {
                     map: self.map.to_owned(),
                     steps_made: self.steps_made,
@@ -98,9 +104,54 @@ impl Robot<'_> {
     }
 }
 
+/// The robot making its list of moves one per tick, on a warehouse of its own.
+#[derive(Debug, Clone)]
+pub struct Moves {
+    pub map: Map,
+    pub position: Position,
+    pub directions: Vec<Direction>,
+    pub made: usize,
+}
+
+impl Moves {
+    pub fn new(map: Map, directions: Vec<Direction>) -> Option<Moves> {
+        Some(Moves {
+            position: map.find_robot_position()?,
+            map,
+            directions,
+            made: 0,
+        })
+    }
+}
+
+/// Every move is made once, so states of one run differ at least in the moves made.
+impl Simulation for Moves {
+    type Key = (usize, Position);
+
+    fn key(&self) -> Self::Key {
+        (self.made, self.position)
+    }
+
+    fn step(&mut self) {
+        let mut robot = Robot {
+            position: self.position,
+            map: &mut self.map,
+            steps_made: self.made as u32,
+        };
+        robot.step(self.directions[self.made]).unwrap();
+        self.position = robot.position;
+        self.made += 1;
+    }
+
+    fn is_terminal(&self) -> bool {
+        self.made == self.directions.len()
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{direction::Direction, map::Map};
+    use crate::days::day15::{direction::Direction, map::Map};
+    use crate::grid::assert_grid_str_eq;
 
     use super::Robot;
 
@@ -114,12 +165,12 @@ mod tests {
         };
 
         robot.step(Direction::Left).unwrap();
-        assert_eq!("#..OO@.", robot.map.to_string().as_str());
+        assert_grid_str_eq("#..OO@.", &robot.map.to_string());
         robot.step(Direction::Left).unwrap();
-        assert_eq!("#.OO@..", robot.map.to_string().as_str());
+        assert_grid_str_eq("#.OO@..", &robot.map.to_string());
         robot.step(Direction::Left).unwrap();
-        assert_eq!("#OO@...", robot.map.to_string().as_str());
+        assert_grid_str_eq("#OO@...", &robot.map.to_string());
         robot.step(Direction::Left).unwrap();
-        assert_eq!("#OO@...", robot.map.to_string().as_str());
+        assert_grid_str_eq("#OO@...", &robot.map.to_string());
     }
 }
@@ -1,4 +1,4 @@
-use crate::position::Position;
+use crate::days::day16::position::Position;
 use std::str::FromStr;
 
 pub const ROTATIONS: [Rotation; 2] = Rotation::rotations();
@@ -1,4 +1,5 @@
-use crate::position::Position;
+use crate::days::day16::position::Position;
+use crate::grid::GridCell;
 use std::{fmt::Write, str::FromStr};
 
 pub const MAP_EXAMPLE: &str = "###############
@@ -72,72 +73,71 @@ impl std::fmt::Display for Map {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseMapError {
+    Element(ParseElementError),
+    /// A maze needs exactly one start and one end; these are how many it has.
+    Ends {
+        starts: usize,
+        ends: usize,
+    },
+}
+
 impl FromStr for Map {
-    type Err = ParseElementError;
+    type Err = ParseMapError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Map(s
+        let map = Map(s
             .lines()
             .map(|line| {
                 line.chars()
                     .map(|ch| Element::from_str(&ch.to_string()))
                     .collect::<Result<Vec<Element>, ParseElementError>>()
             })
-            .collect::<Result<Vec<Vec<Element>>, ParseElementError>>(
-            )?))
+            .collect::<Result<Vec<Vec<Element>>, ParseElementError>>()
+            .map_err(ParseMapError::Element)?);
+
+        let count = |wanted: Element| map.0.iter().flatten().filter(|el| **el == wanted).count();
+        match (count(Element::Start), count(Element::End)) {
+            (1, 1) => Ok(map),
+            (starts, ends) => Err(ParseMapError::Ends { starts, ends }),
+        }
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, GridCell)]
 pub enum Element {
+    #[cell('.')]
     Empty,
+    #[cell('#')]
     Wall,
+    #[cell('S')]
     Start,
+    #[cell('E')]
     End,
 }
 
-impl Element {
-    fn as_char(&self) -> char {
-        match self {
-            Element::Empty => '.',
-            Element::Wall => '#',
-            Element::Start => 'S',
-            Element::End => 'E',
-        }
-    }
-}
-
-impl std::fmt::Display for Element {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_char(self.as_char())
-    }
-}
-
-#[derive(Debug)]
-pub struct ParseElementError(pub String);
-
-impl FromStr for Element {
-    type Err = ParseElementError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "." => Ok(Element::Empty),
-            "#" => Ok(Element::Wall),
-            "S" => Ok(Element::Start),
-            "E" => Ok(Element::End),
-            _ => Err(ParseElementError(s.to_owned())),
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
-    use super::Map;
-    use crate::map::MAP_EXAMPLE;
+    use super::{Map, ParseMapError};
+    use crate::days::day16::map::MAP_EXAMPLE;
+    use crate::grid::assert_grid_str_eq;
 
     #[test]
     fn parse() {
         let map: Map = MAP_EXAMPLE.parse().unwrap();
-        println!("{map}");
+        assert_grid_str_eq(MAP_EXAMPLE, &map.to_string());
+    }
+
+    #[test]
+    fn one_start_and_end() {
+        assert_eq!(
+            Err(ParseMapError::Ends { starts: 0, ends: 0 }),
+            "####\n#..#\n####".parse::<Map>().map(drop)
+        );
+        assert_eq!(
+            Err(ParseMapError::Ends { starts: 1, ends: 2 }),
+            "#####\n#SEE#\n#####".parse::<Map>().map(drop)
+        );
     }
 }
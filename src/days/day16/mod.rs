@@ -0,0 +1,61 @@
+use direction::Direction;
+use map::Map;
+use walker::{tile_map::TileMap, Solution};
+
+pub mod direction;
+pub mod map;
+pub mod position;
+pub mod walker;
+
+pub fn main() {
+    let map: Map = super::input(16).parse().unwrap();
+
+    println!("Day 16");
+    match part1(&map) {
+        Some(score) => println!("Part 1: {score}"),
+        None => println!("Part 1: the end can't be reached"),
+    }
+    println!("Part 2: {}", part2(&map));
+    println!("{}", best_path_tiles(&map));
+}
+
+/// The lowest score from start to end, or `None` when the end can't be reached.
+pub fn part1(map: &Map) -> Option<u32> {
+    let start = map.find_start_position().unwrap();
+    let solution = Solution {
+        position: start,
+        map,
+        start,
+        moves: vec![],
+        direction: Direction::Right,
+    };
+
+    let complete_solutions = solution.explore_solutions();
+    complete_solutions.iter().map(|s| s.score()).min()
+}
+
+pub fn best_path_tiles(map: &Map) -> TileMap {
+    let start = map.find_start_position().unwrap();
+    let solution = Solution {
+        position: start,
+        map,
+        start,
+        moves: vec![],
+        direction: Direction::Right,
+    };
+
+    solution.best_path_tiles()
+}
+
+pub fn part2(map: &Map) -> usize {
+    let start = map.find_start_position().unwrap();
+    let solution = Solution {
+        position: start,
+        map,
+        start,
+        moves: vec![],
+        direction: Direction::Right,
+    };
+
+    solution.explore_part2()
+}
@@ -1,12 +1,15 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{HashMap, VecDeque};
 
 use solution_map::SolutionMap;
-use tile_map::TileMap;
+use tile_map::{TileMap, TileMapElement};
 
 use crate::{
-    direction::{self, Direction, Rotation},
-    map::{Element, Map},
-    position::Position,
+    days::day16::{
+        direction::{self, Direction, Rotation},
+        map::{Element, Map},
+        position::Position,
+    },
+    grid::mask::GridMask,
 };
 
 #[derive(Debug, Clone)]
@@ -32,6 +35,16 @@ impl Solution<'_> {
 
 impl Solution<'_> {
     pub fn explore_part2(&self) -> usize {
+        self.best_path_tiles()
+            .0
+            .iter()
+            .flatten()
+            .filter(|el| **el == TileMapElement::Tile)
+            .count()
+    }
+
+    /// The map with every tile that lies on at least one best path marked.
+    pub fn best_path_tiles(&self) -> TileMap {
         let mut complete: Vec<Solution> = vec![];
         let mut queue: VecDeque<Solution> = VecDeque::from_iter(vec![self.clone()]);
         let mut visited: HashMap<Visited, u32> = HashMap::new();
@@ -58,7 +71,7 @@ impl Solution<'_> {
 
                         if visited
                             .get(&visited_entry)
-                            .map_or(true, |&prev_score| current_score <= prev_score)
+                            .is_none_or(|&prev_score| current_score <= prev_score)
                         {
                             visited.insert(visited_entry, current_score);
                             queue.push_back(new_solution);
@@ -80,7 +93,7 @@ impl Solution<'_> {
 
                 if visited
                     .get(&visited_entry)
-                    .map_or(true, |&prev_score| current_score <= prev_score)
+                    .is_none_or(|&prev_score| current_score <= prev_score)
                 {
                     visited.insert(visited_entry, current_score);
                     queue.push_back(new_solution);
@@ -88,28 +101,22 @@ impl Solution<'_> {
             }
         }
 
+        // An unreachable end leaves no best path, and no tiles on one.
         complete.sort_by_key(|a| a.score());
-        let first_min_solution = complete.first().unwrap();
-        let first_min_solution_score = complete.first().unwrap().score();
-
-        let positions = complete
-            .clone()
-            .into_iter()
-            .take_while(|solution| solution.score() == first_min_solution_score)
-            .flat_map(|solution| SolutionMap::new(&solution).steps_positions())
-            .collect::<HashSet<_>>();
-
-        println!(
-            "{}",
-            TileMap::new(
-                first_min_solution.map.clone(),
-                positions.clone().into_iter().collect()
-            )
-        );
-        positions.len()
+        let min_score = complete.first().map(Solution::score);
+
+        let mut tiles = GridMask::for_grid(&self.map.0);
+        for solution in complete
+            .iter()
+            .take_while(|solution| Some(solution.score()) == min_score)
+        {
+            tiles.union_with(&SolutionMap::new(solution).steps());
+        }
+
+        TileMap::new(self.map.clone(), &tiles)
     }
 
-    pub fn explore_solutions(&self) -> Vec<Solution> {
+    pub fn explore_solutions(&self) -> Vec<Solution<'_>> {
         let mut complete: Vec<Solution> = vec![];
         let mut queue: VecDeque<Solution> = VecDeque::from_iter(vec![self.clone()]);
         let mut visited: HashMap<Visited, u32> = HashMap::new();
@@ -137,7 +144,7 @@ impl Solution<'_> {
 
                         if visited
                             .get(&visited_entry)
-                            .map_or(true, |&prev_score| current_score < prev_score)
+                            .is_none_or(|&prev_score| current_score < prev_score)
                         {
                             visited.insert(visited_entry, current_score);
                             scored
@@ -163,7 +170,7 @@ impl Solution<'_> {
 
                 if visited
                     .get(&visited_entry)
-                    .map_or(true, |&prev_score| current_score < prev_score)
+                    .is_none_or(|&prev_score| current_score < prev_score)
                 {
                     visited.insert(visited_entry, current_score);
                     scored
@@ -197,10 +204,10 @@ impl std::fmt::Display for Solution<'_> {
     }
 }
 
-mod tile_map {
+pub mod tile_map {
     use crate::{
-        map::{Element, Map},
-        position::Position,
+        days::day16::map::{Element, Map},
+        grid::mask::GridMask,
     };
     use std::fmt::Write;
 
@@ -208,11 +215,11 @@ mod tile_map {
     pub struct TileMap(pub Vec<Vec<TileMapElement>>);
 
     impl TileMap {
-        pub fn new(map: Map, tiles_positions: Vec<Position>) -> TileMap {
+        pub fn new(map: Map, tiles: &GridMask) -> TileMap {
             let mut map = TileMap::from(map);
-            tiles_positions
-                .into_iter()
-                .for_each(|Position { x, y }| map.0[y][x] = TileMapElement::Tile);
+            tiles
+                .iter()
+                .for_each(|(x, y)| map.0[y][x] = TileMapElement::Tile);
             map
         }
     }
@@ -285,13 +292,15 @@ mod tile_map {
     }
 }
 
-mod solution_map {
+pub mod solution_map {
     use super::Solution;
     use crate::{
-        direction::{self, Direction},
-        map::{Element, Map},
-        position::Position,
-        walker::Move,
+        days::day16::{
+            direction::{self, Direction},
+            map::{Element, Map},
+            walker::Move,
+        },
+        grid::mask::GridMask,
     };
     use std::fmt::Write;
 
@@ -299,20 +308,17 @@ mod solution_map {
     pub struct SolutionMap(pub Vec<Vec<SolutionMapElement>>);
 
     impl SolutionMap {
-        pub fn steps_positions(&self) -> Vec<Position> {
-            self.0
-                .iter()
-                .enumerate()
-                .flat_map(|(y, row)| {
-                    row.iter().enumerate().filter_map(move |(x, el)| {
-                        if let SolutionMapElement::Direction(_) = el {
-                            return Some(Position { x, y });
-                        }
-
-                        None
-                    })
-                })
-                .collect()
+        /// The cells the solution walks through.
+        pub fn steps(&self) -> GridMask {
+            let mut steps = GridMask::for_grid(&self.0);
+            for (y, row) in self.0.iter().enumerate() {
+                for (x, el) in row.iter().enumerate() {
+                    if let SolutionMapElement::Direction(_) = el {
+                        steps.insert(x, y);
+                    }
+                }
+            }
+            steps
         }
 
         pub fn new(solution: &Solution) -> SolutionMap {
@@ -411,10 +417,11 @@ mod solution_map {
 #[cfg(test)]
 mod tests {
     use super::Solution;
-    use crate::{
+    use crate::days::day16::{
         direction::Direction,
         map::{Map, MAP_EXAMPLE, MAP_EXAMPLE2},
     };
+    use crate::snapshot::assert_snapshot;
 
     #[test]
     fn explore_solutions() {
@@ -457,4 +464,27 @@ mod tests {
         };
         assert_eq!(64, solution.explore_part2());
     }
+
+    #[test]
+    fn best_path_snapshots() {
+        for (name, example) in [("example", MAP_EXAMPLE), ("example2", MAP_EXAMPLE2)] {
+            let map: Map = example.parse().unwrap();
+            let start = map.find_start_position().unwrap();
+            let solution = Solution {
+                position: start,
+                map: &map,
+                start,
+                moves: vec![],
+                direction: Direction::Right,
+            };
+
+            let complete_solutions = solution.explore_solutions();
+            let best = complete_solutions.iter().min_by_key(|s| s.score()).unwrap();
+            assert_snapshot(&format!("day16_solution_map_{name}"), best);
+            assert_snapshot(
+                &format!("day16_tile_map_{name}"),
+                solution.best_path_tiles(),
+            );
+        }
+    }
 }
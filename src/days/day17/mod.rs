@@ -0,0 +1,389 @@
+use crate::{
+    budget::{Budget, BudgetExceeded},
+    parse::{
+        alt, lines, map, pair, parse_all, preceded, separated, tag, terminated, try_map, unsigned,
+        Input, ParseError, Parsed,
+    },
+    simulation::Simulation,
+};
+use std::str::FromStr;
+
+pub const EXAMPLE: &str = "Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0";
+
+pub fn main() {
+    println!("Day 17");
+
+    println!(
+        "Part 1: {}",
+        super::input(17).parse::<Computer>().unwrap().run_program()
+    )
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Computer {
+    pub a: RegisterA,
+    pub b: RegisterB,
+    pub c: RegisterC,
+    pub program: Program,
+}
+
+impl Computer {
+    pub fn operand_value(&self, operand: Operand) -> u32 {
+        match operand.0 {
+            n if (0..=3).contains(&n) => n as u32,
+            4 => self.a.0,
+            5 => self.b.0,
+            6 => self.c.0,
+            _ => 7,
+        }
+    }
+
+    pub fn run_program(&mut self) -> String {
+        self.run_program_with_budget(&Budget::unlimited()).unwrap()
+    }
+
+    pub fn run_program_with_budget(&mut self, budget: &Budget) -> Result<String, BudgetExceeded> {
+        let mut execution = Execution::new(self.clone());
+        // Runs until the program halts; only the budget stops one that never does.
+        let result = execution.run_n(u64::MAX, budget);
+        let output = join_output(&execution.output);
+        let result = result.map_err(|err| {
+            err.with_progress(format!(
+                "instruction pointer at {}, output so far: {output}",
+                execution.instruction_pointer
+            ))
+        });
+        *self = execution.computer;
+
+        result.map(|_| output)
+    }
+
+    pub fn execute_instruction(
+        &mut self,
+        instruction_pointer: &mut usize,
+        (instruction, operand): &(Instruction, Operand),
+    ) -> Option<u32> {
+        let value = self.operand_value(*operand);
+        *instruction_pointer += 2;
+        match instruction {
+            Instruction::Adv => self.a.0 = divide_by_power_of_two(self.a.0, value),
+            Instruction::Bxl => self.b.0 ^= value,
+            Instruction::Bst => self.b.0 = value % 8,
+            Instruction::Jnz => {
+                if self.a.0 == 0 {
+                    return None;
+                }
+
+                *instruction_pointer = value as usize;
+            }
+            Instruction::Bxc => self.b.0 ^= self.c.0,
+            Instruction::Out => return Some(value % 8),
+            Instruction::Bdv => self.b.0 = divide_by_power_of_two(self.a.0, value),
+            Instruction::Cdv => self.c.0 = divide_by_power_of_two(self.a.0, value),
+        }
+
+        None
+    }
+}
+
+/// `numerator / 2^exponent`, which is zero once the power no longer fits in a `u32`.
+fn divide_by_power_of_two(numerator: u32, exponent: u32) -> u32 {
+    numerator.checked_shr(exponent).unwrap_or(0)
+}
+
+/// A program running one instruction per tick.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Execution {
+    pub computer: Computer,
+    pub instruction_pointer: usize,
+    pub output: Vec<u32>,
+}
+
+impl Execution {
+    pub fn new(computer: Computer) -> Execution {
+        Execution {
+            computer,
+            instruction_pointer: 0,
+            output: Vec::new(),
+        }
+    }
+}
+
+/// The output is only ever appended to and never read back, so the registers and the
+/// instruction pointer decide everything that follows.
+impl Simulation for Execution {
+    type Key = (u32, u32, u32, usize);
+
+    fn key(&self) -> Self::Key {
+        (
+            self.computer.a.0,
+            self.computer.b.0,
+            self.computer.c.0,
+            self.instruction_pointer,
+        )
+    }
+
+    fn step(&mut self) {
+        let instruction = self.computer.program.0[self.instruction_pointer / 2].clone();
+        if let Some(out) = self
+            .computer
+            .execute_instruction(&mut self.instruction_pointer, &instruction)
+        {
+            self.output.push(out);
+        }
+    }
+
+    fn is_terminal(&self) -> bool {
+        self.instruction_pointer / 2 >= self.computer.program.0.len()
+    }
+}
+
+fn join_output(output: &[u32]) -> String {
+    output
+        .iter()
+        .map(|n| n.to_string())
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+impl FromStr for Computer {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (registers, program) = parse_all(
+            s,
+            pair(terminated(lines(register()), tag("\n\n")), program()),
+        )?;
+
+        let mut computer = Computer {
+            a: RegisterA(0),
+            b: RegisterB(0),
+            c: RegisterC(0),
+            program,
+        };
+        for register in registers {
+            match register {
+                Register::A(a) => computer.a = a,
+                Register::B(b) => computer.b = b,
+                Register::C(c) => computer.c = c,
+            }
+        }
+        Ok(computer)
+    }
+}
+
+/// A `Register A: 729` line. Registers that are left out start at zero.
+enum Register {
+    A(RegisterA),
+    B(RegisterB),
+    C(RegisterC),
+}
+
+fn register<'a>() -> impl Fn(Input<'a>) -> Parsed<'a, Register> {
+    alt(
+        map(preceded(tag("Register A: "), unsigned()), |value| {
+            Register::A(RegisterA(value))
+        }),
+        alt(
+            map(preceded(tag("Register B: "), unsigned()), |value| {
+                Register::B(RegisterB(value))
+            }),
+            map(preceded(tag("Register C: "), unsigned()), |value| {
+                Register::C(RegisterC(value))
+            }),
+        ),
+    )
+}
+
+/// `Program: 0,1,5,4,3,0`, read as instruction and operand pairs.
+fn program<'a>() -> impl Fn(Input<'a>) -> Parsed<'a, Program> {
+    let instruction = try_map(unsigned::<u8>(), |code| {
+        Instruction::try_from(code).map_err(|_| "an instruction code from 0 to 7".to_owned())
+    });
+    let operand = try_map(unsigned::<u8>(), |operand| match operand {
+        0..=7 => Ok(Operand(operand)),
+        _ => Err("an operand from 0 to 7".to_owned()),
+    });
+    let step = pair(terminated(instruction, tag(",")), operand);
+    map(
+        preceded(tag("Program: "), separated(step, tag(","))),
+        Program,
+    )
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RegisterA(pub u32);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RegisterB(pub u32);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RegisterC(pub u32);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Operand(pub u8);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Program(pub Vec<(Instruction, Operand)>);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Instruction {
+    /// The adv instruction (opcode 0) performs division. The numerator is the value in the A register.
+    /// The denominator is found by raising 2 to the power of the instruction's combo operand.
+    /// (So, an operand of 2 would divide A by 4 (2^2); an operand of 5 would divide A by 2^B.)
+    /// The result of the division operation is truncated to an integer and then written to the A register.
+    Adv,
+    /// The bxl instruction (opcode 1) calculates the bitwise XOR of register B and the instruction's literal operand,
+    /// then stores the result in register B.
+    Bxl,
+    /// The bst instruction (opcode 2) calculates the value of its combo operand modulo 8
+    /// (thereby keeping only its lowest 3 bits), then writes that value to the B register.
+    Bst,
+    /// The jnz instruction (opcode 3) does nothing if the A register is 0.
+    /// However, if the A register is not zero, it jumps by setting the instruction pointer to the value of its literal operand;
+    /// if this instruction jumps, the instruction pointer is not increased by 2 after this instruction.
+    Jnz,
+    /// The bxc instruction (opcode 4) calculates the bitwise XOR of register B and register C,
+    /// then stores the result in register B. (For legacy reasons, this instruction reads an operand but ignores it.)
+    Bxc,
+    /// The out instruction (opcode 5) calculates the value of its combo operand modulo 8, then outputs that value.
+    /// (If a program outputs multiple values, they are separated by commas.)
+    Out,
+    /// The bdv instruction (opcode 6) works exactly like the adv instruction except that the result is stored in the B register.
+    /// (The numerator is still read from the A register.)
+    Bdv,
+    /// The cdv instruction (opcode 7) works exactly like the adv instruction except that the result is stored in the C register.
+    /// (The numerator is still read from the A register.)
+    Cdv,
+}
+
+#[derive(Debug)]
+pub struct InstructionCodeShouldBeBetweenZeroAndSeven(pub u8);
+
+impl TryFrom<u8> for Instruction {
+    type Error = InstructionCodeShouldBeBetweenZeroAndSeven;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Instruction::Adv),
+            1 => Ok(Instruction::Bxl),
+            2 => Ok(Instruction::Bst),
+            3 => Ok(Instruction::Jnz),
+            4 => Ok(Instruction::Bxc),
+            5 => Ok(Instruction::Out),
+            6 => Ok(Instruction::Bdv),
+            7 => Ok(Instruction::Cdv),
+            _ => Err(InstructionCodeShouldBeBetweenZeroAndSeven(value)),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        budget::Budget,
+        days::day17::{
+            Computer, Execution, Instruction, Operand, Program, RegisterA, RegisterB, RegisterC,
+            EXAMPLE,
+        },
+        simulation::{brent, Cycle},
+    };
+
+    #[test]
+    fn parse() {
+        let computer: Computer = EXAMPLE.parse().unwrap();
+        assert_eq!(
+            Computer {
+                a: RegisterA(729),
+                b: RegisterB(0),
+                c: RegisterC(0),
+                program: Program(vec![
+                    (Instruction::Adv, Operand(1)),
+                    (Instruction::Out, Operand(4)),
+                    (Instruction::Jnz, Operand(0))
+                ])
+            },
+            computer
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = "Register A: 1\n\nProgram: 0,1,8,2"
+            .parse::<Computer>()
+            .unwrap_err();
+        assert_eq!("an instruction code from 0 to 7", err.expected);
+        assert_eq!((3, 14), (err.line, err.column));
+
+        let err = "Register D: 1\n\nProgram: 0,1"
+            .parse::<Computer>()
+            .unwrap_err();
+        assert_eq!(
+            "\"Register A: \" or \"Register B: \" or \"Register C: \"",
+            err.expected
+        );
+
+        let err = "Register A: 1\n\nProgram: 0,9"
+            .parse::<Computer>()
+            .unwrap_err();
+        assert_eq!("an operand from 0 to 7", err.expected);
+    }
+
+    #[test]
+    fn large_shifts() {
+        let mut computer: Computer = "Register A: 7
+Register B: 40
+
+Program: 0,5"
+            .parse()
+            .unwrap();
+        computer.run_program();
+        assert_eq!(RegisterA(0), computer.a);
+    }
+
+    #[test]
+    fn execute() {
+        let mut computer: Computer = "Register C: 9\n\nProgram: 2,6".parse().unwrap();
+        computer.run_program();
+        assert_eq!(RegisterB(1), computer.b);
+
+        let mut computer: Computer = "Register A: 10\n\nProgram: 5,0,5,1,5,4".parse().unwrap();
+        assert_eq!("0,1,2".to_owned(), computer.run_program());
+
+        let mut computer: Computer = "Register A: 2024\n\nProgram: 0,1,5,4,3,0".parse().unwrap();
+        assert_eq!("4,2,5,6,7,7,7,7,3,1,0".to_owned(), computer.run_program());
+
+        let mut computer: Computer = "Register B: 29\n\nProgram: 1,7".parse().unwrap();
+        computer.run_program();
+        assert_eq!(RegisterB(26), computer.b);
+
+        let mut computer: Computer = "Register B: 2024\nRegister C: 43690\n\nProgram: 4,0"
+            .parse()
+            .unwrap();
+        computer.run_program();
+        assert_eq!(RegisterB(44354), computer.b);
+
+        let mut computer: Computer = EXAMPLE.parse().unwrap();
+        assert_eq!("4,6,3,5,6,3,5,2,1,0", computer.run_program())
+    }
+
+    #[test]
+    fn loop_detection() {
+        let halting = Execution::new(EXAMPLE.parse().unwrap());
+        assert_eq!(None, brent(&halting, &Budget::unlimited()).unwrap());
+
+        // Flips B between 0 and 1 forever: bxl 1, bst B, jnz 0.
+        let looping = Execution::new("Register A: 1\n\nProgram: 1,1,2,5,3,0".parse().unwrap());
+        assert_eq!(
+            Some(Cycle {
+                start: 0,
+                length: 6
+            }),
+            brent(&looping, &Budget::unlimited()).unwrap()
+        );
+    }
+}
@@ -0,0 +1,276 @@
+use crate::budget::{Budget, BudgetExceeded};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+
+/// Solves one part of a puzzle for the given input.
+pub type Part = fn(&str, &Budget) -> Result<String, PartError>;
+
+/// Why a part has no answer.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PartError {
+    /// The day's parser rejects the input.
+    InvalidInput(String),
+    BudgetExceeded(BudgetExceeded),
+}
+
+impl From<BudgetExceeded> for PartError {
+    fn from(exceeded: BudgetExceeded) -> PartError {
+        PartError::BudgetExceeded(exceeded)
+    }
+}
+
+/// Runs a day's input parser on its own and throws the result away.
+pub type Parse = fn(&str) -> Result<(), String>;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Input {
+    File(&'static str),
+    Inline(&'static str),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub number: u8,
+    pub input: Input,
+    pub parse: Parse,
+    pub part1: Part,
+    pub part2: Option<Part>,
+}
+
+impl Day {
+    pub fn parts(&self) -> impl Iterator<Item = (u8, Part)> {
+        std::iter::once((1, self.part1)).chain(self.part2.map(|part| (2, part)))
+    }
+
+    /// Reads the input, decrypting it when only an encrypted copy exists, see [`crate::crypt`].
+    pub fn load_input(&self) -> std::io::Result<String> {
+        match self.input {
+            Input::File(path) => crate::crypt::read_input(path),
+            Input::Inline(input) => Ok(input.to_owned()),
+        }
+    }
+}
+
+fn parsed<T, E: std::fmt::Debug>(result: Result<T, E>) -> Result<(), String> {
+    result.map(drop).map_err(|err| format!("{err:?}"))
+}
+
+/// The parsed input for a part, or the parser's error when it rejects the input.
+fn valid<T, E: std::fmt::Debug>(result: Result<T, E>) -> Result<T, PartError> {
+    result.map_err(|err| PartError::InvalidInput(format!("{err:?}")))
+}
+
+pub fn get(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+/// Day `number`'s input as [`DAYS`] locates it, for the day's own binary.
+fn input(number: u8) -> String {
+    get(number)
+        .expect("day should be registered")
+        .load_input()
+        .unwrap_or_else(|err| panic!("Could not read input for day {number}: {err}"))
+}
+
+pub const DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        input: Input::File("./files/01/lists.txt"),
+        parse: |input| parsed(day01::read_input(input)),
+        part1: |input, _| {
+            let (l1, l2) = valid(day01::read_input(input))?;
+            Ok(day01::distance(&l1, &l2).to_string())
+        },
+        part2: Some(|input, _| {
+            let (l1, l2) = valid(day01::read_input(input))?;
+            Ok(day01::similarity_score(&l1, &l2).to_string())
+        }),
+    },
+    Day {
+        number: 2,
+        input: Input::File("./files/02.txt"),
+        parse: |input| parsed(day02::read_input(input)),
+        part1: |input, _| {
+            let reports = valid(day02::read_input(input))?;
+            Ok(day02::count_safe_reports(&reports).to_string())
+        },
+        part2: Some(|input, _| {
+            let reports = valid(day02::read_input(input))?;
+            Ok(day02::count_safe_reports_with_problem_dampener(&reports).to_string())
+        }),
+    },
+    Day {
+        number: 3,
+        input: Input::File("./files/03.txt"),
+        parse: |input| {
+            day03::part_1::find_correct_muls(input);
+            Ok(())
+        },
+        part1: |input, _| Ok(day03::part_1::calc_input_muls(input).to_string()),
+        part2: Some(|input, _| Ok(day03::part_2::calc_input_muls(input).to_string())),
+    },
+    Day {
+        number: 4,
+        input: Input::File("./files/04.txt"),
+        parse: |input| parsed(day04::parse_input(input)),
+        part1: |input, _| {
+            let board = day04::Board(valid(day04::parse_input(input))?);
+            Ok(day04::count_xmas(&board).to_string())
+        },
+        part2: Some(|input, _| {
+            let board = day04::Board(valid(day04::parse_input(input))?);
+            Ok(day04::count_xmas_crosses(&board).to_string())
+        }),
+    },
+    Day {
+        number: 5,
+        input: Input::File("./files/05.txt"),
+        parse: |input| parsed(day05::read_input(input)),
+        part1: |input, _| Ok(day05::part1(&valid(day05::read_input(input))?).to_string()),
+        part2: Some(|input, _| {
+            Ok(day05::part2::main(&valid(day05::read_input(input))?).to_string())
+        }),
+    },
+    Day {
+        number: 6,
+        input: Input::File("./files/06.txt"),
+        parse: |input| parsed(input.parse::<day06::map::Map>()),
+        part1: |input, budget| {
+            Ok(
+                match day06::part1_with_budget(&valid(input.parse())?, budget)? {
+                    Some(visited) => visited.to_string(),
+                    None => "guard loops".to_owned(),
+                },
+            )
+        },
+        part2: Some(|input, budget| {
+            Ok(day06::part2_with_budget(&valid(input.parse())?, budget)?.to_string())
+        }),
+    },
+    Day {
+        number: 7,
+        input: Input::File("./files/07.txt"),
+        parse: |input| parsed(day07::equation::parse_equations(input)),
+        part1: |input, _| {
+            Ok(day07::part1(&valid(day07::equation::parse_equations(input))?).to_string())
+        },
+        part2: Some(|input, _| {
+            let equations = valid(day07::part2::equation::parse_equations(input))?;
+            Ok(day07::part2(&equations).to_string())
+        }),
+    },
+    Day {
+        number: 8,
+        input: Input::File("./files/08.txt"),
+        parse: |input| parsed(input.parse::<day08::map::Map>()),
+        part1: |input, _| Ok(day08::part1(&valid(input.parse())?).to_string()),
+        part2: Some(|input, _| Ok(day08::part2(&valid(input.parse())?).to_string())),
+    },
+    Day {
+        number: 9,
+        input: Input::File("./files/09.txt"),
+        parse: |input| parsed(day09::map::Map::from_input(input)),
+        part1: |input, _| Ok(day09::part1(valid(day09::map::Map::from_input(input))?).to_string()),
+        part2: Some(|input, _| {
+            Ok(day09::part2(valid(day09::part2::Map::from_input(input))?).to_string())
+        }),
+    },
+    Day {
+        number: 10,
+        input: Input::File("./files/10.txt"),
+        parse: |input| parsed(input.parse::<day10::map::Map>()),
+        part1: |input, _| Ok(day10::part1(&valid(input.parse())?).to_string()),
+        part2: Some(|input, _| Ok(day10::part2(&valid(input.parse())?).to_string())),
+    },
+    Day {
+        number: 11,
+        input: Input::File("./files/11.txt"),
+        parse: |input| parsed(day11::parse_input(input)),
+        part1: |input, _| Ok(day11::part1(&valid(day11::parse_input(input))?, 25).to_string()),
+        part2: Some(|input, _| {
+            Ok(day11::part2(valid(day11::Occurences::from_input(input))?, 75).to_string())
+        }),
+    },
+    Day {
+        number: 12,
+        input: Input::File("./files/12.txt"),
+        parse: |input| parsed(input.parse::<day12::garden::Garden>()),
+        part1: |input, _| {
+            let garden: day12::garden::Garden = valid(input.parse())?;
+            Ok(garden.fence_price().to_string())
+        },
+        part2: None,
+    },
+    Day {
+        number: 13,
+        input: Input::File("./files/13.txt"),
+        parse: |input| parsed(day13::part1::parse_input(input)),
+        part1: |input, _| Ok(day13::part1(&valid(day13::part1::parse_input(input))?).to_string()),
+        part2: None,
+    },
+    Day {
+        number: 14,
+        input: Input::File("./files/14.txt"),
+        parse: |input| parsed(day14::grid::Grid::from_robots_input(input, day14::SIZE)),
+        part1: |input, _| {
+            let grid = valid(day14::grid::Grid::from_robots_input(input, day14::SIZE))?;
+            Ok(day14::part1(grid).to_string())
+        },
+        part2: Some(|input, budget| {
+            let grid = valid(day14::grid::Grid::from_robots_input(input, day14::SIZE))?;
+            Ok(match day14::part2_with_budget(grid, budget)? {
+                Some((_, seconds_passed)) => seconds_passed.to_string(),
+                None => "no tree".to_owned(),
+            })
+        }),
+    },
+    Day {
+        number: 15,
+        input: Input::File("./files/15.txt"),
+        parse: |input| parsed(input.parse::<day15::input::Input>()),
+        part1: |input, budget| {
+            Ok(day15::part1_with_budget(valid(input.parse())?, budget)?.to_string())
+        },
+        part2: Some(|input, budget| {
+            Ok(day15::part2_with_budget(valid(input.parse())?, budget)?.to_string())
+        }),
+    },
+    Day {
+        number: 16,
+        input: Input::File("./files/16.txt"),
+        parse: |input| parsed(input.parse::<day16::map::Map>()),
+        part1: |input, _| {
+            Ok(match day16::part1(&valid(input.parse())?) {
+                Some(score) => score.to_string(),
+                None => "no path".to_owned(),
+            })
+        },
+        part2: Some(|input, _| Ok(day16::part2(&valid(input.parse())?).to_string())),
+    },
+    Day {
+        number: 17,
+        input: Input::File("./files/17.txt"),
+        parse: |input| parsed(input.parse::<day17::Computer>()),
+        part1: |input, budget| {
+            let mut computer: day17::Computer = valid(input.parse())?;
+            Ok(computer.run_program_with_budget(budget)?)
+        },
+        part2: None,
+    },
+];
@@ -0,0 +1,229 @@
+//! Cell-by-cell comparison of two text grids, rendered side by side.
+
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const RESET: &str = "\x1b[0m";
+
+/// How many differing cells are listed by coordinate before the list is cut short.
+const MAX_LISTED_CELLS: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CellDiff {
+    pub x: usize,
+    pub y: usize,
+    pub expected: Option<char>,
+    pub actual: Option<char>,
+}
+
+/// Cells that differ between two renderings; a missing cell (shorter row or fewer rows) is `None`.
+pub fn cell_diffs(expected: &str, actual: &str) -> Vec<CellDiff> {
+    let expected: Vec<Vec<char>> = expected.lines().map(|l| l.chars().collect()).collect();
+    let actual: Vec<Vec<char>> = actual.lines().map(|l| l.chars().collect()).collect();
+
+    let mut diffs = Vec::new();
+    for y in 0..expected.len().max(actual.len()) {
+        let expected_row = expected.get(y).map(Vec::as_slice).unwrap_or_default();
+        let actual_row = actual.get(y).map(Vec::as_slice).unwrap_or_default();
+        for x in 0..expected_row.len().max(actual_row.len()) {
+            let (expected, actual) = (expected_row.get(x).copied(), actual_row.get(x).copied());
+            if expected != actual {
+                diffs.push(CellDiff {
+                    x,
+                    y,
+                    expected,
+                    actual,
+                });
+            }
+        }
+    }
+
+    diffs
+}
+
+/// Whether diffs should be coloured: on unless `NO_COLOR` is set.
+pub fn use_color() -> bool {
+    std::env::var_os("NO_COLOR").is_none()
+}
+
+/// Renders `expected` and `actual` next to each other with differing cells highlighted,
+/// followed by their coordinates. Returns `None` only when both texts are identical, line
+/// terminators included; texts that differ in nothing but those say where they do.
+pub fn grid_diff(expected: &str, actual: &str, color: bool) -> Option<String> {
+    if expected == actual {
+        return None;
+    }
+    let diffs = cell_diffs(expected, actual);
+    let mut out = render(expected, actual, &diffs, color);
+    if diffs.is_empty() {
+        out.push_str(&match line_ending_diff(expected, actual) {
+            Some((y, expected, actual)) => {
+                let show = |ending: Option<&str>| match ending {
+                    Some("") => "no line ending".to_owned(),
+                    Some(ending) => format!("{ending:?}"),
+                    None => "no line".to_owned(),
+                };
+                format!(
+                    "line endings differ at y={y}: expected {}, actual {}\n",
+                    show(expected),
+                    show(actual)
+                )
+            }
+            None => "the texts differ outside their cells\n".to_owned(),
+        });
+    }
+    Some(out)
+}
+
+/// The first line whose terminator (`"\n"`, `"\r\n"` or `""` for an unterminated last line)
+/// differs, with `None` for a line one of the texts does not have.
+pub fn line_ending_diff<'a>(
+    expected: &'a str,
+    actual: &'a str,
+) -> Option<(usize, Option<&'a str>, Option<&'a str>)> {
+    fn ending(line: &str) -> &str {
+        let trimmed = line.trim_end_matches('\n').trim_end_matches('\r');
+        &line[trimmed.len()..]
+    }
+
+    let expected: Vec<&str> = expected.split_inclusive('\n').map(ending).collect();
+    let actual: Vec<&str> = actual.split_inclusive('\n').map(ending).collect();
+    (0..expected.len().max(actual.len())).find_map(|y| {
+        let (expected, actual) = (expected.get(y).copied(), actual.get(y).copied());
+        (expected != actual).then_some((y, expected, actual))
+    })
+}
+
+/// Renders the two grids side by side, highlighting exactly the cells in `diffs`. Callers
+/// that compare something richer than characters use this to flag cells that render alike.
+pub fn render(expected: &str, actual: &str, diffs: &[CellDiff], color: bool) -> String {
+    let expected_lines: Vec<&str> = expected.lines().collect();
+    let actual_lines: Vec<&str> = actual.lines().collect();
+    let width = expected_lines
+        .iter()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or_default()
+        .max("expected".len());
+
+    let highlight = |line: &str, y: usize, paint: &str| -> String {
+        let mut out = String::new();
+        for (x, ch) in line.chars().enumerate() {
+            let differs = diffs.iter().any(|d| d.x == x && d.y == y);
+            match (differs, color) {
+                (true, true) => out.push_str(&format!("{paint}{ch}{RESET}")),
+                _ => out.push(ch),
+            }
+        }
+        out
+    };
+
+    let mut out = format!("{:<width$} | actual\n", "expected");
+    for y in 0..expected_lines.len().max(actual_lines.len()) {
+        let expected_line = expected_lines.get(y).copied().unwrap_or_default();
+        let actual_line = actual_lines.get(y).copied().unwrap_or_default();
+        let padding = " ".repeat(width - expected_line.chars().count());
+        let marker = match diffs.iter().any(|d| d.y == y) {
+            true => '|',
+            false => ' ',
+        };
+
+        out.push_str(&format!(
+            "{}{padding} {marker} {}\n",
+            highlight(expected_line, y, GREEN),
+            highlight(actual_line, y, RED)
+        ));
+    }
+
+    if diffs.is_empty() {
+        return out;
+    }
+    let show = |ch: Option<char>| ch.map_or("nothing".to_owned(), |ch| format!("{ch:?}"));
+    out.push_str(&format!("{} cell(s) differ:\n", diffs.len()));
+    for diff in diffs.iter().take(MAX_LISTED_CELLS) {
+        out.push_str(&format!(
+            "  (x={}, y={}): expected {}, actual {}\n",
+            diff.x,
+            diff.y,
+            show(diff.expected),
+            show(diff.actual)
+        ));
+    }
+    if diffs.len() > MAX_LISTED_CELLS {
+        out.push_str(&format!(
+            "  ... and {} more\n",
+            diffs.len() - MAX_LISTED_CELLS
+        ));
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cell_diffs, grid_diff, line_ending_diff, CellDiff};
+
+    #[test]
+    fn identical() {
+        assert_eq!(None, grid_diff("#.#\n...", "#.#\n...", true));
+    }
+
+    #[test]
+    fn diffs() {
+        assert_eq!(
+            vec![
+                CellDiff {
+                    x: 1,
+                    y: 0,
+                    expected: Some('.'),
+                    actual: Some('O'),
+                },
+                CellDiff {
+                    x: 0,
+                    y: 2,
+                    expected: None,
+                    actual: Some('#'),
+                },
+            ],
+            cell_diffs("#.#\n...", "#O#\n...\n#")
+        );
+    }
+
+    #[test]
+    fn line_endings() {
+        assert_eq!(None, line_ending_diff("#.\n..\n", "#.\n..\n"));
+        assert_eq!(
+            Some((1, Some(""), Some("\n"))),
+            line_ending_diff("#.\n..", "#.\n..\n")
+        );
+        assert_eq!(
+            Some((0, Some("\n"), Some("\r\n"))),
+            line_ending_diff("#.\n..", "#.\r\n..")
+        );
+        assert_eq!(
+            Some((1, None, Some("\n"))),
+            line_ending_diff("#.\n", "#.\n\n")
+        );
+
+        let diff = grid_diff("#.\n..\n", "#.\n..", false).unwrap();
+        assert!(
+            diff.ends_with("line endings differ at y=1: expected \"\\n\", actual no line ending\n")
+        );
+        assert!(grid_diff("#.\r\n..", "#.\n..", false).is_some());
+    }
+
+    #[test]
+    fn side_by_side() {
+        let expected = [
+            "expected | actual",
+            "#.#      | #O#",
+            "...        ...",
+            "         | #",
+            "2 cell(s) differ:",
+            "  (x=1, y=0): expected '.', actual 'O'",
+            "  (x=0, y=2): expected nothing, actual '#'",
+            "",
+        ]
+        .join("\n");
+        assert_eq!(Some(expected), grid_diff("#.#\n...", "#O#\n...\n#", false));
+    }
+}
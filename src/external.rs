@@ -0,0 +1,392 @@
+//! Solutions written in other languages, run as child processes.
+//!
+//! The solver gets the normalized input on stdin, or, when one of its arguments is `{input}`,
+//! as the path of a temporary file put in that argument's place. It prints its answers on
+//! stdout in the sidecar format, one `partN: ANSWER` line per part (see [`Answers`]); other
+//! output is ignored, so debug prints can stay in. A non-zero exit status is a failure.
+//!
+//! A solver still running after its timeout is killed and the parts it has not answered yet
+//! are reported as exceeding their time budget. Only the solver process itself is killed, so
+//! wrapper scripts should `exec` the real program.
+
+use crate::{
+    budget::{BudgetExceeded, Limit},
+    days::Day,
+    fixtures::{Answers, Check, Fixture},
+    normalize::normalize,
+    runner::{Outcome, PartResult},
+};
+use std::{
+    io::{Read, Write},
+    path::PathBuf,
+    process::{Command, Stdio},
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// The argument replaced by the input's path.
+pub const INPUT_PLACEHOLDER: &str = "{input}";
+
+/// How often a running solver is checked for having exited.
+const POLL_INTERVAL: Duration = Duration::from_millis(2);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solver {
+    pub program: String,
+    pub args: Vec<String>,
+    pub timeout: Duration,
+}
+
+impl Solver {
+    /// A solver from a command line like `python3 day06.py {input}`, split on whitespace.
+    pub fn parse(command: &str, timeout: Duration) -> Result<Solver, String> {
+        let mut words = command.split_whitespace().map(str::to_owned);
+        let program = words.next().ok_or("The solver command is empty")?;
+        Ok(Solver {
+            program,
+            args: words.collect(),
+            timeout,
+        })
+    }
+
+    fn takes_path(&self) -> bool {
+        self.args.iter().any(|arg| arg == INPUT_PLACEHOLDER)
+    }
+
+    /// Runs the solver on `input` and reads one result for each of `day`'s parts from its
+    /// output. The solver's whole running time is reported on part 1.
+    ///
+    /// Fails when the solver can not be started, exits with an error, or exits without
+    /// answering one of the parts.
+    pub fn solve(&self, day: &Day, input: &str) -> Result<Vec<(u8, PartResult)>, String> {
+        let run = self.run(&normalize(input))?;
+
+        day.parts()
+            .map(|(n, _)| {
+                let outcome = match run.answers.get(n) {
+                    Some(answer) => Outcome::Answer(answer.to_owned()),
+                    None if run.timed_out => Outcome::BudgetExceeded(BudgetExceeded {
+                        limit: Limit::Time(self.timeout),
+                        steps: 0,
+                        elapsed: run.elapsed,
+                        progress: String::new(),
+                    }),
+                    None => return Err(format!("{self} printed no part{n} answer")),
+                };
+                let result = PartResult {
+                    outcome,
+                    elapsed: if n == 1 { run.elapsed } else { Duration::ZERO },
+                    allocations: None,
+                };
+                Ok((n, result))
+            })
+            .collect()
+    }
+
+    fn run(&self, input: &str) -> Result<Run, String> {
+        let path = match self.takes_path() {
+            true => Some(TempInput::write(input)?),
+            false => None,
+        };
+        let args = self.args.iter().map(|arg| match (&path, arg.as_str()) {
+            (Some(path), INPUT_PLACEHOLDER) => path.0.as_os_str(),
+            _ => arg.as_ref(),
+        });
+
+        let started = Instant::now();
+        let mut child = Command::new(&self.program)
+            .args(args)
+            .stdin(match path {
+                Some(_) => Stdio::null(),
+                None => Stdio::piped(),
+            })
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|err| format!("Could not run {self}: {err}"))?;
+
+        // Pipes are drained on their own threads so a chatty solver can not block on a full
+        // pipe while we wait for it.
+        let stdin = child.stdin.take().map(|mut stdin| {
+            let input = input.to_owned();
+            thread::spawn(move || stdin.write_all(input.as_bytes()))
+        });
+        let stdout = read_to_end(child.stdout.take());
+        let stderr = read_to_end(child.stderr.take());
+
+        let status = loop {
+            if let Some(status) = child.try_wait().map_err(|err| err.to_string())? {
+                break Some(status);
+            }
+            if started.elapsed() >= self.timeout {
+                // It may have exited in the meantime, which is just as good.
+                let _ = child.kill();
+                child.wait().map_err(|err| err.to_string())?;
+                break None;
+            }
+            thread::sleep(POLL_INTERVAL);
+        };
+        let elapsed = started.elapsed();
+
+        // A solver that exits without reading all of its input closes the pipe early, which
+        // is its own business.
+        if let Some(stdin) = stdin {
+            let _ = stdin.join();
+        }
+        let stdout = stdout.join().unwrap_or_default();
+        let stderr = stderr.join().unwrap_or_default();
+
+        if let Some(status) = status.filter(|status| !status.success()) {
+            let message = stderr.lines().rev().find(|line| !line.trim().is_empty());
+            return Err(match message {
+                Some(message) => format!("{self} failed with {status}: {}", message.trim()),
+                None => format!("{self} failed with {status}"),
+            });
+        }
+
+        Ok(Run {
+            answers: answers(&stdout).map_err(|err| format!("{self}: {err}"))?,
+            elapsed,
+            timed_out: status.is_none(),
+        })
+    }
+}
+
+impl std::fmt::Display for Solver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.program)?;
+        for arg in &self.args {
+            write!(f, " {arg}")?;
+        }
+        Ok(())
+    }
+}
+
+struct Run {
+    answers: Answers,
+    elapsed: Duration,
+    timed_out: bool,
+}
+
+/// The `partN:` lines of a solver's output.
+fn answers(stdout: &str) -> Result<Answers, String> {
+    stdout
+        .lines()
+        .filter(|line| {
+            let line = line.trim_start();
+            line.starts_with("part1:") || line.starts_with("part2:")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+        .parse()
+        .map_err(|err: crate::fixtures::ParseAnswersError| err.0)
+}
+
+fn read_to_end(pipe: Option<impl Read + Send + 'static>) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut bytes = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut bytes);
+        }
+        String::from_utf8_lossy(&bytes).into_owned()
+    })
+}
+
+/// An input written to a file for solvers that take a path, removed again on drop.
+struct TempInput(PathBuf);
+
+impl TempInput {
+    fn write(input: &str) -> Result<TempInput, String> {
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+        let name = format!(
+            "aoc-external-{}-{}.txt",
+            std::process::id(),
+            COUNT.fetch_add(1, Ordering::Relaxed)
+        );
+        let path = std::env::temp_dir().join(name);
+        std::fs::write(&path, input)
+            .map_err(|err| format!("Could not write {}: {err}", path.display()))?;
+        Ok(TempInput(path))
+    }
+}
+
+impl Drop for TempInput {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+/// Runs the solver on the fixture and checks every part with a known answer, like
+/// [`crate::fixtures::check`] does for the built-in days.
+pub fn check(day: &Day, solver: &Solver, fixture: &Fixture) -> Result<Vec<Check>, String> {
+    let results = solver.solve(day, &fixture.input)?;
+    Ok(results
+        .into_iter()
+        .filter_map(|(n, result)| {
+            Some(Check {
+                fixture: fixture.name.clone(),
+                part: n,
+                expected: fixture.answers.get(n)?.to_owned(),
+                result,
+            })
+        })
+        .collect())
+}
+
+/// One part of the same input solved by the built-in day and by an external solver.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub part: u8,
+    pub builtin: Outcome,
+    pub external: Outcome,
+}
+
+impl Comparison {
+    /// Whether both gave the same answer. A part that ran out of budget agrees with nothing.
+    pub fn agrees(&self) -> bool {
+        matches!(
+            (&self.builtin, &self.external),
+            (Outcome::Answer(a), Outcome::Answer(b)) if a == b
+        )
+    }
+}
+
+impl std::fmt::Display for Comparison {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.agrees() {
+            true => write!(f, "part {}: {} on both", self.part, self.builtin),
+            false => write!(
+                f,
+                "part {}: DIFFERENT, built-in {}, external {}",
+                self.part, self.builtin, self.external
+            ),
+        }
+    }
+}
+
+/// Pairs up the results of the same parts.
+pub fn compare(builtin: &[(u8, PartResult)], external: &[(u8, PartResult)]) -> Vec<Comparison> {
+    builtin
+        .iter()
+        .filter_map(|(n, builtin)| {
+            let (_, external) = external.iter().find(|(m, _)| m == n)?;
+            Some(Comparison {
+                part: *n,
+                builtin: builtin.outcome.clone(),
+                external: external.outcome.clone(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compare, Solver};
+    use crate::{
+        budget::Limit,
+        days,
+        runner::{Outcome, PartResult},
+    };
+    use std::time::Duration;
+
+    fn sh(script: &str, timeout: Duration) -> Solver {
+        Solver {
+            program: "sh".to_owned(),
+            args: vec!["-c".to_owned(), script.to_owned()],
+            timeout,
+        }
+    }
+
+    fn outcomes(results: Vec<(u8, PartResult)>) -> Vec<Outcome> {
+        results
+            .into_iter()
+            .map(|(_, result)| result.outcome)
+            .collect()
+    }
+
+    #[test]
+    fn answers_from_stdin() {
+        let solver = sh(
+            "echo reading; echo part2: $(wc -l); echo '  part1: 11 '",
+            Duration::from_secs(10),
+        );
+        let results = solver
+            .solve(days::get(1).unwrap(), "3   4\n4   3\n")
+            .unwrap();
+        assert_eq!(
+            vec![
+                Outcome::Answer("11".to_owned()),
+                Outcome::Answer("1".to_owned())
+            ],
+            outcomes(results)
+        );
+    }
+
+    #[test]
+    fn input_as_path() {
+        let mut solver = sh(
+            "echo part1: $(cat \"$0\"); echo part2: 2",
+            Duration::from_secs(10),
+        );
+        solver.args.push("{input}".to_owned());
+        let results = solver.solve(days::get(1).unwrap(), "1   2\r\n").unwrap();
+        assert_eq!(Outcome::Answer("1 2".to_owned()), results[0].1.outcome);
+    }
+
+    #[test]
+    fn timeout() {
+        let solver = sh("echo part1: 5; exec sleep 10", Duration::from_millis(200));
+        let results = outcomes(solver.solve(days::get(1).unwrap(), "1   2").unwrap());
+        assert_eq!(Outcome::Answer("5".to_owned()), results[0]);
+        assert!(matches!(
+            &results[1],
+            Outcome::BudgetExceeded(exceeded) if exceeded.limit == Limit::Time(Duration::from_millis(200))
+        ));
+    }
+
+    #[test]
+    fn failures() {
+        let day = days::get(1).unwrap();
+        let crashed = sh(
+            "echo part1: 1; echo Traceback >&2; exit 3",
+            Duration::from_secs(10),
+        );
+        assert!(crashed
+            .solve(day, "")
+            .unwrap_err()
+            .ends_with("failed with exit status: 3: Traceback"));
+
+        let silent = sh("echo part1: 1", Duration::from_secs(10));
+        assert!(silent
+            .solve(day, "")
+            .unwrap_err()
+            .ends_with("printed no part2 answer"));
+
+        let missing = Solver::parse("./no-such-solver {input}", Duration::from_secs(1)).unwrap();
+        assert!(missing
+            .solve(day, "")
+            .unwrap_err()
+            .starts_with("Could not run ./no-such-solver {input}"));
+    }
+
+    #[test]
+    fn comparisons() {
+        let result = |answer: &str| PartResult {
+            outcome: Outcome::Answer(answer.to_owned()),
+            elapsed: Duration::ZERO,
+            allocations: None,
+        };
+        let builtin = vec![(1, result("41")), (2, result("6"))];
+        let external = vec![(1, result("41")), (2, result("7"))];
+
+        let comparisons = compare(&builtin, &external);
+        assert!(comparisons[0].agrees());
+        assert!(!comparisons[1].agrees());
+        assert_eq!("part 1: 41 on both", comparisons[0].to_string());
+        assert_eq!(
+            "part 2: DIFFERENT, built-in 6, external 7",
+            comparisons[1].to_string()
+        );
+    }
+}
@@ -0,0 +1,273 @@
+//! Example inputs with their expected answers, stored as `fixtures/NN/<name>.txt`.
+//!
+//! Expected answers live next to the input in a `<name>.answers` sidecar with one
+//! `partN: ANSWER` line per known part.
+
+use crate::{
+    crypt::CryptError,
+    days::Day,
+    runner::{self, Limits, Outcome, PartResult},
+};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseAnswersError(pub String);
+
+impl Answers {
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Reads the sidecar for `input`, e.g. `example.answers` for `example.txt`.
+    /// A missing sidecar means no answers are known.
+    pub fn for_input(input: &Path) -> Result<Answers, String> {
+        let path = input.with_extension("answers");
+        match std::fs::read_to_string(&path) {
+            Ok(s) => s
+                .parse()
+                .map_err(|err: ParseAnswersError| format!("{}: {}", path.display(), err.0)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(err) => Err(format!("Could not read {}: {err}", path.display())),
+        }
+    }
+}
+
+impl std::str::FromStr for Answers {
+    type Err = ParseAnswersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = Answers::default();
+        for line in s.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let (part, answer) = line.split_once(':').ok_or_else(|| {
+                ParseAnswersError(format!("Expected `partN: ANSWER`, got {line}"))
+            })?;
+            let answer = Some(answer.trim().to_owned());
+            match part.trim() {
+                "part1" => answers.part1 = answer,
+                "part2" => answers.part2 = answer,
+                other => return Err(ParseAnswersError(format!("Unknown part {other}"))),
+            }
+        }
+
+        Ok(answers)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fixture {
+    pub name: String,
+    pub path: PathBuf,
+    pub input: String,
+    pub answers: Answers,
+}
+
+pub fn dir(day: u8) -> PathBuf {
+    PathBuf::from(format!("./fixtures/{day:02}"))
+}
+
+/// Every fixture of a day, sorted by name. A day without a fixtures directory has none.
+pub fn load(day: u8) -> Result<Vec<Fixture>, String> {
+    let dir = dir(day);
+    let entries = match std::fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(format!("Could not read {}: {err}", dir.display())),
+    };
+
+    let mut fixtures = Vec::new();
+    for entry in entries {
+        let path = entry.map_err(|err| err.to_string())?.path();
+        if path.extension().is_none_or(|ext| ext != "txt") {
+            continue;
+        }
+
+        let input = std::fs::read_to_string(&path)
+            .map_err(|err| format!("Could not read {}: {err}", path.display()))?;
+        fixtures.push(Fixture {
+            name: path.file_stem().unwrap().to_string_lossy().into_owned(),
+            answers: Answers::for_input(&path)?,
+            path,
+            input,
+        });
+    }
+
+    fixtures.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(fixtures)
+}
+
+/// The day's real input, or its first example when the input is encrypted and no key is
+/// configured, so tests that prefer real inputs still run on a fresh checkout. Any other
+/// failure to read the input, such as a wrong key, is an error.
+pub fn input_or_example(day: &Day) -> Result<String, String> {
+    match day.load_input() {
+        Ok(input) => Ok(input),
+        Err(err) if !is_missing_key(&err) => Err(format!(
+            "Could not read input for day {}: {err}",
+            day.number
+        )),
+        Err(err) => load(day.number)?
+            .into_iter()
+            .next()
+            .map(|fixture| fixture.input)
+            .ok_or_else(|| format!("No input or example for day {}: {err}", day.number)),
+    }
+}
+
+fn is_missing_key(err: &std::io::Error) -> bool {
+    err.get_ref()
+        .and_then(|inner| inner.downcast_ref::<CryptError>())
+        .is_some_and(|err| *err == CryptError::MissingKey)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Check {
+    pub fixture: String,
+    pub part: u8,
+    pub expected: String,
+    pub result: PartResult,
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        matches!(&self.result.outcome, Outcome::Answer(answer) if *answer == self.expected)
+    }
+}
+
+/// Runs every part with a known answer against the fixture.
+pub fn check(day: &Day, fixture: &Fixture, limits: Limits) -> Vec<Check> {
+    day.parts()
+        .filter_map(|(n, part)| {
+            let expected = fixture.answers.get(n)?;
+            Some(Check {
+                fixture: fixture.name.clone(),
+                part: n,
+                expected: expected.to_owned(),
+                result: runner::run_part(part, &fixture.input, limits),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check, input_or_example, load, Answers, Fixture, ParseAnswersError};
+    use crate::{
+        days::{self, Day, Input},
+        runner::{self, Limits},
+    };
+
+    /// The same input as saved on Windows and as pasted from a browser.
+    fn variants(input: &str) -> [(&'static str, String); 2] {
+        let crlf = format!("\u{feff}{}\r\n", input.trim_end().replace('\n', "\r\n"));
+        let padded = format!(
+            "\n{}\n\n\n",
+            input
+                .trim_end()
+                .lines()
+                .map(|line| format!("{line}  \t"))
+                .collect::<Vec<_>>()
+                .join("\n")
+        );
+        [("crlf", crlf), ("padded", padded)]
+    }
+
+    #[test]
+    fn parse_answers() {
+        let answers: Answers = "part1: 7036\n\npart2: 45\n".parse().unwrap();
+        assert_eq!(Some("7036"), answers.get(1));
+        assert_eq!(Some("45"), answers.get(2));
+        assert_eq!(
+            Err(ParseAnswersError("Unknown part part3".to_owned())),
+            "part3: 1".parse::<Answers>()
+        );
+    }
+
+    #[test]
+    fn only_a_missing_key_falls_back() {
+        let day = Day {
+            input: Input::File("./files/missing.txt"),
+            ..*days::get(16).unwrap()
+        };
+        let err = input_or_example(&day).unwrap_err();
+        assert!(err.contains("Neither ./files/missing.txt nor"), "{err}");
+    }
+
+    #[test]
+    fn every_fixture_passes() {
+        let mut checked = 0;
+        for day in days::DAYS {
+            for fixture in load(day.number).unwrap() {
+                for check in check(day, &fixture, Limits::default()) {
+                    assert!(
+                        check.passed(),
+                        "day {} {} part {}: expected {}, got {}",
+                        day.number,
+                        check.fixture,
+                        check.part,
+                        check.expected,
+                        check.result.outcome
+                    );
+                    checked += 1;
+                }
+            }
+        }
+        assert!(checked > 0, "No fixtures found in ./fixtures");
+    }
+
+    #[test]
+    fn every_fixture_passes_crlf_and_padded() {
+        for day in days::DAYS {
+            for fixture in load(day.number).unwrap() {
+                for (variant, input) in variants(&fixture.input) {
+                    let fixture = Fixture {
+                        input,
+                        ..fixture.clone()
+                    };
+                    for check in check(day, &fixture, Limits::default()) {
+                        assert!(
+                            check.passed(),
+                            "day {} {} ({variant}) part {}: expected {}, got {}",
+                            day.number,
+                            check.fixture,
+                            check.part,
+                            check.expected,
+                            check.result.outcome
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Day 14's fixture has no answers because its example uses a smaller grid, so the
+    /// variants are compared with the plain example instead.
+    #[test]
+    fn day14_crlf_and_padded() {
+        let day = days::get(14).unwrap();
+        let example = days::day14::robot::ROBOTS_INPUT_EXAMPLE;
+        let expected = runner::run_part(day.part1, example, Limits::default()).outcome;
+
+        for (variant, input) in variants(example) {
+            assert_eq!(
+                Ok(()),
+                runner::run_parse(day.parse, &input).result,
+                "{variant}"
+            );
+            assert_eq!(
+                expected,
+                runner::run_part(day.part1, &input, Limits::default()).outcome,
+                "{variant}"
+            );
+        }
+    }
+}
@@ -0,0 +1,357 @@
+//! Bitsets over a grid's cells, for the visited sets of searches that run in hot loops.
+//!
+//! A `HashSet<Position>` hashes every lookup; a mask is one bit per cell, so membership is a
+//! shift and a mask, and clearing or counting handles 64 cells at a time.
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A fixed-size bitset; the grid types below map cells onto it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Bits(Vec<u64>);
+
+impl Bits {
+    fn new(len: usize) -> Bits {
+        Bits(vec![0; len.div_ceil(WORD_BITS)])
+    }
+
+    fn get(&self, i: usize) -> bool {
+        self.0[i / WORD_BITS] & (1 << (i % WORD_BITS)) != 0
+    }
+
+    /// Sets bit `i`, returning whether it was clear before.
+    fn set(&mut self, i: usize) -> bool {
+        let word = &mut self.0[i / WORD_BITS];
+        let bit = 1 << (i % WORD_BITS);
+        let was_clear = *word & bit == 0;
+        *word |= bit;
+        was_clear
+    }
+
+    /// Clears bit `i`, returning whether it was set before.
+    fn unset(&mut self, i: usize) -> bool {
+        let word = &mut self.0[i / WORD_BITS];
+        let bit = 1 << (i % WORD_BITS);
+        let was_set = *word & bit != 0;
+        *word &= !bit;
+        was_set
+    }
+
+    fn clear(&mut self) {
+        self.0.fill(0);
+    }
+
+    fn count(&self) -> usize {
+        self.0.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    fn ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.0.iter().enumerate().flat_map(|(i, &word)| {
+            let mut word = word;
+            std::iter::from_fn(move || {
+                (word != 0).then(|| {
+                    let bit = word.trailing_zeros() as usize;
+                    word &= word - 1;
+                    i * WORD_BITS + bit
+                })
+            })
+        })
+    }
+
+    fn zip_with(&mut self, other: &Bits, f: impl Fn(u64, u64) -> u64) {
+        for (word, other) in self.0.iter_mut().zip(&other.0) {
+            *word = f(*word, *other);
+        }
+    }
+}
+
+/// One bit per cell of a `width` by `height` grid, addressed as `(x, y)` like `grid[y][x]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridMask {
+    width: usize,
+    height: usize,
+    bits: Bits,
+}
+
+/// Inputs with ragged rows parse fine, so masks cover the widest one.
+fn width<T>(grid: &[Vec<T>]) -> usize {
+    grid.iter().map(Vec::len).max().unwrap_or(0)
+}
+
+impl GridMask {
+    pub fn new(width: usize, height: usize) -> GridMask {
+        GridMask {
+            width,
+            height,
+            bits: Bits::new(width * height),
+        }
+    }
+
+    /// An empty mask as wide as the grid's widest row and as tall as the grid.
+    pub fn for_grid<T>(grid: &[Vec<T>]) -> GridMask {
+        GridMask::new(width(grid), grid.len())
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index(&self, x: usize, y: usize) -> Option<usize> {
+        (x < self.width && y < self.height).then(|| y * self.width + x)
+    }
+
+    #[track_caller]
+    fn index_in_bounds(&self, x: usize, y: usize) -> usize {
+        self.index(x, y).unwrap_or_else(|| {
+            panic!(
+                "({x}, {y}) is outside the {}x{} mask",
+                self.width, self.height
+            )
+        })
+    }
+
+    /// Cells outside the grid are never set.
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        self.index(x, y).is_some_and(|i| self.bits.get(i))
+    }
+
+    /// Sets a cell, returning whether it was clear before. Panics outside the grid.
+    #[track_caller]
+    pub fn insert(&mut self, x: usize, y: usize) -> bool {
+        let i = self.index_in_bounds(x, y);
+        self.bits.set(i)
+    }
+
+    /// Clears a cell, returning whether it was set before.
+    pub fn remove(&mut self, x: usize, y: usize) -> bool {
+        self.index(x, y).is_some_and(|i| self.bits.unset(i))
+    }
+
+    pub fn clear(&mut self) {
+        self.bits.clear();
+    }
+
+    /// How many cells are set.
+    pub fn count(&self) -> usize {
+        self.bits.count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.0.iter().all(|word| *word == 0)
+    }
+
+    /// Set cells as `(x, y)`, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.bits.ones().map(|i| (i % self.width, i / self.width))
+    }
+
+    #[track_caller]
+    fn assert_same_size(&self, other: &GridMask) {
+        assert_eq!(
+            (self.width, self.height),
+            (other.width, other.height),
+            "masks differ in size"
+        );
+    }
+
+    /// Sets every cell that is set in `other`.
+    #[track_caller]
+    pub fn union_with(&mut self, other: &GridMask) {
+        self.assert_same_size(other);
+        self.bits.zip_with(&other.bits, |a, b| a | b);
+    }
+
+    /// Keeps only the cells that are also set in `other`.
+    #[track_caller]
+    pub fn intersect_with(&mut self, other: &GridMask) {
+        self.assert_same_size(other);
+        self.bits.zip_with(&other.bits, |a, b| a & b);
+    }
+
+    /// Clears every cell that is set in `other`.
+    #[track_caller]
+    pub fn difference_with(&mut self, other: &GridMask) {
+        self.assert_same_size(other);
+        self.bits.zip_with(&other.bits, |a, b| a & !b);
+    }
+
+    pub fn is_disjoint(&self, other: &GridMask) -> bool {
+        self.bits
+            .0
+            .iter()
+            .zip(&other.bits.0)
+            .all(|(a, b)| a & b == 0)
+    }
+}
+
+/// `LAYERS` bits per cell, e.g. one per direction a cell was entered from. A cell's layers sit
+/// next to each other, so checking all of them touches one word.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayeredMask<const LAYERS: usize> {
+    width: usize,
+    height: usize,
+    bits: Bits,
+}
+
+impl<const LAYERS: usize> LayeredMask<LAYERS> {
+    pub fn new(width: usize, height: usize) -> LayeredMask<LAYERS> {
+        LayeredMask {
+            width,
+            height,
+            bits: Bits::new(width * height * LAYERS),
+        }
+    }
+
+    pub fn for_grid<T>(grid: &[Vec<T>]) -> LayeredMask<LAYERS> {
+        LayeredMask::new(width(grid), grid.len())
+    }
+
+    fn index(&self, x: usize, y: usize, layer: usize) -> Option<usize> {
+        (x < self.width && y < self.height && layer < LAYERS)
+            .then(|| (y * self.width + x) * LAYERS + layer)
+    }
+
+    pub fn contains(&self, x: usize, y: usize, layer: usize) -> bool {
+        self.index(x, y, layer).is_some_and(|i| self.bits.get(i))
+    }
+
+    /// Sets a cell's layer, returning whether it was clear before. Panics outside the grid.
+    #[track_caller]
+    pub fn insert(&mut self, x: usize, y: usize, layer: usize) -> bool {
+        let i = self.index(x, y, layer).unwrap_or_else(|| {
+            panic!(
+                "({x}, {y}) layer {layer} is outside the {}x{}x{LAYERS} mask",
+                self.width, self.height
+            )
+        });
+        self.bits.set(i)
+    }
+
+    pub fn remove(&mut self, x: usize, y: usize, layer: usize) -> bool {
+        self.index(x, y, layer).is_some_and(|i| self.bits.unset(i))
+    }
+
+    pub fn clear(&mut self) {
+        self.bits.clear();
+    }
+
+    /// How many (cell, layer) pairs are set.
+    pub fn count(&self) -> usize {
+        self.bits.count()
+    }
+
+    /// Set `(x, y, layer)` triples, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, usize)> + '_ {
+        self.bits.ones().map(|i| {
+            let cell = i / LAYERS;
+            (cell % self.width, cell / self.width, i % LAYERS)
+        })
+    }
+
+    /// The cells set in `layer`.
+    pub fn layer(&self, layer: usize) -> GridMask {
+        let mut mask = GridMask::new(self.width, self.height);
+        for (x, y, _) in self.iter().filter(|(_, _, l)| *l == layer) {
+            mask.insert(x, y);
+        }
+        mask
+    }
+
+    /// The cells set in any layer.
+    pub fn flatten(&self) -> GridMask {
+        let mut mask = GridMask::new(self.width, self.height);
+        for (x, y, _) in self.iter() {
+            mask.insert(x, y);
+        }
+        mask
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GridMask, LayeredMask};
+
+    #[test]
+    fn cells() {
+        let mut mask = GridMask::new(70, 3);
+        assert!(mask.insert(69, 0));
+        assert!(!mask.insert(69, 0));
+        assert!(mask.insert(0, 1));
+        assert!(mask.insert(5, 2));
+
+        assert!(mask.contains(69, 0));
+        assert!(!mask.contains(70, 0));
+        assert_eq!(3, mask.count());
+        assert_eq!(
+            vec![(69, 0), (0, 1), (5, 2)],
+            mask.iter().collect::<Vec<_>>()
+        );
+
+        assert!(mask.remove(0, 1));
+        assert!(!mask.remove(0, 1));
+        assert_eq!(2, mask.count());
+        mask.clear();
+        assert!(mask.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "(3, 0) is outside the 3x3 mask")]
+    fn insert_outside() {
+        GridMask::new(3, 3).insert(3, 0);
+    }
+
+    #[test]
+    fn set_operations() {
+        let mask = |cells: &[(usize, usize)]| {
+            let mut mask = GridMask::new(4, 4);
+            for &(x, y) in cells {
+                mask.insert(x, y);
+            }
+            mask
+        };
+        let a = mask(&[(0, 0), (1, 1), (2, 2)]);
+        let b = mask(&[(1, 1), (3, 3)]);
+
+        let mut union = a.clone();
+        union.union_with(&b);
+        assert_eq!(mask(&[(0, 0), (1, 1), (2, 2), (3, 3)]), union);
+
+        let mut intersection = a.clone();
+        intersection.intersect_with(&b);
+        assert_eq!(mask(&[(1, 1)]), intersection);
+
+        let mut difference = a.clone();
+        difference.difference_with(&b);
+        assert_eq!(mask(&[(0, 0), (2, 2)]), difference);
+        assert!(difference.is_disjoint(&b));
+    }
+
+    #[test]
+    fn layers() {
+        let mut mask: LayeredMask<4> = LayeredMask::new(5, 5);
+        assert!(mask.insert(2, 3, 1));
+        assert!(mask.insert(2, 3, 3));
+        assert!(mask.insert(4, 0, 1));
+        assert!(!mask.insert(2, 3, 1));
+
+        assert!(mask.contains(2, 3, 3));
+        assert!(!mask.contains(2, 3, 0));
+        assert!(!mask.contains(2, 3, 4));
+        assert_eq!(3, mask.count());
+        assert_eq!(2, mask.layer(1).count());
+        assert_eq!(
+            vec![(4, 0), (2, 3)],
+            mask.flatten().iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn ragged_grids() {
+        let grid = vec![vec![0; 2], vec![0; 5], vec![0; 1]];
+        assert_eq!(5, GridMask::for_grid(&grid).width());
+        assert!(LayeredMask::<4>::for_grid(&grid).insert(4, 1, 3));
+    }
+}
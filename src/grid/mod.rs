@@ -0,0 +1,197 @@
+//! Shared pieces for the days that keep their map as a `Vec<Vec<T>>` grid.
+
+use crate::diff::{self, CellDiff};
+
+pub mod mask;
+pub mod sparse;
+
+pub use advent_of_code_derive::GridCell;
+
+/// A grid cell that renders as a single character.
+///
+/// `#[derive(GridCell)]` writes this for enums whose variants are `#[cell('c')]` characters or
+/// hold another cell, along with `Display`, `FromStr` and a `Parse<Enum>Error`.
+pub trait GridCell {
+    fn to_char(&self) -> char;
+}
+
+impl GridCell for char {
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+/// One line per row, without a trailing newline.
+pub fn render<T: GridCell>(grid: &[Vec<T>]) -> String {
+    grid.iter()
+        .map(|row| row.iter().map(GridCell::to_char).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Cells where the two grids differ, compared as `T` rather than by their rendering.
+pub fn cell_diffs<T: GridCell + PartialEq>(
+    expected: &[Vec<T>],
+    actual: &[Vec<T>],
+) -> Vec<CellDiff> {
+    let mut diffs = Vec::new();
+    for y in 0..expected.len().max(actual.len()) {
+        let expected_row = expected.get(y).map(Vec::as_slice).unwrap_or_default();
+        let actual_row = actual.get(y).map(Vec::as_slice).unwrap_or_default();
+        for x in 0..expected_row.len().max(actual_row.len()) {
+            let (expected, actual) = (expected_row.get(x), actual_row.get(x));
+            if expected != actual {
+                diffs.push(CellDiff {
+                    x,
+                    y,
+                    expected: expected.map(GridCell::to_char),
+                    actual: actual.map(GridCell::to_char),
+                });
+            }
+        }
+    }
+    diffs
+}
+
+/// Panics with both grids side by side and the differing cells highlighted when they are not equal.
+#[track_caller]
+pub fn assert_grid_eq<T: GridCell + PartialEq>(expected: &[Vec<T>], actual: &[Vec<T>]) {
+    if expected == actual {
+        return;
+    }
+    let diffs = cell_diffs(expected, actual);
+    panic!(
+        "grids differ\n{}",
+        diff::render(
+            &render(expected),
+            &render(actual),
+            &diffs,
+            diff::use_color()
+        )
+    );
+}
+
+/// [`assert_grid_eq`] for grids that are already rendered, e.g. a map's `Display` output.
+/// The texts must be equal byte for byte, line terminators included; the cell diff only
+/// explains a failure.
+#[track_caller]
+pub fn assert_grid_str_eq(expected: &str, actual: &str) {
+    if expected == actual {
+        return;
+    }
+    let diff = diff::grid_diff(expected, actual, diff::use_color())
+        .expect("texts that differ should have a diff");
+    panic!("grids differ\n{diff}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{assert_grid_eq, assert_grid_str_eq, render, GridCell};
+
+    #[derive(Debug, PartialEq)]
+    enum Cell {
+        Empty,
+        Wall { cracked: bool },
+    }
+
+    impl GridCell for Cell {
+        fn to_char(&self) -> char {
+            match self {
+                Cell::Empty => '.',
+                Cell::Wall { .. } => '#',
+            }
+        }
+    }
+
+    fn wall() -> Cell {
+        Cell::Wall { cracked: false }
+    }
+
+    #[test]
+    fn renders_rows() {
+        assert_eq!(
+            "#.\n.#",
+            render(&[vec![wall(), Cell::Empty], vec![Cell::Empty, wall()]])
+        );
+    }
+
+    #[test]
+    fn equal_grids_pass() {
+        assert_grid_eq(&[vec![wall(), Cell::Empty]], &[vec![wall(), Cell::Empty]]);
+        assert_grid_str_eq("#.\n.#", "#.\n.#");
+    }
+
+    #[test]
+    fn reports_cells_that_render_alike() {
+        let message = std::panic::catch_unwind(|| {
+            assert_grid_eq(
+                &[vec![wall(), Cell::Empty]],
+                &[vec![Cell::Wall { cracked: true }, Cell::Empty]],
+            )
+        })
+        .unwrap_err();
+        let message = message.downcast_ref::<String>().unwrap();
+
+        assert!(message.contains("1 cell(s) differ"));
+        assert!(message.contains("(x=0, y=0): expected '#', actual '#'"));
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, GridCell)]
+    enum Tile {
+        #[cell('.')]
+        Floor,
+        /// Doc comments are fine next to `#[cell]`.
+        #[cell('\\')]
+        Mirror,
+        Digit(Digit),
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Digit(u8);
+
+    impl GridCell for Digit {
+        fn to_char(&self) -> char {
+            char::from(b'0' + self.0)
+        }
+    }
+
+    impl std::str::FromStr for Digit {
+        type Err = ();
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            match s.as_bytes() {
+                [digit @ b'0'..=b'9'] => Ok(Digit(digit - b'0')),
+                _ => Err(()),
+            }
+        }
+    }
+
+    #[test]
+    fn derived_cells() {
+        let tiles = [Tile::Floor, Tile::Mirror, Tile::Digit(Digit(7))];
+        for (tile, s) in tiles.into_iter().zip([".", "\\", "7"]) {
+            assert_eq!(Ok(tile), s.parse::<Tile>());
+            assert_eq!(s, tile.to_string());
+        }
+        assert_eq!(Err(ParseTileError("x".to_owned())), "x".parse::<Tile>());
+        assert_eq!(Err(ParseTileError("..".to_owned())), "..".parse::<Tile>());
+    }
+
+    #[test]
+    #[should_panic(expected = "(x=1, y=1): expected '#', actual 'O'")]
+    fn reports_coordinates() {
+        assert_grid_str_eq("#.\n.#", "#.\n.O");
+    }
+
+    #[test]
+    #[should_panic(expected = "line endings differ at y=1")]
+    fn trailing_newline_fails() {
+        assert_grid_str_eq("#.\n.#\n", "#.\n.#");
+    }
+
+    #[test]
+    #[should_panic(expected = "line endings differ at y=0")]
+    fn crlf_fails() {
+        assert_grid_str_eq("#.\n.#\n", "#.\r\n.#\n");
+    }
+}
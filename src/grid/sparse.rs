@@ -0,0 +1,301 @@
+//! A grid that only stores the cells that are set, addressed with signed coordinates.
+//!
+//! Dense grids are rectangles indexed from `(0, 0)`. Puzzles whose points step off the map,
+//! go negative or grow without limit fit this better: any `Point` is a valid address, and
+//! the grid keeps track of the bounding box of what it holds.
+
+use super::GridCell;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const fn new(x: i64, y: i64) -> Point {
+        Point { x, y }
+    }
+}
+
+impl std::ops::Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl std::ops::Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl std::ops::AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl std::ops::SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+/// An inclusive rectangle of points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Bounds {
+    pub fn point(point: Point) -> Bounds {
+        Bounds {
+            min: point,
+            max: point,
+        }
+    }
+
+    /// The rectangle a dense grid covers, or `None` when it has no cells.
+    pub fn of_dense<T>(grid: &[Vec<T>]) -> Option<Bounds> {
+        let width = grid.iter().map(Vec::len).max()?;
+        (width > 0).then(|| Bounds {
+            min: Point::new(0, 0),
+            max: Point::new(width as i64 - 1, grid.len() as i64 - 1),
+        })
+    }
+
+    pub fn width(&self) -> u64 {
+        self.min.x.abs_diff(self.max.x) + 1
+    }
+
+    pub fn height(&self) -> u64 {
+        self.min.y.abs_diff(self.max.y) + 1
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    /// Grows the rectangle just enough to cover `point`.
+    pub fn include(&mut self, point: Point) {
+        self.min = Point::new(self.min.x.min(point.x), self.min.y.min(point.y));
+        self.max = Point::new(self.max.x.max(point.x), self.max.y.max(point.y));
+    }
+
+    /// Moves `point` into the rectangle as if its edges were glued together, like a torus.
+    pub fn wrap(&self, point: Point) -> Point {
+        Point::new(
+            self.min.x + (point.x - self.min.x).rem_euclid(self.width() as i64),
+            self.min.y + (point.y - self.min.y).rem_euclid(self.height() as i64),
+        )
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let Bounds { min, max } = *self;
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point::new(x, y)))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+    bounds: Option<Bounds>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid::default()
+    }
+
+    /// Keeps the cells of a dense grid that pass `keep`, with `grid[y][x]` at `(x, y)`.
+    pub fn from_dense(grid: &[Vec<T>], keep: impl Fn(&T) -> bool) -> SparseGrid<T>
+    where
+        T: Clone,
+    {
+        let mut sparse = SparseGrid::new();
+        for (y, row) in grid.iter().enumerate() {
+            for (x, cell) in row.iter().enumerate() {
+                if keep(cell) {
+                    sparse.insert(Point::new(x as i64, y as i64), cell.clone());
+                }
+            }
+        }
+        sparse
+    }
+
+    /// A dense copy of the bounding box, with `empty` where nothing is set. Row 0, column 0
+    /// is `bounds().min`.
+    pub fn to_dense(&self, empty: T) -> Vec<Vec<T>>
+    where
+        T: Clone,
+    {
+        let Some(bounds) = self.bounds else {
+            return Vec::new();
+        };
+        (bounds.min.y..=bounds.max.y)
+            .map(|y| {
+                (bounds.min.x..=bounds.max.x)
+                    .map(|x| self.get(Point::new(x, y)).unwrap_or(&empty).clone())
+                    .collect()
+            })
+            .collect()
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.cells.get_mut(&point)
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    /// Sets a cell, returning what was there before.
+    pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        match &mut self.bounds {
+            Some(bounds) => bounds.include(point),
+            None => self.bounds = Some(Bounds::point(point)),
+        }
+        self.cells.insert(point, value)
+    }
+
+    /// Clears a cell. The bounds shrink when it was on their edge.
+    pub fn remove(&mut self, point: Point) -> Option<T> {
+        let removed = self.cells.remove(&point)?;
+        if self.bounds.is_some_and(|bounds| {
+            point.x == bounds.min.x
+                || point.x == bounds.max.x
+                || point.y == bounds.min.y
+                || point.y == bounds.max.y
+        }) {
+            self.bounds = self.cells.keys().fold(None, |bounds, &point| {
+                let mut bounds = bounds.unwrap_or(Bounds::point(point));
+                bounds.include(point);
+                Some(bounds)
+            });
+        }
+        Some(removed)
+    }
+
+    /// The smallest rectangle holding every set cell, `None` while the grid is empty.
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Set cells in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(point, value)| (*point, value))
+    }
+
+    /// The bounding box as text, one line per row, with `empty` where nothing is set.
+    pub fn render(&self, empty: char) -> String
+    where
+        T: GridCell,
+    {
+        let Some(bounds) = self.bounds else {
+            return String::new();
+        };
+        (bounds.min.y..=bounds.max.y)
+            .map(|y| {
+                (bounds.min.x..=bounds.max.x)
+                    .map(|x| self.get(Point::new(x, y)).map_or(empty, GridCell::to_char))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        for (point, value) in iter {
+            grid.insert(point, value);
+        }
+        grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Bounds, Point, SparseGrid};
+
+    #[test]
+    fn bounds_follow_the_cells() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(None, grid.bounds());
+
+        grid.insert(Point::new(2, 1), '#');
+        grid.insert(Point::new(-3, 4), 'O');
+        grid.insert(Point::new(0, -2), '@');
+        assert_eq!(
+            Some(Bounds {
+                min: Point::new(-3, -2),
+                max: Point::new(2, 4),
+            }),
+            grid.bounds()
+        );
+
+        grid.remove(Point::new(-3, 4));
+        assert_eq!(
+            Some(Bounds {
+                min: Point::new(0, -2),
+                max: Point::new(2, 1),
+            }),
+            grid.bounds()
+        );
+        assert_eq!("@..\n...\n...\n..#", grid.render('.'));
+    }
+
+    #[test]
+    fn dense_round_trip() {
+        let dense: Vec<Vec<char>> = ["....", ".#..", "...#"]
+            .iter()
+            .map(|row| row.chars().collect())
+            .collect();
+        let sparse = SparseGrid::from_dense(&dense, |c| *c != '.');
+
+        assert_eq!(2, sparse.len());
+        assert_eq!(Some(&'#'), sparse.get(Point::new(3, 2)));
+        assert_eq!(
+            vec![vec!['#', '.', '.'], vec!['.', '.', '#']],
+            sparse.to_dense('.')
+        );
+    }
+
+    #[test]
+    fn wrap() {
+        let bounds = Bounds::of_dense(&vec![vec![(); 11]; 7]).unwrap();
+        assert_eq!(Point::new(10, 0), bounds.wrap(Point::new(-1, 7)));
+        assert_eq!(Point::new(1, 6), bounds.wrap(Point::new(23, -8)));
+        assert!(!bounds.contains(Point::new(11, 0)));
+        assert_eq!(77, bounds.points().count());
+    }
+}
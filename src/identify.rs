@@ -0,0 +1,435 @@
+//! Guesses which day an input belongs to from its shape.
+//!
+//! Every day gets two checks: a structural signature (`p=… v=…` lines, `Button A:` blocks, a
+//! map with one guard, …) and its own parser. A day whose signature doesn't match is rejected
+//! with the reason; a day whose signature matches is a candidate, trusted less when the
+//! signature is one several days share or when its parser still fails.
+
+use crate::{days, normalize::normalize, table::Table};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Confidence {
+    /// The shape matches but the day's parser rejects the input.
+    Low,
+    /// The shape matches, but it's a generic one, e.g. rows of numbers.
+    Medium,
+    High,
+}
+
+impl std::fmt::Display for Confidence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Confidence::Low => "low",
+            Confidence::Medium => "medium",
+            Confidence::High => "high",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Candidate {
+    pub day: u8,
+    pub confidence: Confidence,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rejection {
+    pub day: u8,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Identification {
+    /// Most likely first.
+    pub candidates: Vec<Candidate>,
+    pub rejected: Vec<Rejection>,
+}
+
+impl Identification {
+    /// Every candidate that shares the best confidence.
+    pub fn most_likely(&self) -> &[Candidate] {
+        let Some(best) = self.candidates.first() else {
+            return &[];
+        };
+        let n = self
+            .candidates
+            .iter()
+            .take_while(|candidate| candidate.confidence == best.confidence)
+            .count();
+        &self.candidates[..n]
+    }
+
+    pub fn table(&self) -> Table {
+        let mut table = Table::new(["day", "verdict", "reason"]);
+        for candidate in &self.candidates {
+            table.push([
+                format!("{:02}", candidate.day),
+                format!("{} confidence", candidate.confidence),
+                candidate.reason.clone(),
+            ]);
+        }
+        for rejection in &self.rejected {
+            table.push([
+                format!("{:02}", rejection.day),
+                "rejected".to_owned(),
+                rejection.reason.clone(),
+            ]);
+        }
+        table
+    }
+}
+
+/// Checks `input` against every registered day.
+///
+/// Parsers that panic count as failing; the panic message still goes through the panic hook.
+pub fn identify(input: &str) -> Identification {
+    let input = normalize(input);
+    let mut candidates = Vec::new();
+    let mut rejected = Vec::new();
+
+    for day in days::DAYS {
+        match signature(day.number, &input) {
+            Ok(Shape { reason, generic }) => {
+                let confidence = match parse(day.parse, &input) {
+                    Err(err) => {
+                        candidates.push(Candidate {
+                            day: day.number,
+                            confidence: Confidence::Low,
+                            reason: format!("{reason}, but the parser failed: {err}"),
+                        });
+                        continue;
+                    }
+                    Ok(()) if generic => Confidence::Medium,
+                    Ok(()) => Confidence::High,
+                };
+                candidates.push(Candidate {
+                    day: day.number,
+                    confidence,
+                    reason,
+                });
+            }
+            Err(reason) => rejected.push(Rejection {
+                day: day.number,
+                reason,
+            }),
+        }
+    }
+
+    candidates.sort_by(|a, b| b.confidence.cmp(&a.confidence).then(a.day.cmp(&b.day)));
+    Identification {
+        candidates,
+        rejected,
+    }
+}
+
+fn parse(parse: days::Parse, input: &str) -> Result<(), String> {
+    std::panic::catch_unwind(|| parse(input)).unwrap_or_else(|panic| {
+        let message = panic
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| panic.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        Err(format!("panicked: {message}"))
+    })
+}
+
+struct Shape {
+    reason: String,
+    /// Shared with other days, so a match says less.
+    generic: bool,
+}
+
+fn distinctive(reason: &str) -> Result<Shape, String> {
+    Ok(Shape {
+        reason: reason.to_owned(),
+        generic: false,
+    })
+}
+
+fn generic(reason: &str) -> Result<Shape, String> {
+    Ok(Shape {
+        reason: reason.to_owned(),
+        generic: true,
+    })
+}
+
+fn signature(day: u8, input: &str) -> Result<Shape, String> {
+    let lines: Vec<&str> = input.lines().collect();
+    if lines.is_empty() {
+        return Err("input is empty".to_owned());
+    }
+
+    match day {
+        1 => {
+            every_line(&lines, "two numbers", |line| {
+                numbers(line).is_some_and(|n| n == 2)
+            })?;
+            generic("two columns of numbers")
+        }
+        2 => {
+            if lines.len() == 1 {
+                return Err("a single line of numbers, not a list of reports".to_owned());
+            }
+            every_line(&lines, "a row of numbers", |line| {
+                numbers(line).is_some_and(|n| n >= 2)
+            })?;
+            if lines.iter().all(|line| numbers(line) == Some(2)) {
+                return Err("every row has exactly two numbers, like day 1's lists".to_owned());
+            }
+            generic("rows of numbers")
+        }
+        3 => {
+            if !has_mul(input) {
+                return Err("no mul(X,Y) instruction".to_owned());
+            }
+            distinctive("mul(X,Y) instructions in corrupted memory")
+        }
+        4 => {
+            grid(&lines, "X, M, A or S", |c| "XMAS".contains(c))?;
+            distinctive("a word search of X, M, A and S")
+        }
+        5 => {
+            let (rules, updates) = sections(&lines)?;
+            every_line(rules, "an X|Y ordering rule", |line| {
+                line.split_once('|')
+                    .is_some_and(|(a, b)| is_number(a) && is_number(b))
+            })?;
+            every_line(updates, "a comma separated update", |line| {
+                line.contains(',') && line.split(',').all(is_number)
+            })?;
+            distinctive("X|Y rules followed by comma separated updates")
+        }
+        6 => {
+            grid(&lines, "'.', '#' or a guard", |c| ".#^>v<".contains(c))?;
+            exactly_one(input, "a guard", |c| "^>v<".contains(c))?;
+            distinctive("a map with obstacles and one guard")
+        }
+        7 => {
+            every_line(&lines, "an equation like `190: 10 19`", |line| {
+                line.split_once(": ").is_some_and(|(value, operands)| {
+                    is_number(value) && numbers(operands).is_some()
+                })
+            })?;
+            distinctive("calibration equations")
+        }
+        8 => {
+            grid(&lines, "'.' or an antenna", |c| {
+                c == '.' || c.is_ascii_alphanumeric()
+            })?;
+            if !input.contains('.') || !input.chars().any(|c| c.is_ascii_alphanumeric()) {
+                return Err("needs both empty cells and antennas".to_owned());
+            }
+            distinctive("a map of antennas")
+        }
+        9 => {
+            single_line(&lines)?;
+            if !lines[0].chars().all(|c| c.is_ascii_digit()) {
+                return Err("the line is not all digits".to_owned());
+            }
+            distinctive("one long line of digits, a disk map")
+        }
+        10 => {
+            grid(&lines, "a height digit", |c| c.is_ascii_digit())?;
+            distinctive("a grid of height digits")
+        }
+        11 => {
+            single_line(&lines)?;
+            match numbers(lines[0]) {
+                None => return Err("the line is not space separated numbers".to_owned()),
+                Some(1) => return Err("a single number, like day 9's disk map".to_owned()),
+                Some(_) => {}
+            }
+            distinctive("one line of stone numbers")
+        }
+        12 => {
+            grid(&lines, "a plant letter", |c| c.is_ascii_uppercase())?;
+            if input
+                .lines()
+                .flat_map(str::chars)
+                .all(|c| "XMAS".contains(c))
+            {
+                return Err("only X, M, A and S, like day 4's word search".to_owned());
+            }
+            generic("a garden of plant letters")
+        }
+        13 => {
+            if !input.contains("Button A:") || !input.contains("Prize:") {
+                return Err("no `Button A:` and `Prize:` lines".to_owned());
+            }
+            distinctive("claw machine blocks with buttons and prizes")
+        }
+        14 => {
+            every_line(&lines, "a `p=x,y v=dx,dy` robot", is_robot)?;
+            distinctive("p=… v=… robot lines")
+        }
+        15 => {
+            let (map, moves) = sections(&lines)?;
+            grid(map, "'#', '.', 'O' or '@'", |c| "#.O@".contains(c))?;
+            exactly_one(input, "a robot", |c| c == '@')?;
+            every_line(moves, "a list of moves", |line| {
+                line.chars().all(|c| "<>^v".contains(c))
+            })?;
+            distinctive("a warehouse map followed by robot moves")
+        }
+        16 => {
+            grid(&lines, "'#', '.', 'S' or 'E'", |c| "#.SE".contains(c))?;
+            exactly_one(input, "a start", |c| c == 'S')?;
+            exactly_one(input, "an end", |c| c == 'E')?;
+            distinctive("a maze with a start and an end")
+        }
+        17 => {
+            if !input.contains("Register A:") || !input.contains("Program:") {
+                return Err("no `Register A:` and `Program:` lines".to_owned());
+            }
+            distinctive("registers and a program")
+        }
+        _ => Err("no known signature".to_owned()),
+    }
+}
+
+fn every_line(lines: &[&str], what: &str, check: impl Fn(&str) -> bool) -> Result<(), String> {
+    if lines.is_empty() {
+        return Err(format!("expected lines with {what}, found none"));
+    }
+    match lines.iter().position(|line| !check(line)) {
+        Some(i) => Err(format!("line {} is not {what}", i + 1)),
+        None => Ok(()),
+    }
+}
+
+/// A rectangle of at least two rows whose cells all pass `cell`.
+fn grid(lines: &[&str], what: &str, cell: impl Fn(char) -> bool) -> Result<(), String> {
+    if lines.len() < 2 {
+        return Err("not a grid, only one line".to_owned());
+    }
+    let width = lines[0].chars().count();
+    if let Some(i) = lines.iter().position(|line| line.chars().count() != width) {
+        return Err(format!("not a grid, line {} has a different width", i + 1));
+    }
+    for (y, line) in lines.iter().enumerate() {
+        if let Some((x, c)) = line.chars().enumerate().find(|(_, c)| !cell(*c)) {
+            return Err(format!("{c:?} at (x={x}, y={y}) is not {what}"));
+        }
+    }
+    Ok(())
+}
+
+fn exactly_one(input: &str, what: &str, matches: impl Fn(char) -> bool) -> Result<(), String> {
+    match input.chars().filter(|c| matches(*c)).count() {
+        1 => Ok(()),
+        0 => Err(format!(
+            "no {}",
+            what.trim_start_matches("a ").trim_start_matches("an ")
+        )),
+        n => Err(format!("{n} cells where {what} should be")),
+    }
+}
+
+/// The two blank-line separated halves of the input.
+fn sections<'a>(lines: &'a [&'a str]) -> Result<(&'a [&'a str], &'a [&'a str]), String> {
+    let blank = lines
+        .iter()
+        .position(|line| line.is_empty())
+        .ok_or("no blank line between two sections")?;
+    Ok((&lines[..blank], &lines[blank + 1..]))
+}
+
+fn single_line(lines: &[&str]) -> Result<(), String> {
+    match lines.len() {
+        1 => Ok(()),
+        n => Err(format!("{n} lines, expected a single line")),
+    }
+}
+
+fn is_number(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_ascii_digit())
+}
+
+/// How many whitespace separated numbers `line` holds, if that's all it holds.
+fn numbers(line: &str) -> Option<usize> {
+    let mut n = 0;
+    for word in line.split_whitespace() {
+        if !is_number(word) {
+            return None;
+        }
+        n += 1;
+    }
+    (n > 0).then_some(n)
+}
+
+fn is_robot(line: &str) -> bool {
+    let pair = |s: &str| {
+        s.split_once(',')
+            .is_some_and(|(x, y)| x.parse::<i64>().is_ok() && y.parse::<i64>().is_ok())
+    };
+    line.strip_prefix("p=")
+        .and_then(|rest| rest.split_once(" v="))
+        .is_some_and(|(p, v)| pair(p) && pair(v))
+}
+
+fn has_mul(input: &str) -> bool {
+    input.match_indices("mul(").any(|(i, _)| {
+        let rest = &input[i + "mul(".len()..];
+        rest.split_once(')')
+            .and_then(|(args, _)| args.split_once(','))
+            .is_some_and(|(a, b)| is_number(a) && is_number(b))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{identify, Confidence};
+    use crate::{days, fixtures};
+
+    fn most_likely(input: &str) -> Vec<u8> {
+        identify(input)
+            .most_likely()
+            .iter()
+            .map(|candidate| candidate.day)
+            .collect()
+    }
+
+    #[test]
+    fn every_input_is_its_own_day() {
+        for day in days::DAYS {
+            let input = fixtures::input_or_example(day).unwrap();
+            assert_eq!(vec![day.number], most_likely(&input), "day {}", day.number);
+        }
+    }
+
+    #[test]
+    fn rejections_say_why() {
+        let identification = identify(days::day17::EXAMPLE);
+        let rejection = |day| {
+            identification
+                .rejected
+                .iter()
+                .find(|rejection| rejection.day == day)
+                .unwrap()
+                .reason
+                .as_str()
+        };
+
+        assert_eq!("no `Button A:` and `Prize:` lines", rejection(13));
+        assert_eq!("line 1 is not a `p=x,y v=dx,dy` robot", rejection(14));
+        assert_eq!("not a grid, line 2 has a different width", rejection(15));
+    }
+
+    #[test]
+    fn broken_input_is_a_low_candidate() {
+        let identification = identify("p=0,4 v=3,-3\np=6,3 v=-1,-3");
+        assert_eq!(Confidence::High, identification.candidates[0].confidence);
+
+        let identification = identify("Button A: X+94, Y+34\nPrize: X=8400, Y=5400");
+        let candidate = &identification.candidates[0];
+        assert_eq!((13, Confidence::Low), (candidate.day, candidate.confidence));
+    }
+
+    #[test]
+    fn generic_shapes() {
+        assert_eq!(vec![1], most_likely("3   4\n4   3\n2   5"));
+        assert_eq!(vec![2], most_likely("7 6 4 2 1\n1 2 7 8 9"));
+        assert_eq!(vec![11], most_likely("125 17"));
+    }
+}
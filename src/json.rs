@@ -0,0 +1,346 @@
+use std::{fmt::Write, iter::Peekable, str::CharIndices};
+
+/// Minimal JSON document model, enough for leaderboard exports and our own reports.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Value>),
+    /// Keys keep the order they had in the source document.
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.as_object()?
+            .iter()
+            .find_map(|(k, value)| (k == key).then_some(value))
+    }
+
+    pub fn as_object(&self) -> Option<&[(String, Value)]> {
+        match self {
+            Value::Object(entries) => Some(entries),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Value]> {
+        match self {
+            Value::Array(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    pub fn as_u64(&self) -> Option<u64> {
+        let n = self.as_f64()?;
+        (n >= 0.0 && n.fract() == 0.0 && n <= u64::MAX as f64).then_some(n as u64)
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self, Value::Null)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(s: &str) -> Self {
+        Value::String(s.to_owned())
+    }
+}
+
+impl From<String> for Value {
+    fn from(s: String) -> Self {
+        Value::String(s)
+    }
+}
+
+impl From<u64> for Value {
+    fn from(n: u64) -> Self {
+        Value::Number(n as f64)
+    }
+}
+
+impl From<i64> for Value {
+    fn from(n: i64) -> Self {
+        Value::Number(n as f64)
+    }
+}
+
+impl From<bool> for Value {
+    fn from(b: bool) -> Self {
+        Value::Bool(b)
+    }
+}
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+    fn from(option: Option<T>) -> Self {
+        option.map_or(Value::Null, Into::into)
+    }
+}
+
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Null => f.write_str("null"),
+            Value::Bool(b) => write!(f, "{b}"),
+            Value::Number(n) if n.is_finite() => write!(f, "{n}"),
+            Value::Number(_) => f.write_str("null"),
+            Value::String(s) => write_escaped(f, s),
+            Value::Array(values) => {
+                f.write_char('[')?;
+                for (i, value) in values.iter().enumerate() {
+                    if i != 0 {
+                        f.write_char(',')?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                f.write_char(']')
+            }
+            Value::Object(entries) => {
+                f.write_char('{')?;
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i != 0 {
+                        f.write_char(',')?;
+                    }
+                    write_escaped(f, key)?;
+                    write!(f, ":{value}")?;
+                }
+                f.write_char('}')
+            }
+        }
+    }
+}
+
+fn write_escaped(f: &mut std::fmt::Formatter<'_>, s: &str) -> std::fmt::Result {
+    f.write_char('"')?;
+    for ch in s.chars() {
+        match ch {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            ch if (ch as u32) < 0x20 => write!(f, "\\u{:04x}", ch as u32)?,
+            ch => f.write_char(ch)?,
+        }
+    }
+    f.write_char('"')
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseJsonError {
+    UnexpectedEnd,
+    UnexpectedChar(char, usize),
+    InvalidNumber(String, usize),
+    InvalidEscape(usize),
+    TrailingCharacters(usize),
+}
+
+pub fn parse(s: &str) -> Result<Value, ParseJsonError> {
+    let mut parser = Parser {
+        chars: s.char_indices().peekable(),
+    };
+
+    let value = parser.value()?;
+    parser.skip_whitespace();
+    match parser.chars.next() {
+        Some((i, _)) => Err(ParseJsonError::TrailingCharacters(i)),
+        None => Ok(value),
+    }
+}
+
+struct Parser<'a> {
+    chars: Peekable<CharIndices<'a>>,
+}
+
+impl Parser<'_> {
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|(_, ch)| ch.is_whitespace()).is_some() {}
+    }
+
+    fn next(&mut self) -> Result<(usize, char), ParseJsonError> {
+        self.chars.next().ok_or(ParseJsonError::UnexpectedEnd)
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseJsonError> {
+        match self.next()? {
+            (_, ch) if ch == expected => Ok(()),
+            (i, ch) => Err(ParseJsonError::UnexpectedChar(ch, i)),
+        }
+    }
+
+    fn keyword(&mut self, keyword: &str, value: Value) -> Result<Value, ParseJsonError> {
+        keyword.chars().try_for_each(|ch| self.expect(ch))?;
+        Ok(value)
+    }
+
+    fn value(&mut self) -> Result<Value, ParseJsonError> {
+        self.skip_whitespace();
+        let &(i, ch) = self.chars.peek().ok_or(ParseJsonError::UnexpectedEnd)?;
+        match ch {
+            'n' => self.keyword("null", Value::Null),
+            't' => self.keyword("true", Value::Bool(true)),
+            'f' => self.keyword("false", Value::Bool(false)),
+            '"' => Ok(Value::String(self.string()?)),
+            '[' => self.array(),
+            '{' => self.object(),
+            '-' | '0'..='9' => self.number(),
+            ch => Err(ParseJsonError::UnexpectedChar(ch, i)),
+        }
+    }
+
+    fn number(&mut self) -> Result<Value, ParseJsonError> {
+        let mut s = String::new();
+        let start = self.chars.peek().map(|(i, _)| *i).unwrap_or_default();
+        while let Some((_, ch)) = self
+            .chars
+            .next_if(|(_, ch)| matches!(ch, '-' | '+' | '.' | 'e' | 'E' | '0'..='9'))
+        {
+            s.push(ch);
+        }
+
+        s.parse()
+            .map(Value::Number)
+            .map_err(|_| ParseJsonError::InvalidNumber(s, start))
+    }
+
+    fn string(&mut self) -> Result<String, ParseJsonError> {
+        self.expect('"')?;
+        let mut s = String::new();
+        loop {
+            match self.next()? {
+                (_, '"') => return Ok(s),
+                (i, '\\') => match self.next()?.1 {
+                    '"' => s.push('"'),
+                    '\\' => s.push('\\'),
+                    '/' => s.push('/'),
+                    'b' => s.push('\u{8}'),
+                    'f' => s.push('\u{c}'),
+                    'n' => s.push('\n'),
+                    'r' => s.push('\r'),
+                    't' => s.push('\t'),
+                    'u' => {
+                        let code = self.hex4(i)?;
+                        let code = match code {
+                            0xd800..=0xdbff => {
+                                self.expect('\\')?;
+                                self.expect('u')?;
+                                let low = self.hex4(i)?;
+                                0x10000 + ((code - 0xd800) << 10) + (low.wrapping_sub(0xdc00))
+                            }
+                            code => code,
+                        };
+                        s.push(char::from_u32(code).ok_or(ParseJsonError::InvalidEscape(i))?);
+                    }
+                    _ => return Err(ParseJsonError::InvalidEscape(i)),
+                },
+                (_, ch) => s.push(ch),
+            }
+        }
+    }
+
+    fn hex4(&mut self, escape_start: usize) -> Result<u32, ParseJsonError> {
+        (0..4).try_fold(0, |code, _| {
+            let digit = self.next()?.1.to_digit(16);
+            digit
+                .map(|digit| code * 16 + digit)
+                .ok_or(ParseJsonError::InvalidEscape(escape_start))
+        })
+    }
+
+    fn array(&mut self) -> Result<Value, ParseJsonError> {
+        self.expect('[')?;
+        let mut values = Vec::new();
+        self.skip_whitespace();
+        if self.chars.next_if(|(_, ch)| *ch == ']').is_some() {
+            return Ok(Value::Array(values));
+        }
+
+        loop {
+            values.push(self.value()?);
+            self.skip_whitespace();
+            match self.next()? {
+                (_, ',') => {}
+                (_, ']') => return Ok(Value::Array(values)),
+                (i, ch) => return Err(ParseJsonError::UnexpectedChar(ch, i)),
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<Value, ParseJsonError> {
+        self.expect('{')?;
+        let mut entries = Vec::new();
+        self.skip_whitespace();
+        if self.chars.next_if(|(_, ch)| *ch == '}').is_some() {
+            return Ok(Value::Object(entries));
+        }
+
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            self.skip_whitespace();
+            self.expect(':')?;
+            entries.push((key, self.value()?));
+            self.skip_whitespace();
+            match self.next()? {
+                (_, ',') => {}
+                (_, '}') => return Ok(Value::Object(entries)),
+                (i, ch) => return Err(ParseJsonError::UnexpectedChar(ch, i)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, ParseJsonError, Value};
+
+    #[test]
+    fn parse_document() {
+        let value = parse(r#"{"a": [1, -2.5, true, null], "b": {"c": "d\né"}}"#).unwrap();
+        assert_eq!(
+            Value::Array(vec![
+                Value::Number(1.0),
+                Value::Number(-2.5),
+                Value::Bool(true),
+                Value::Null
+            ]),
+            *value.get("a").unwrap()
+        );
+        assert_eq!(
+            Some("d\né"),
+            value
+                .get("b")
+                .and_then(|b| b.get("c"))
+                .and_then(Value::as_str)
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(Err(ParseJsonError::UnexpectedEnd), parse("[1, 2"));
+        assert_eq!(Err(ParseJsonError::UnexpectedChar('x', 1)), parse("[x]"));
+        assert_eq!(Err(ParseJsonError::TrailingCharacters(3)), parse("{} {}"));
+    }
+
+    #[test]
+    fn display_roundtrip() {
+        let s = r#"{"name":"a \"quoted\" name","stars":[1,2],"ok":false,"none":null}"#;
+        assert_eq!(s, parse(s).unwrap().to_string());
+    }
+}
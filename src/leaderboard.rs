@@ -0,0 +1,545 @@
+use crate::{
+    days,
+    json::{self, ParseJsonError, Value},
+    table::Table,
+};
+use std::collections::BTreeMap;
+
+/// Puzzles unlock at midnight EST, which is 05:00 UTC.
+const UNLOCK_HOUR_UTC: u64 = 5;
+
+/// A private leaderboard as exported by the "API" link on adventofcode.com.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Leaderboard {
+    pub event: u16,
+    pub members: Vec<Member>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u32,
+    /// Star timestamps (unix seconds) by day.
+    pub completions: BTreeMap<u8, Completion>,
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Completion {
+    pub part1: Option<u64>,
+    pub part2: Option<u64>,
+}
+
+impl Completion {
+    fn part(&self, part: u8) -> Option<u64> {
+        match part {
+            1 => self.part1,
+            _ => self.part2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseLeaderboardError {
+    Json(ParseJsonError),
+    MissingField(&'static str),
+    InvalidField(&'static str, String),
+}
+
+impl std::str::FromStr for Leaderboard {
+    type Err = ParseLeaderboardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use ParseLeaderboardError::{InvalidField, MissingField};
+
+        let root = json::parse(s).map_err(ParseLeaderboardError::Json)?;
+        let event = root.get("event").ok_or(MissingField("event"))?;
+        let event = event
+            .as_str()
+            .and_then(|s| s.parse().ok())
+            .ok_or_else(|| InvalidField("event", event.to_string()))?;
+
+        let members = root
+            .get("members")
+            .and_then(Value::as_object)
+            .ok_or(MissingField("members"))?
+            .iter()
+            .map(|(_, member)| parse_member(member))
+            .collect::<Result<Vec<Member>, ParseLeaderboardError>>()?;
+
+        Ok(Leaderboard { event, members })
+    }
+}
+
+fn parse_member(member: &Value) -> Result<Member, ParseLeaderboardError> {
+    use ParseLeaderboardError::{InvalidField, MissingField};
+
+    let number = |field: &'static str| -> Result<u64, ParseLeaderboardError> {
+        let value = member.get(field).ok_or(MissingField(field))?;
+        value
+            .as_u64()
+            .ok_or_else(|| InvalidField(field, value.to_string()))
+    };
+
+    let name = member
+        .get("name")
+        .and_then(Value::as_str)
+        .map(str::to_owned);
+
+    let mut completions = BTreeMap::new();
+    let days = member
+        .get("completion_day_level")
+        .and_then(Value::as_object)
+        .ok_or(MissingField("completion_day_level"))?;
+    for (day, parts) in days {
+        let day_number: u8 = day
+            .parse()
+            .map_err(|_| InvalidField("completion_day_level", day.clone()))?;
+        let star = |part: &str| {
+            parts
+                .get(part)
+                .and_then(|star| star.get("get_star_ts"))
+                .and_then(Value::as_u64)
+        };
+
+        completions.insert(
+            day_number,
+            Completion {
+                part1: star("1"),
+                part2: star("2"),
+            },
+        );
+    }
+
+    Ok(Member {
+        id: number("id")?,
+        name,
+        stars: number("stars")? as u32,
+        local_score: number("local_score")? as u32,
+        completions,
+    })
+}
+
+/// Unix timestamp at which the puzzle for `day` of `year` unlocks.
+pub fn unlock_timestamp(year: u16, day: u8) -> u64 {
+    days_from_civil(year as i64, 12, day as i64) as u64 * 86_400 + UNLOCK_HOUR_UTC * 3600
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date (Howard Hinnant's algorithm).
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub event: u16,
+    pub members: Vec<Member>,
+    pub days: Vec<DayReport>,
+    /// Days with stars on the leaderboard that this project has no solution for.
+    pub unregistered_days: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayReport {
+    pub day: u8,
+    pub unlock: u64,
+    /// Sorted by rank after this day.
+    pub entries: Vec<DayEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayEntry {
+    pub member_id: u64,
+    pub name: String,
+    /// Seconds from unlock to the first star.
+    pub part1: Option<u64>,
+    /// Seconds from unlock to the second star.
+    pub part2: Option<u64>,
+    /// Local score accumulated up to and including this day.
+    pub score: u32,
+    pub rank: usize,
+    /// Places gained (positive) or lost (negative) compared to the previous day.
+    pub rank_change: Option<i64>,
+}
+
+impl DayEntry {
+    /// Seconds between the first and second star, or `None` when the second star is dated
+    /// before the first (clock skew or an edited export).
+    pub fn delta(&self) -> Option<u64> {
+        self.part2?.checked_sub(self.part1?)
+    }
+}
+
+impl Leaderboard {
+    /// Local score each member earned on `day`, computed the way the site does:
+    /// the first of N members to get a star earns N points, the next N - 1 and so on.
+    fn day_points(&self, day: u8) -> BTreeMap<u64, u32> {
+        let n = self.members.len() as u32;
+        let mut points: BTreeMap<u64, u32> = BTreeMap::new();
+
+        for part in [1, 2] {
+            let mut finishers: Vec<(u64, u64)> = self
+                .members
+                .iter()
+                .filter_map(|member| {
+                    let ts = member.completions.get(&day)?.part(part)?;
+                    Some((ts, member.id))
+                })
+                .collect();
+            finishers.sort();
+
+            for (place, (_, id)) in finishers.into_iter().enumerate() {
+                *points.entry(id).or_default() += n - place as u32;
+            }
+        }
+
+        points
+    }
+
+    pub fn report(&self) -> Report {
+        let registered: Vec<u8> = days::DAYS.iter().map(|day| day.number).collect();
+        let last_day = self
+            .members
+            .iter()
+            .flat_map(|member| member.completions.keys().copied())
+            .chain(registered.iter().copied())
+            .max()
+            .unwrap_or_default();
+
+        let mut scores: BTreeMap<u64, u32> = self.members.iter().map(|m| (m.id, 0)).collect();
+        let mut previous_ranks: Option<BTreeMap<u64, usize>> = None;
+        let mut day_reports = Vec::new();
+
+        for day in 1..=last_day {
+            for (id, points) in self.day_points(day) {
+                *scores.entry(id).or_default() += points;
+            }
+            let ranks = competition_ranks(&scores);
+
+            if registered.contains(&day) {
+                let unlock = unlock_timestamp(self.event, day);
+                let mut entries: Vec<DayEntry> = self
+                    .members
+                    .iter()
+                    .map(|member| {
+                        let completion = member.completions.get(&day).copied().unwrap_or_default();
+                        let rank = ranks[&member.id];
+                        DayEntry {
+                            member_id: member.id,
+                            name: member.display_name(),
+                            part1: completion.part1.map(|ts| ts.saturating_sub(unlock)),
+                            part2: completion.part2.map(|ts| ts.saturating_sub(unlock)),
+                            score: scores[&member.id],
+                            rank,
+                            rank_change: previous_ranks
+                                .as_ref()
+                                .map(|previous| previous[&member.id] as i64 - rank as i64),
+                        }
+                    })
+                    .collect();
+                entries.sort_by(|a, b| a.rank.cmp(&b.rank).then_with(|| a.name.cmp(&b.name)));
+
+                day_reports.push(DayReport {
+                    day,
+                    unlock,
+                    entries,
+                });
+            }
+
+            previous_ranks = Some(ranks);
+        }
+
+        let mut unregistered_days: Vec<u8> = self
+            .members
+            .iter()
+            .flat_map(|member| member.completions.keys().copied())
+            .filter(|day| !registered.contains(day))
+            .collect();
+        unregistered_days.sort();
+        unregistered_days.dedup();
+
+        let mut members = self.members.clone();
+        members.sort_by(|a, b| b.local_score.cmp(&a.local_score).then(a.id.cmp(&b.id)));
+
+        Report {
+            event: self.event,
+            members,
+            days: day_reports,
+            unregistered_days,
+        }
+    }
+}
+
+/// Ranks by score, highest first; tied members share a rank ("1224" ranking).
+fn competition_ranks(scores: &BTreeMap<u64, u32>) -> BTreeMap<u64, usize> {
+    scores
+        .iter()
+        .map(|(id, score)| (*id, 1 + scores.values().filter(|s| *s > score).count()))
+        .collect()
+}
+
+/// Formats a number of seconds as `h:mm:ss`, prefixed with whole days when needed.
+pub fn format_duration(secs: u64) -> String {
+    let (days, secs) = (secs / 86_400, secs % 86_400);
+    let (h, m, s) = (secs / 3600, secs % 3600 / 60, secs % 60);
+    match days {
+        0 => format!("{h}:{m:02}:{s:02}"),
+        days => format!("{days}d {h:02}:{m:02}:{s:02}"),
+    }
+}
+
+impl Report {
+    pub fn to_table(&self) -> String {
+        let optional = |secs: Option<u64>| secs.map(format_duration).unwrap_or("-".to_owned());
+        let mut out = format!("Leaderboard {}\n\n", self.event);
+
+        let mut summary = Table::new(["Member", "Stars", "Local score"]);
+        for member in &self.members {
+            summary.push([
+                member.display_name(),
+                member.stars.to_string(),
+                member.local_score.to_string(),
+            ]);
+        }
+        out.push_str(&summary.to_string());
+
+        for day in &self.days {
+            let mut table = Table::new(["Rank", "Change", "Member", "Part 1", "Part 2", "Delta"]);
+            for entry in &day.entries {
+                table.push([
+                    entry.rank.to_string(),
+                    match entry.rank_change {
+                        None | Some(0) => "".to_owned(),
+                        Some(change) => format!("{change:+}"),
+                    },
+                    entry.name.clone(),
+                    optional(entry.part1),
+                    optional(entry.part2),
+                    optional(entry.delta()),
+                ]);
+            }
+
+            out.push_str(&format!("\nDay {}\n{table}", day.day));
+        }
+
+        if !self.unregistered_days.is_empty() {
+            let days: Vec<String> = self.unregistered_days.iter().map(u8::to_string).collect();
+            out.push_str(&format!(
+                "\nDays without a solution here: {}\n",
+                days.join(", ")
+            ));
+        }
+
+        out
+    }
+
+    pub fn to_json(&self) -> Value {
+        let members = self
+            .members
+            .iter()
+            .map(|member| {
+                Value::Object(vec![
+                    ("id".to_owned(), member.id.into()),
+                    ("name".to_owned(), member.display_name().into()),
+                    ("stars".to_owned(), (member.stars as u64).into()),
+                    ("local_score".to_owned(), (member.local_score as u64).into()),
+                ])
+            })
+            .collect();
+
+        let days = self
+            .days
+            .iter()
+            .map(|day| {
+                let entries = day
+                    .entries
+                    .iter()
+                    .map(|entry| {
+                        Value::Object(vec![
+                            ("id".to_owned(), entry.member_id.into()),
+                            ("name".to_owned(), entry.name.as_str().into()),
+                            ("part1_secs".to_owned(), entry.part1.into()),
+                            ("part2_secs".to_owned(), entry.part2.into()),
+                            ("delta_secs".to_owned(), entry.delta().into()),
+                            ("score".to_owned(), (entry.score as u64).into()),
+                            ("rank".to_owned(), (entry.rank as u64).into()),
+                            ("rank_change".to_owned(), entry.rank_change.into()),
+                        ])
+                    })
+                    .collect();
+
+                Value::Object(vec![
+                    ("day".to_owned(), (day.day as u64).into()),
+                    ("unlock_ts".to_owned(), day.unlock.into()),
+                    ("members".to_owned(), Value::Array(entries)),
+                ])
+            })
+            .collect();
+
+        Value::Object(vec![
+            ("event".to_owned(), (self.event as u64).into()),
+            ("members".to_owned(), Value::Array(members)),
+            ("days".to_owned(), Value::Array(days)),
+            (
+                "unregistered_days".to_owned(),
+                Value::Array(
+                    self.unregistered_days
+                        .iter()
+                        .map(|day| (*day as u64).into())
+                        .collect(),
+                ),
+            ),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_duration, unlock_timestamp, DayEntry, Leaderboard};
+
+    const DAY_1: u64 = 1_733_029_200;
+    const DAY_2: u64 = DAY_1 + 86_400;
+
+    fn example() -> Leaderboard {
+        format!(
+            r#"{{"owner_id": 1, "event": "2024", "members": {{
+                "1": {{"id": 1, "name": "Ada", "stars": 3, "local_score": 10, "global_score": 0,
+                       "last_star_ts": {a3},
+                       "completion_day_level": {{
+                           "1": {{"1": {{"get_star_ts": {a1}, "star_index": 1}},
+                                  "2": {{"get_star_ts": {a2}, "star_index": 2}}}},
+                           "2": {{"1": {{"get_star_ts": {a3}, "star_index": 5}}}}}}}},
+                "2": {{"id": 2, "name": null, "stars": 4, "local_score": 11, "global_score": 0,
+                       "last_star_ts": {b4},
+                       "completion_day_level": {{
+                           "1": {{"1": {{"get_star_ts": {b1}, "star_index": 3}},
+                                  "2": {{"get_star_ts": {b2}, "star_index": 4}}}},
+                           "2": {{"1": {{"get_star_ts": {b3}, "star_index": 6}},
+                                  "2": {{"get_star_ts": {b4}, "star_index": 7}}}}}}}}
+            }}}}"#,
+            a1 = DAY_1 + 60,
+            a2 = DAY_1 + 300,
+            a3 = DAY_2 + 4000,
+            b1 = DAY_1 + 120,
+            b2 = DAY_1 + 3725,
+            b3 = DAY_2 + 100,
+            b4 = DAY_2 + 200,
+        )
+        .parse()
+        .unwrap()
+    }
+
+    #[test]
+    fn unlock() {
+        assert_eq!(DAY_1, unlock_timestamp(2024, 1));
+        assert_eq!(DAY_1 + 16 * 86_400, unlock_timestamp(2024, 17));
+    }
+
+    #[test]
+    fn parse() {
+        let leaderboard = example();
+        assert_eq!(2024, leaderboard.event);
+        assert_eq!(2, leaderboard.members.len());
+        assert_eq!("(anonymous user #2)", leaderboard.members[1].display_name());
+        assert_eq!(
+            Some(DAY_1 + 300),
+            leaderboard.members[0].completions[&1].part2
+        );
+        assert_eq!(None, leaderboard.members[0].completions[&2].part2);
+    }
+
+    #[test]
+    fn report() {
+        let report = example().report();
+
+        let day1 = &report.days[0];
+        assert_eq!("Ada", day1.entries[0].name);
+        assert_eq!(
+            (Some(60), Some(300)),
+            (day1.entries[0].part1, day1.entries[0].part2)
+        );
+        assert_eq!(Some(240), day1.entries[0].delta());
+        assert_eq!(
+            (4, 1, None),
+            (
+                day1.entries[0].score,
+                day1.entries[0].rank,
+                day1.entries[0].rank_change
+            )
+        );
+
+        let day2 = &report.days[1];
+        assert_eq!("(anonymous user #2)", day2.entries[0].name);
+        assert_eq!(
+            (6, 1, Some(1)),
+            (
+                day2.entries[0].score,
+                day2.entries[0].rank,
+                day2.entries[0].rank_change
+            )
+        );
+        assert_eq!(
+            (5, 2, Some(-1)),
+            (
+                day2.entries[1].score,
+                day2.entries[1].rank,
+                day2.entries[1].rank_change
+            )
+        );
+        assert_eq!(None, day2.entries[1].delta());
+    }
+
+    #[test]
+    fn to_table() {
+        let table = example().report().to_table();
+        assert!(
+            table.contains("Day 2\nRank  Change  Member               Part 1   Part 2   Delta\n")
+        );
+        assert!(table.contains("1     +1      (anonymous user #2)  0:01:40  0:03:20  0:01:40\n"));
+    }
+
+    #[test]
+    fn delta() {
+        let entry = DayEntry {
+            member_id: 1,
+            name: "Ada".to_owned(),
+            part1: Some(300),
+            part2: Some(60),
+            score: 0,
+            rank: 1,
+            rank_change: None,
+        };
+        assert_eq!(None, entry.delta());
+        assert_eq!(
+            Some(240),
+            DayEntry {
+                part1: Some(60),
+                part2: Some(300),
+                ..entry
+            }
+            .delta()
+        );
+    }
+
+    #[test]
+    fn duration() {
+        assert_eq!("0:04:05", format_duration(245));
+        assert_eq!("1d 01:00:00", format_duration(90_000));
+    }
+}
@@ -0,0 +1,28 @@
+pub mod batch;
+pub mod budget;
+pub mod crypt;
+pub mod days;
+pub mod diff;
+pub mod external;
+pub mod fixtures;
+pub mod grid;
+pub mod identify;
+pub mod json;
+pub mod leaderboard;
+pub mod math;
+pub mod memory;
+pub mod minimize;
+pub mod normalize;
+pub mod parse;
+pub mod plugin;
+pub mod puzzle;
+pub mod recording;
+pub mod repl;
+pub mod report;
+pub mod runner;
+pub mod serve;
+pub mod simulation;
+pub mod snapshot;
+pub mod table;
+pub mod trace;
+pub mod watch;
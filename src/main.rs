@@ -1,3 +1,1013 @@
+use advent_of_code::{
+    batch,
+    crypt::{self, Key},
+    days::{self, Day, Input},
+    diff,
+    external::{self, Solver},
+    fixtures, identify,
+    leaderboard::Leaderboard,
+    memory::{self, AllocStats, CountingAllocator},
+    minimize::{self, Predicate, Reference},
+    normalize::{self, normalize, Whitespace},
+    plugin, puzzle,
+    recording::{self, Recording},
+    repl::Repl,
+    report::{self, DayReport},
+    runner::{self, Limits, Outcome},
+    serve, trace,
+    watch::{self, Report, Watcher},
+};
+use std::{path::PathBuf, time::Duration};
+
+const USAGE: &str = "Usage:
+    advent_of_code [run] [--day N] [--time-limit SECS] [--step-limit STEPS] [--alloc] [--explain]
+    advent_of_code run --day N --inputs DIR [--time-limit SECS] [--step-limit STEPS]
+    advent_of_code run --plugins DIR [--day N] [--inputs DIR] [--time-limit SECS] [--step-limit STEPS] [--alloc] [--explain]
+    advent_of_code leaderboard FILE [--json]
+    advent_of_code report --out DIR [--day N] [--time-limit SECS] [--step-limit STEPS]
+    advent_of_code encrypt [--remove] [FILE...]
+    advent_of_code decrypt [FILE...]
+    advent_of_code identify FILE
+    advent_of_code external --day N --command CMD [--timeout SECS] [--inputs DIR] [--compare]
+    advent_of_code minimize --day N --part P --out FILE (--panics | --exceeds-budget | --differs-from CMD | --differs-from-plugin LIB)
+        [--input PATH] [--time-limit SECS] [--step-limit STEPS]
+    advent_of_code puzzle --day N [--refresh] [--extract] [--url BASE]
+    advent_of_code record --day N --out FILE [--input PATH] [--steps N]
+    advent_of_code replay FILE [--step N] [--diff OTHER]
+    advent_of_code repl
+    advent_of_code serve [--port N] [--time-limit SECS] [--step-limit STEPS] [--max-body BYTES] [--max-concurrent N]
+    advent_of_code watch --day N [--poll] [--time-limit SECS] [--step-limit STEPS]
+
+Every command takes --whitespace strict|tolerant, how inputs are cleaned up before
+parsing. Both fix line ends and trailing newlines; tolerant, the default, also trims
+lines and collapses blank lines.";
+
+/// Counts nothing until `--alloc` turns counting on.
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Time limit every part gets unless `--time-limit` says otherwise.
+const DEFAULT_TIME_LIMIT: Duration = Duration::from_secs(60);
+
 fn main() {
-    println!("Hello, advent of code 2024!");
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args = match take_whitespace(args) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("{err}");
+            eprintln!("{USAGE}");
+            std::process::exit(1);
+        }
+    };
+
+    let result = match args.first().map(String::as_str) {
+        Some("run") => run(&args[1..]),
+        Some("leaderboard") => leaderboard(&args[1..]),
+        Some("report") => report(&args[1..]),
+        Some("encrypt") => encrypt(&args[1..]),
+        Some("decrypt") => decrypt(&args[1..]),
+        Some("identify") => identify(&args[1..]),
+        Some("external") => external(&args[1..]),
+        Some("minimize") => minimize(&args[1..]),
+        Some("puzzle") => puzzle_statement(&args[1..]),
+        Some("record") => record(&args[1..]),
+        Some("replay") => replay(&args[1..]),
+        Some("repl") => repl(),
+        Some("serve") => serve(&args[1..]),
+        Some("watch") => watch(&args[1..]),
+        _ => run(&args),
+    };
+
+    if let Err(err) = result {
+        eprintln!("{err}");
+        eprintln!("{USAGE}");
+        std::process::exit(1);
+    }
+}
+
+/// Removes `--whitespace` from `args`, applying it to every input read from here on.
+fn take_whitespace(mut args: Vec<String>) -> Result<Vec<String>, String> {
+    let Some(index) = args.iter().position(|arg| arg == "--whitespace") else {
+        return Ok(args);
+    };
+    let value = args
+        .get(index + 1)
+        .ok_or("Missing value for --whitespace")?;
+    normalize::set_whitespace(value.parse::<Whitespace>()?);
+    args.drain(index..=index + 1);
+    Ok(args)
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let mut day: Option<u8> = None;
+    let mut alloc = false;
+    let mut explain = false;
+    let mut inputs: Option<&str> = None;
+    let mut plugins: Option<&str> = None;
+    let mut limits = Limits {
+        time: Some(DEFAULT_TIME_LIMIT),
+        steps: None,
+    };
+
+    let mut flags = Flags::new(args);
+    while let Some(arg) = flags.next() {
+        match arg {
+            "--day" => day = Some(flags.parse(arg)?),
+            "--time-limit" | "--step-limit" => flags.limit(arg, &mut limits)?,
+            "--alloc" => alloc = true,
+            "--explain" => explain = true,
+            "--inputs" => inputs = Some(flags.value(arg)?),
+            "--plugins" => plugins = Some(flags.value(arg)?),
+            other => return Err(format!("Unknown argument: {other}")),
+        }
+    }
+
+    // The batch table has no room for allocation counts or trace events.
+    if inputs.is_some() && (alloc || explain) {
+        return Err("--alloc and --explain can't be combined with --inputs".to_owned());
+    }
+    if alloc {
+        memory::enable();
+    }
+
+    if let Some(dir) = plugins {
+        return run_plugins(dir, day, inputs, limits, explain);
+    }
+
+    if let Some(dir) = inputs {
+        let number = day.ok_or("--inputs needs --day N")?;
+        let day = days::get(number).ok_or_else(|| format!("Day {number} is not solved"))?;
+        return run_batch(day, dir, limits);
+    }
+
+    match day {
+        Some(number) => {
+            let day = days::get(number).ok_or_else(|| format!("Day {number} is not solved"))?;
+            run_day(day, limits, explain)
+        }
+        None => days::DAYS
+            .iter()
+            .try_for_each(|day| run_day(day, limits, explain)),
+    }
+}
+
+fn run_plugins(
+    dir: &str,
+    day: Option<u8>,
+    inputs: Option<&str>,
+    limits: Limits,
+    explain: bool,
+) -> Result<(), String> {
+    // Safety: loading runs foreign code, which is what `--plugins` asks for.
+    let loaded = unsafe { plugin::load_dir(std::path::Path::new(dir)) }?;
+    let selected: Vec<_> = loaded
+        .into_iter()
+        .filter(|(_, plugin_day)| day.is_none_or(|number| plugin_day.number == number))
+        .collect();
+    if selected.is_empty() {
+        return Err(format!("No plugin in {dir} solves the requested day"));
+    }
+
+    for (path, day) in selected {
+        println!("Plugin {}", path.display());
+        match inputs {
+            Some(inputs) => run_batch(day, inputs, limits)?,
+            None => run_day(day, limits, explain)?,
+        }
+    }
+
+    Ok(())
+}
+
+fn run_day(day: &Day, limits: Limits, explain: bool) -> Result<(), String> {
+    let input = day
+        .load_input()
+        .map_err(|err| format!("Could not read input for day {}: {err}", day.number))?;
+
+    println!("Day {}", day.number);
+    let parsed = runner::run_parse(day.parse, &input);
+    parsed
+        .result
+        .map_err(|err| format!("Could not parse input for day {}: {err}", day.number))?;
+    println!(
+        "  Parse: ok ({:?}){}",
+        parsed.elapsed,
+        allocations(parsed.allocations)
+    );
+
+    for (n, part) in day.parts() {
+        let (result, events) = match explain {
+            true => trace::collect(|| runner::run_part(part, &input, limits)),
+            false => (runner::run_part(part, &input, limits), Vec::new()),
+        };
+        match result.outcome {
+            Outcome::Answer(answer) => println!(
+                "  Part {n}: {answer} ({:?}){}",
+                result.elapsed,
+                part_allocations(result.allocations)
+            ),
+            outcome => println!("  Part {n}: {outcome}"),
+        }
+        for event in events {
+            println!("    {event}");
+        }
+    }
+
+    Ok(())
+}
+
+fn run_batch(day: &Day, dir: &str, limits: Limits) -> Result<(), String> {
+    let entries = batch::run(day, std::path::Path::new(dir), limits)?;
+    print!("{}", batch::table(day, &entries));
+
+    let mismatched = entries
+        .iter()
+        .filter(|entry| {
+            entry.parse.is_err()
+                || !entry.mismatches().is_empty()
+                || entry
+                    .parts
+                    .iter()
+                    .any(|(_, result)| matches!(result.outcome, Outcome::Panicked(_)))
+        })
+        .count();
+    println!(
+        "{} file(s), {mismatched} with a mismatch, parse error or panic",
+        entries.len()
+    );
+
+    Ok(())
+}
+
+fn allocations(stats: Option<AllocStats>) -> String {
+    stats.map_or(String::new(), |stats| format!(" [{stats}]"))
+}
+
+/// A part parses the input itself, so its figures count a parse on top of its own work.
+fn part_allocations(stats: Option<AllocStats>) -> String {
+    stats.map_or(String::new(), |stats| {
+        format!(" [{stats}, parsing included]")
+    })
+}
+
+fn leaderboard(args: &[String]) -> Result<(), String> {
+    let mut path: Option<&str> = None;
+    let mut as_json = false;
+
+    for arg in args {
+        match arg.as_str() {
+            "--json" => as_json = true,
+            other if other.starts_with("--") => return Err(format!("Unknown argument: {other}")),
+            other => path = Some(other),
+        }
+    }
+
+    let path = path.ok_or("Missing leaderboard file")?;
+    let input =
+        std::fs::read_to_string(path).map_err(|err| format!("Could not read {path}: {err}"))?;
+    let leaderboard: Leaderboard = input
+        .parse()
+        .map_err(|err| format!("Invalid leaderboard {path}: {err:?}"))?;
+
+    let report = leaderboard.report();
+    match as_json {
+        true => println!("{}", report.to_json()),
+        false => print!("{}", report.to_table()),
+    }
+
+    Ok(())
+}
+
+fn report(args: &[String]) -> Result<(), String> {
+    let mut out: Option<&str> = None;
+    let mut day: Option<u8> = None;
+    let mut limits = Limits {
+        time: Some(DEFAULT_TIME_LIMIT),
+        steps: None,
+    };
+
+    let mut flags = Flags::new(args);
+    while let Some(arg) = flags.next() {
+        match arg {
+            "--out" => out = Some(flags.value(arg)?),
+            "--day" => day = Some(flags.parse(arg)?),
+            "--time-limit" | "--step-limit" => flags.limit(arg, &mut limits)?,
+            other => return Err(format!("Unknown argument: {other}")),
+        }
+    }
+
+    let out = out.ok_or("Missing --out DIR")?;
+    let selected: Vec<&Day> = match day {
+        Some(number) => {
+            vec![days::get(number).ok_or_else(|| format!("Day {number} is not solved"))?]
+        }
+        None => days::DAYS.iter().collect(),
+    };
+
+    memory::enable();
+    let mut reports = Vec::new();
+    for day in selected {
+        eprintln!("Day {}", day.number);
+        let input = day
+            .load_input()
+            .map_err(|err| format!("Could not read input for day {}: {err}", day.number))?;
+        reports.push(DayReport::collect(day, &input, limits)?);
+    }
+
+    report::write(std::path::Path::new(out), &reports)?;
+    println!("Wrote {} day page(s) to {out}", reports.len());
+    Ok(())
+}
+
+/// Input files of every solved day, as named in the registry.
+fn input_paths() -> Vec<PathBuf> {
+    days::DAYS
+        .iter()
+        .filter_map(|day| match day.input {
+            Input::File(path) => Some(PathBuf::from(path)),
+            Input::Inline(_) => None,
+        })
+        .collect()
+}
+
+fn encrypt(args: &[String]) -> Result<(), String> {
+    let mut remove = false;
+    let mut paths = Vec::new();
+    for arg in args {
+        match arg.as_str() {
+            "--remove" => remove = true,
+            other if other.starts_with("--") => return Err(format!("Unknown argument: {other}")),
+            other => paths.push(PathBuf::from(other)),
+        }
+    }
+    if paths.is_empty() {
+        paths = input_paths()
+            .into_iter()
+            .filter(|path| path.is_file())
+            .collect();
+    }
+
+    let key = match Key::load().map_err(|err| err.to_string())? {
+        Some(key) => key,
+        None => {
+            let key = Key::generate().map_err(|err| err.to_string())?;
+            write_key_file(&key)?;
+            println!(
+                "Generated a new key in {}, keep it out of git",
+                crypt::DEFAULT_KEY_FILE
+            );
+            key
+        }
+    };
+
+    for path in paths {
+        let input = std::fs::read_to_string(&path)
+            .map_err(|err| format!("Could not read {}: {err}", path.display()))?;
+        let encrypted = crypt::encrypt(&key, &input).map_err(|err| err.to_string())?;
+        if crypt::decrypt(&key, &encrypted).as_deref() != Ok(input.as_str()) {
+            return Err(format!("{} did not survive a round trip", path.display()));
+        }
+
+        let encrypted_path = crypt::encrypted_path(&path);
+        std::fs::write(&encrypted_path, encrypted)
+            .map_err(|err| format!("Could not write {}: {err}", encrypted_path.display()))?;
+        if remove {
+            std::fs::remove_file(&path)
+                .map_err(|err| format!("Could not remove {}: {err}", path.display()))?;
+        }
+        println!("{} -> {}", path.display(), encrypted_path.display());
+    }
+
+    Ok(())
+}
+
+fn write_key_file(key: &Key) -> Result<(), String> {
+    use std::io::Write;
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    options
+        .open(crypt::DEFAULT_KEY_FILE)
+        .and_then(|mut file| writeln!(file, "{}", key.to_hex()))
+        .map_err(|err| format!("Could not write {}: {err}", crypt::DEFAULT_KEY_FILE))
+}
+
+fn decrypt(args: &[String]) -> Result<(), String> {
+    let mut paths = Vec::new();
+    for arg in args {
+        match arg.as_str() {
+            other if other.starts_with("--") => return Err(format!("Unknown argument: {other}")),
+            other => paths.push(PathBuf::from(other.strip_suffix(".enc").unwrap_or(other))),
+        }
+    }
+    if paths.is_empty() {
+        paths = input_paths()
+            .into_iter()
+            .filter(|path| crypt::encrypted_path(path).is_file())
+            .collect();
+    }
+
+    let key = Key::load()
+        .and_then(|key| key.ok_or(crypt::CryptError::MissingKey))
+        .map_err(|err| err.to_string())?;
+
+    for path in paths {
+        let encrypted_path = crypt::encrypted_path(&path);
+        let encrypted = std::fs::read(&encrypted_path)
+            .map_err(|err| format!("Could not read {}: {err}", encrypted_path.display()))?;
+        let input = crypt::decrypt(&key, &encrypted)
+            .map_err(|err| format!("{}: {err}", encrypted_path.display()))?;
+        std::fs::write(&path, input)
+            .map_err(|err| format!("Could not write {}: {err}", path.display()))?;
+        println!("{} -> {}", encrypted_path.display(), path.display());
+    }
+
+    Ok(())
+}
+
+fn serve(args: &[String]) -> Result<(), String> {
+    let mut port: u16 = 2024;
+    let mut config = serve::Config::default();
+
+    let mut flags = Flags::new(args);
+    while let Some(arg) = flags.next() {
+        match arg {
+            "--port" => port = flags.parse(arg)?,
+            "--time-limit" | "--step-limit" => flags.limit(arg, &mut config.limits)?,
+            "--max-body" => config.max_body = flags.parse(arg)?,
+            "--max-concurrent" => {
+                config.max_concurrent = flags.parse(arg)?;
+                if config.max_concurrent == 0 {
+                    return Err("--max-concurrent must be at least 1".to_owned());
+                }
+            }
+            other => return Err(format!("Unknown argument: {other}")),
+        }
+    }
+
+    let listener = std::net::TcpListener::bind(("127.0.0.1", port))
+        .map_err(|err| format!("Could not listen on port {port}: {err}"))?;
+    let address = listener
+        .local_addr()
+        .map_err(|err| format!("Could not listen on port {port}: {err}"))?;
+    println!("Serving on http://{address}, Ctrl-C to stop");
+    serve::serve(listener, config).map_err(|err| format!("Server stopped: {err}"))
+}
+
+fn external(args: &[String]) -> Result<(), String> {
+    let mut day: Option<u8> = None;
+    let mut command: Option<&str> = None;
+    let mut timeout = DEFAULT_TIME_LIMIT;
+    let mut inputs: Option<&str> = None;
+    let mut compare = false;
+
+    let mut flags = Flags::new(args);
+    while let Some(arg) = flags.next() {
+        match arg {
+            "--day" => day = Some(flags.parse(arg)?),
+            "--command" => command = Some(flags.value(arg)?),
+            "--timeout" => timeout = flags.seconds(arg)?,
+            "--inputs" => inputs = Some(flags.value(arg)?),
+            "--compare" => compare = true,
+            other => return Err(format!("Unknown argument: {other}")),
+        }
+    }
+
+    let number = day.ok_or("Missing --day N")?;
+    let day = days::get(number).ok_or_else(|| format!("Day {number} is not solved"))?;
+    let solver = Solver::parse(command.ok_or("Missing --command CMD")?, timeout)?;
+    // The built-in side of a comparison gets as long as the solver.
+    let limits = Limits {
+        time: Some(timeout),
+        steps: None,
+    };
+
+    println!("Day {} ({solver})", day.number);
+    if let Some(dir) = inputs {
+        return external_batch(day, &solver, dir, compare.then_some(limits));
+    }
+
+    for fixture in fixtures::load(day.number)? {
+        match external::check(day, &solver, &fixture) {
+            Ok(checks) => {
+                for check in checks {
+                    let verdict = match check.passed() {
+                        true => "ok".to_owned(),
+                        false => format!("FAILED (expected {})", check.expected),
+                    };
+                    println!(
+                        "  {} part {}: {} {verdict}",
+                        check.fixture, check.part, check.result.outcome
+                    );
+                }
+            }
+            Err(err) => println!("  {}: {err}", fixture.name),
+        }
+    }
+
+    let input = match day.load_input() {
+        Ok(input) => input,
+        Err(err) => {
+            println!("  input: not available ({err})");
+            return Ok(());
+        }
+    };
+    let results = match solver.solve(day, &input) {
+        Ok(results) => results,
+        Err(err) => {
+            println!("  input: {err}");
+            return Ok(());
+        }
+    };
+
+    if compare {
+        runner::run_parse(day.parse, &input)
+            .result
+            .map_err(|err| format!("Could not parse input for day {}: {err}", day.number))?;
+        let builtin: Vec<_> = day
+            .parts()
+            .map(|(n, part)| (n, runner::run_part(part, &input, limits)))
+            .collect();
+        for comparison in external::compare(&builtin, &results) {
+            println!("  input {comparison}");
+        }
+    } else {
+        // The solver runs once for all parts, so only the total time is known.
+        let elapsed: Duration = results.iter().map(|(_, result)| result.elapsed).sum();
+        for (n, result) in results {
+            println!("  input part {n}: {}", result.outcome);
+        }
+        println!("  input solved in {elapsed:?}");
+    }
+
+    Ok(())
+}
+
+fn external_batch(
+    day: &Day,
+    solver: &Solver,
+    dir: &str,
+    compare: Option<Limits>,
+) -> Result<(), String> {
+    let dir = std::path::Path::new(dir);
+    let entries = batch::run_with(dir, |input| match solver.solve(day, input) {
+        Ok(parts) => (Ok(()), parts),
+        Err(err) => (Err(err), Vec::new()),
+    })?;
+    print!("{}", batch::table(day, &entries));
+
+    let mismatched = entries
+        .iter()
+        .filter(|entry| entry.parse.is_err() || !entry.mismatches().is_empty())
+        .count();
+    println!(
+        "{} file(s), {mismatched} with a mismatch or error",
+        entries.len()
+    );
+
+    if let Some(limits) = compare {
+        let builtin = batch::run(day, dir, limits)?;
+        let mut different = 0;
+        for (builtin, external) in builtin.iter().zip(&entries) {
+            for comparison in external::compare(&builtin.parts, &external.parts) {
+                if !comparison.agrees() {
+                    println!("{} {comparison}", external.name);
+                    different += 1;
+                }
+            }
+        }
+        println!("{different} part(s) differ from the built-in solution");
+    }
+
+    Ok(())
+}
+
+fn identify(args: &[String]) -> Result<(), String> {
+    let [path] = args else {
+        return Err("Expected exactly one FILE".to_owned());
+    };
+    let input = crypt::read_input(path).map_err(|err| format!("Could not read {path}: {err}"))?;
+
+    // Parsers fed the wrong day's input may panic; that's a rejection, not news.
+    let hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|_| {}));
+    let identification = identify::identify(&input);
+    std::panic::set_hook(hook);
+
+    match identification.most_likely() {
+        [] => println!("{path} does not look like any day's input"),
+        [best] => println!(
+            "{path} is most likely day {:02} ({} confidence)",
+            best.day, best.confidence
+        ),
+        tied => println!(
+            "{path} could be day {} ({} confidence)",
+            tied.iter()
+                .map(|candidate| format!("{:02}", candidate.day))
+                .collect::<Vec<_>>()
+                .join(" or "),
+            tied[0].confidence
+        ),
+    }
+    if let (Some(named), [best, ..]) = (day_in_name(path), identification.most_likely()) {
+        if named != best.day {
+            println!("but it is named as day {named:02}");
+        }
+    }
+    println!();
+    print!("{}", identification.table());
+    Ok(())
+}
+
+/// The day a path like `files/06.txt` or `files/01/lists.txt` claims to be.
+fn day_in_name(path: &str) -> Option<u8> {
+    std::path::Path::new(path)
+        .iter()
+        .rev()
+        .filter_map(|part| part.to_str()?.split('.').next()?.parse().ok())
+        .find(|n| days::get(*n).is_some())
+}
+
+fn puzzle_statement(args: &[String]) -> Result<(), String> {
+    let mut day: Option<u8> = None;
+    let mut refresh = false;
+    let mut extract = false;
+    let mut base: Option<String> = None;
+
+    let mut flags = Flags::new(args);
+    while let Some(arg) = flags.next() {
+        match arg {
+            "--day" => day = Some(flags.parse(arg)?),
+            "--refresh" => refresh = true,
+            "--extract" => extract = true,
+            "--url" => base = Some(flags.value(arg)?.to_owned()),
+            other => return Err(format!("Unknown argument: {other}")),
+        }
+    }
+
+    let day = day.ok_or("Missing --day N")?;
+    let base = base.unwrap_or_else(puzzle::base_url);
+    let page = puzzle::load(day, &base, std::path::Path::new(puzzle::CACHE_DIR), refresh)?;
+
+    if !extract {
+        print!("{}", puzzle::markdown::to_markdown(&page));
+        return Ok(());
+    }
+
+    let written = puzzle::extract_candidates(day, &page)?;
+    if written.is_empty() {
+        println!("No new examples with answers for day {day}");
+    }
+    for path in written {
+        println!("Wrote {path}");
+    }
+    Ok(())
+}
+
+fn minimize(args: &[String]) -> Result<(), String> {
+    let mut day: Option<u8> = None;
+    let mut part: Option<u8> = None;
+    let mut out: Option<&str> = None;
+    let mut input_path: Option<&str> = None;
+    let mut predicate: Option<Predicate> = None;
+    let mut limits = Limits {
+        time: Some(Duration::from_secs(10)),
+        steps: None,
+    };
+
+    let mut flags = Flags::new(args);
+    while let Some(arg) = flags.next() {
+        match arg {
+            "--day" => day = Some(flags.parse(arg)?),
+            "--part" => part = Some(flags.parse(arg)?),
+            "--out" => out = Some(flags.value(arg)?),
+            "--input" => input_path = Some(flags.value(arg)?),
+            "--time-limit" | "--step-limit" => flags.limit(arg, &mut limits)?,
+            "--panics" => predicate = Some(Predicate::Panics),
+            "--exceeds-budget" => predicate = Some(Predicate::ExceedsBudget),
+            "--differs-from" => {
+                let solver = Solver::parse(flags.value(arg)?, DEFAULT_TIME_LIMIT)?;
+                predicate = Some(Predicate::DiffersFrom(Reference::External(solver)));
+            }
+            "--differs-from-plugin" => {
+                let path = std::path::Path::new(flags.value(arg)?);
+                // Safety: loading runs foreign code, which is what the flag asks for.
+                let plugin = unsafe { plugin::Plugin::load(path) }?;
+                let day = plugin::register(plugin)?;
+                predicate = Some(Predicate::DiffersFrom(Reference::Day(day)));
+            }
+            other => return Err(format!("Unknown argument: {other}")),
+        }
+    }
+
+    let number = day.ok_or("Missing --day N")?;
+    let day = days::get(number).ok_or_else(|| format!("Day {number} is not solved"))?;
+    let part = part.ok_or("Missing --part P")?;
+    let out = out.ok_or("Missing --out FILE")?;
+    let predicate = predicate
+        .ok_or("Missing --panics, --exceeds-budget, --differs-from or --differs-from-plugin")?;
+    let input = match input_path {
+        Some(path) => {
+            crypt::read_input(path).map_err(|err| format!("Could not read {path}: {err}"))?
+        }
+        None => day
+            .load_input()
+            .map_err(|err| format!("Could not read input for day {number}: {err}"))?,
+    };
+    let input = normalize(&input);
+
+    // Every candidate that still reproduces a panic would print it.
+    let hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|_| {}));
+    let minimized = match predicate.holds(day, part, &input, limits) {
+        true => Ok(minimize::minimize(
+            &input,
+            &minimize::passes(number),
+            |candidate| predicate.holds(day, part, candidate, limits),
+            |pass, len| println!("  {pass}: {len} bytes"),
+        )),
+        false => Err(format!(
+            "Day {number} part {part} does not misbehave on the input to begin with"
+        )),
+    };
+    std::panic::set_hook(hook);
+    let minimized = minimized?;
+
+    std::fs::write(out, &minimized).map_err(|err| format!("Could not write {out}: {err}"))?;
+    println!(
+        "Wrote a {} byte reproducer to {out}, down from {} bytes",
+        minimized.len(),
+        input.len()
+    );
+
+    Ok(())
+}
+
+fn record(args: &[String]) -> Result<(), String> {
+    let mut day: Option<u8> = None;
+    let mut out: Option<&str> = None;
+    let mut input_path: Option<&str> = None;
+    let mut steps: Option<usize> = None;
+
+    let mut flags = Flags::new(args);
+    while let Some(arg) = flags.next() {
+        match arg {
+            "--day" => day = Some(flags.parse(arg)?),
+            "--out" => out = Some(flags.value(arg)?),
+            "--input" => input_path = Some(flags.value(arg)?),
+            "--steps" => steps = Some(flags.parse(arg)?),
+            other => return Err(format!("Unknown argument: {other}")),
+        }
+    }
+
+    let number = day.ok_or("Missing --day N")?;
+    let out = out.ok_or("Missing --out FILE")?;
+    let input = match input_path {
+        Some(path) => {
+            crypt::read_input(path).map_err(|err| format!("Could not read {path}: {err}"))?
+        }
+        None => days::get(number)
+            .ok_or_else(|| format!("Day {number} is not solved"))?
+            .load_input()
+            .map_err(|err| format!("Could not read input for day {number}: {err}"))?,
+    };
+
+    let recording = recording::record(number, &normalize(&input), steps)?;
+    std::fs::write(out, recording.to_string())
+        .map_err(|err| format!("Could not write {out}: {err}"))?;
+    println!(
+        "Recorded {} steps of day {number} to {out}",
+        recording.steps.len()
+    );
+    Ok(())
+}
+
+fn read_recording(path: &str) -> Result<Recording, String> {
+    std::fs::read_to_string(path)
+        .map_err(|err| format!("Could not read {path}: {err}"))?
+        .parse()
+        .map_err(|err: recording::ParseRecordingError| format!("{path}: {}", err.0))
+}
+
+fn replay(args: &[String]) -> Result<(), String> {
+    let mut path: Option<&str> = None;
+    let mut step: Option<usize> = None;
+    let mut other: Option<&str> = None;
+
+    let mut flags = Flags::new(args);
+    while let Some(arg) = flags.next() {
+        match arg {
+            "--step" => step = Some(flags.parse(arg)?),
+            "--diff" => other = Some(flags.value(arg)?),
+            flag if flag.starts_with("--") => return Err(format!("Unknown argument: {flag}")),
+            file => path = Some(file),
+        }
+    }
+
+    let recording = read_recording(path.ok_or("Missing recording FILE")?)?;
+
+    if let Some(other) = other {
+        let other = read_recording(other)?;
+        let Some(divergence) = recording::diff(&recording, &other) else {
+            println!("Recordings are identical ({} steps)", recording.steps.len());
+            return Ok(());
+        };
+
+        println!("{divergence}");
+        if let recording::Divergence::Step { step, .. } = divergence {
+            let (a, b) = (recording.state_at(step), other.state_at(step));
+            if let Some(grid_diff) =
+                diff::grid_diff(&a.render_grid(), &b.render_grid(), diff::use_color())
+            {
+                println!("{grid_diff}");
+            }
+        }
+        return Ok(());
+    }
+
+    let step = step.unwrap_or(recording.steps.len());
+    println!(
+        "Day {} recording, step {} of {}",
+        recording.day,
+        step.min(recording.steps.len()),
+        recording.steps.len()
+    );
+    print!("{}", recording.state_at(step));
+    Ok(())
+}
+
+fn repl() -> Result<(), String> {
+    use std::io::{BufRead, Write};
+
+    let mut repl = Repl::new();
+    let mut lines = std::io::stdin().lock().lines();
+    println!("Type `help` for commands, `quit` to leave.");
+
+    loop {
+        print!("{}", repl.prompt());
+        std::io::stdout().flush().map_err(|err| err.to_string())?;
+
+        let Some(line) = lines.next() else {
+            return Ok(());
+        };
+        let line = line.map_err(|err| format!("Could not read stdin: {err}"))?;
+        if matches!(line.trim(), "quit" | "exit") {
+            return Ok(());
+        }
+
+        match repl.eval(&line) {
+            Ok(Some(output)) => print!("{output}"),
+            Ok(None) => {}
+            Err(err) => println!("error: {err}"),
+        }
+    }
+}
+
+fn watch(args: &[String]) -> Result<(), String> {
+    let mut day: Option<u8> = None;
+    let mut limits = Limits {
+        time: Some(DEFAULT_TIME_LIMIT),
+        steps: None,
+    };
+    let (mut once, mut as_json, mut force_polling) = (false, false, false);
+
+    let mut flags = Flags::new(args);
+    while let Some(arg) = flags.next() {
+        match arg {
+            "--day" => day = Some(flags.parse(arg)?),
+            "--time-limit" | "--step-limit" => flags.limit(arg, &mut limits)?,
+            "--poll" => force_polling = true,
+            // Used by the watcher itself to check a rebuilt binary.
+            "--once" => once = true,
+            "--json" => as_json = true,
+            other => return Err(format!("Unknown argument: {other}")),
+        }
+    }
+
+    let number = day.ok_or("Missing --day")?;
+    let day = days::get(number).ok_or_else(|| format!("Day {number} is not solved"))?;
+
+    if once {
+        let report = watch::check(day, limits);
+        match as_json {
+            true => println!("{}", report.to_json()),
+            false => println!("{}", report.summary(None)),
+        }
+        return Ok(());
+    }
+
+    let paths = watch::paths(day);
+    let mut watcher = Watcher::new(paths.clone(), force_polling);
+    let watched: Vec<String> = paths.iter().map(|p| p.display().to_string()).collect();
+    println!(
+        "Watching {} ({}), Ctrl-C to stop",
+        watched.join(", "),
+        watcher.backend_name()
+    );
+
+    let mut run = 1;
+    println!("-- run {run}");
+    let report = watch::check(day, limits);
+    println!("{}", report.summary(None));
+
+    let mut previous: Option<Report> = Some(report);
+    let mut stale = false;
+
+    loop {
+        let changed = watcher
+            .wait()
+            .map_err(|err| format!("Watching failed: {err}"))?;
+        run += 1;
+        let changed: Vec<String> = changed.iter().map(|p| p.display().to_string()).collect();
+        println!("\n-- run {run}: {} changed", changed.join(", "));
+
+        if changed.iter().any(|path| watch::is_source(path.as_ref())) {
+            stale = true;
+            match watch::run_tests(number) {
+                Ok(tests) if tests.failed.is_empty() => println!("tests: {} passed", tests.passed),
+                Ok(tests) => {
+                    println!(
+                        "tests: {} passed, {} failed",
+                        tests.passed,
+                        tests.failed.len()
+                    );
+                    tests
+                        .failed
+                        .iter()
+                        .for_each(|name| println!("  FAIL {name}"));
+                }
+                Err(err) => {
+                    println!("build failed:\n{err}");
+                    continue;
+                }
+            }
+        }
+
+        let report = match stale {
+            true => match watch::check_rebuilt(number, limits) {
+                Ok(report) => report,
+                Err(err) => {
+                    println!("build failed:\n{err}");
+                    continue;
+                }
+            },
+            false => watch::check(day, limits),
+        };
+        println!("{}", report.summary(previous.as_ref()));
+        previous = Some(report);
+    }
+}
+
+/// A command's arguments, taken one flag at a time.
+struct Flags<'a> {
+    args: std::slice::Iter<'a, String>,
+}
+
+impl<'a> Flags<'a> {
+    fn new(args: &'a [String]) -> Self {
+        Self { args: args.iter() }
+    }
+
+    /// The value following `flag`.
+    fn value(&mut self, flag: &str) -> Result<&'a str, String> {
+        self.next()
+            .ok_or_else(|| format!("Missing value for {flag}"))
+    }
+
+    fn parse<T: std::str::FromStr>(&mut self, flag: &str) -> Result<T, String> {
+        parse_value(flag, self.value(flag)?)
+    }
+
+    /// A value in seconds, which can't be negative.
+    fn seconds(&mut self, flag: &str) -> Result<Duration, String> {
+        let value = self.value(flag)?;
+        Duration::try_from_secs_f64(parse_value(flag, value)?)
+            .map_err(|_| format!("Invalid value for {flag}: {value}"))
+    }
+
+    /// Reads the value of `--time-limit` or `--step-limit` into `limits`.
+    fn limit(&mut self, flag: &str, limits: &mut Limits) -> Result<(), String> {
+        match flag {
+            "--time-limit" => limits.time = Some(self.seconds(flag)?),
+            _ => limits.steps = Some(self.parse(flag)?),
+        }
+        Ok(())
+    }
+}
+
+impl<'a> Iterator for Flags<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        self.args.next().map(String::as_str)
+    }
+}
+
+fn parse_value<T: std::str::FromStr>(arg: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid value for {arg}: {value}"))
 }
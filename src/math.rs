@@ -0,0 +1,196 @@
+//! Integer number theory shared by the days. Results that can overflow are checked and come
+//! back as `None` instead of wrapping.
+
+/// Greatest common divisor, with `gcd(0, 0) == 0`.
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, with `lcm(0, n) == 0`.
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    match gcd(a, b) {
+        0 => Some(0),
+        g => (a / g).checked_mul(b),
+    }
+}
+
+/// `(g, x, y)` with `a * x + b * y == g`, where `g` is the non-negative gcd of `a` and `b`.
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    match old_r < 0 {
+        true => (-old_r, -old_x, -old_y),
+        false => (old_r, old_x, old_y),
+    }
+}
+
+/// The `x` in `0..m` with `a * x ≡ 1 (mod m)`, when `a` and `m` are coprime.
+pub fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    if m <= 0 {
+        return None;
+    }
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// `base^exp mod m`, by squaring. `m` must not be zero.
+pub fn mod_pow(base: u64, mut exp: u64, m: u64) -> u64 {
+    let m = u128::from(m);
+    let mut base = u128::from(base) % m;
+    let mut result = 1 % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % m;
+        }
+        base = base * base % m;
+        exp >>= 1;
+    }
+    result as u64
+}
+
+/// Chinese remainder theorem for `x ≡ residue (mod modulus)` pairs whose moduli need not be
+/// coprime. Returns the smallest non-negative `x` and the lcm of the moduli it repeats with,
+/// or `None` when the congruences contradict each other or the lcm overflows.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let mut x: i64 = 0;
+    let mut modulus: i64 = 1;
+    for &(residue, m) in congruences {
+        if m <= 0 {
+            return None;
+        }
+        // x + modulus * k ≡ residue (mod m), solved for k.
+        let (g, inverse, _) = extended_gcd(modulus, m);
+        let difference = residue - x;
+        if difference % g != 0 {
+            return None;
+        }
+        let step = m / g;
+        let k = i128::from(difference / g) * i128::from(inverse) % i128::from(step);
+        let lcm = modulus.checked_mul(step)?;
+        x = ((i128::from(x) + i128::from(modulus) * k).rem_euclid(i128::from(lcm))) as i64;
+        modulus = lcm;
+    }
+    Some((x, modulus))
+}
+
+/// Number of decimal digits, with `0` having one.
+pub fn digit_count(n: u64) -> u32 {
+    n.checked_ilog10().unwrap_or(0) + 1
+}
+
+/// The left and right halves of a number with an even count of digits, e.g. `1000` into
+/// `(10, 0)`.
+pub fn split_digits(n: u64) -> Option<(u64, u64)> {
+    let digits = digit_count(n);
+    if !digits.is_multiple_of(2) {
+        return None;
+    }
+    let half = 10u64.pow(digits / 2);
+    Some((n / half, n % half))
+}
+
+/// The digits of `a` followed by those of `b`, e.g. `12` and `345` into `12345`.
+pub fn concat_digits(a: u64, b: u64) -> Option<u64> {
+    10u64
+        .checked_pow(digit_count(b))?
+        .checked_mul(a)?
+        .checked_add(b)
+}
+
+/// Division rounding towards negative infinity. Panics when `b` is zero.
+pub fn div_floor(a: i64, b: i64) -> i64 {
+    let q = a / b;
+    match (a % b != 0) && ((a < 0) != (b < 0)) {
+        true => q - 1,
+        false => q,
+    }
+}
+
+/// Division rounding towards positive infinity. Panics when `b` is zero.
+pub fn div_ceil(a: i64, b: i64) -> i64 {
+    let q = a / b;
+    match (a % b != 0) && ((a < 0) == (b < 0)) {
+        true => q + 1,
+        false => q,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        concat_digits, crt, digit_count, div_ceil, div_floor, extended_gcd, gcd, lcm, mod_inverse,
+        mod_pow, split_digits,
+    };
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(6, gcd(54, 24));
+        assert_eq!(7, gcd(0, 7));
+        assert_eq!(0, gcd(0, 0));
+        assert_eq!(Some(10403), lcm(101, 103));
+        assert_eq!(Some(12), lcm(4, 6));
+        assert_eq!(Some(0), lcm(0, 5));
+        assert_eq!(None, lcm(u64::MAX, u64::MAX - 1));
+    }
+
+    #[test]
+    fn extended_euclid() {
+        for (a, b) in [(240, 46), (-240, 46), (17, 0), (0, -5), (94, 22)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(gcd(a.unsigned_abs(), b.unsigned_abs()), g as u64);
+            assert_eq!(g, a * x + b * y);
+        }
+    }
+
+    #[test]
+    fn modular() {
+        assert_eq!(Some(4), mod_inverse(3, 11));
+        assert_eq!(Some(7), mod_inverse(-3, 11));
+        assert_eq!(None, mod_inverse(6, 9));
+        assert_eq!(445, mod_pow(4, 13, 497));
+        assert_eq!(0, mod_pow(5, 0, 1));
+        assert_eq!(1, mod_pow(u64::MAX, u64::MAX, u64::MAX - 1));
+    }
+
+    #[test]
+    fn chinese_remainders() {
+        assert_eq!(Some((23, 105)), crt(&[(2, 3), (3, 5), (2, 7)]));
+        // Moduli sharing a factor, as with robots on a 4 by 6 torus.
+        assert_eq!(Some((10, 12)), crt(&[(2, 4), (4, 6)]));
+        assert_eq!(None, crt(&[(1, 4), (2, 6)]));
+        assert_eq!(Some((3, 7)), crt(&[(-4, 7)]));
+        assert_eq!(Some((0, 1)), crt(&[]));
+    }
+
+    #[test]
+    fn digits() {
+        assert_eq!(1, digit_count(0));
+        assert_eq!(4, digit_count(2024));
+        assert_eq!(20, digit_count(u64::MAX));
+        assert_eq!(Some((253, 0)), split_digits(253000));
+        assert_eq!(Some((1, 0)), split_digits(10));
+        assert_eq!(None, split_digits(253));
+        assert_eq!(Some(12345), concat_digits(12, 345));
+        assert_eq!(Some(60), concat_digits(6, 0));
+        assert_eq!(None, concat_digits(u64::MAX / 10, 99));
+    }
+
+    #[test]
+    fn signed_division() {
+        assert_eq!((2, 3), (div_floor(7, 3), div_ceil(7, 3)));
+        assert_eq!((-3, -2), (div_floor(-7, 3), div_ceil(-7, 3)));
+        assert_eq!((-3, -2), (div_floor(7, -3), div_ceil(7, -3)));
+        assert_eq!((2, 3), (div_floor(-7, -3), div_ceil(-7, -3)));
+        assert_eq!((-2, -2), (div_floor(-6, 3), div_ceil(-6, 3)));
+    }
+}
@@ -0,0 +1,174 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+static LIVE: AtomicU64 = AtomicU64::new(0);
+static PEAK: AtomicU64 = AtomicU64::new(0);
+
+/// Global allocator that counts allocations on top of [`System`].
+///
+/// Installing it costs one relaxed load per allocation; nothing is counted until
+/// [`enable`] is called. Counters are process-wide, so measurements are only
+/// meaningful while a single thread is allocating.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size() as u64);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size() as u64);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record_dealloc(layout.size() as u64);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record_dealloc(layout.size() as u64);
+            record_alloc(new_size as u64);
+        }
+        new_ptr
+    }
+}
+
+fn record_alloc(size: u64) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    BYTES.fetch_add(size, Ordering::Relaxed);
+    let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(live, Ordering::Relaxed);
+}
+
+fn record_dealloc(size: u64) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    // Memory allocated before counting was enabled may be freed while it is on,
+    // so live bytes saturate at zero instead of wrapping.
+    let _ = LIVE.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |live| {
+        Some(live.saturating_sub(size))
+    });
+}
+
+/// Starts counting. Only has an effect when [`CountingAllocator`] is the global allocator.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// What a measured closure allocated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    pub allocations: u64,
+    pub bytes: u64,
+    /// Highest number of bytes live at once, above what was live when measuring started.
+    pub peak: u64,
+}
+
+impl std::fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocs, {} allocated, {} peak",
+            self.allocations,
+            format_bytes(self.bytes),
+            format_bytes(self.peak)
+        )
+    }
+}
+
+/// Runs `f` and reports its allocations, or `None` when counting is not enabled.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    if !is_enabled() {
+        return (f(), None);
+    }
+
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = BYTES.load(Ordering::Relaxed);
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+
+    let value = f();
+
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: BYTES.load(Ordering::Relaxed) - bytes,
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(live),
+    };
+
+    (value, Some(stats))
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    match unit {
+        0 => format!("{bytes} B"),
+        _ => format!("{value:.1} {}", UNITS[unit]),
+    }
+}
+
+#[cfg(test)]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+#[cfg(test)]
+mod tests {
+    use super::{enable, format_bytes, measure};
+
+    #[test]
+    fn measure_allocations() {
+        enable();
+
+        // Other tests allocate concurrently, so only lower bounds hold.
+        let (len, stats) = measure(|| {
+            let first = std::hint::black_box(vec![0u8; 4096]);
+            let second = std::hint::black_box(vec![0u8; 4096]);
+            first.len() + second.len()
+        });
+        let stats = stats.unwrap();
+
+        assert_eq!(8192, len);
+        assert!(stats.allocations >= 2);
+        assert!(stats.bytes >= 8192);
+        assert!(stats.peak >= 8192);
+    }
+
+    #[test]
+    fn bytes() {
+        assert_eq!("512 B", format_bytes(512));
+        assert_eq!("1.5 KiB", format_bytes(1536));
+        assert_eq!("3.0 MiB", format_bytes(3 * 1024 * 1024));
+    }
+}
@@ -0,0 +1,352 @@
+//! Shrinks an input that makes a solution misbehave into a small reproducer, by delta
+//! debugging: parts of the input are removed for as long as the misbehavior survives.
+//!
+//! The input is seen as blocks separated by blank lines. Every [`Pass`] removes one kind of
+//! unit (lines of a block, columns of a grid block, or the characters of day 15's moves),
+//! first in large chunks, then in smaller ones. Passes repeat until none of them gets the input
+//! any smaller. A candidate the day's parser rejects never counts as reproducing anything.
+//!
+//! Only parts that tick their [`Budget`](crate::budget::Budget) can be stopped, so a shrunk
+//! input that sends a part into an endless loop that does not tick hangs the minimizer.
+
+use crate::{
+    days::{Day, Part},
+    external::Solver,
+    runner::{self, Limits, Outcome},
+};
+use std::{
+    collections::HashSet,
+    panic::{self, AssertUnwindSafe},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Pass {
+    /// Lines of each block, e.g. day 5's rules and updates.
+    Lines,
+    /// Columns of each block that is a rectangular grid.
+    Columns,
+    /// Characters of one block, wrapped again at its old width, e.g. day 15's moves.
+    Characters { block: usize },
+}
+
+impl std::fmt::Display for Pass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Pass::Lines => f.write_str("lines"),
+            Pass::Columns => f.write_str("grid columns"),
+            Pass::Characters { block } => write!(f, "characters of block {}", block + 1),
+        }
+    }
+}
+
+/// The passes worth trying on a day's input.
+pub fn passes(day: u8) -> Vec<Pass> {
+    match day {
+        15 => vec![Pass::Lines, Pass::Characters { block: 1 }, Pass::Columns],
+        _ => vec![Pass::Lines, Pass::Columns],
+    }
+}
+
+/// What a smaller input has to keep doing to be kept.
+pub enum Predicate {
+    /// The part panics.
+    Panics,
+    /// The part runs out of its budget.
+    ExceedsBudget,
+    /// The part and another implementation of it both answer, differently.
+    DiffersFrom(Reference),
+}
+
+/// Another implementation of a day, see [`Predicate::DiffersFrom`].
+pub enum Reference {
+    External(Solver),
+    /// A built-in or plugin day, see [`crate::plugin`].
+    Day(&'static Day),
+}
+
+impl Reference {
+    fn answer(&self, day: &Day, part: u8, input: &str, limits: Limits) -> Option<String> {
+        match self {
+            Reference::External(solver) => {
+                let results = solver.solve(day, input).ok()?;
+                match &results.iter().find(|(n, _)| *n == part)?.1.outcome {
+                    Outcome::Answer(answer) => Some(answer.clone()),
+                    Outcome::InvalidInput(_)
+                    | Outcome::BudgetExceeded(_)
+                    | Outcome::Panicked(_) => None,
+                }
+            }
+            Reference::Day(day) => {
+                let (_, reference) = day.parts().find(|(n, _)| *n == part)?;
+                run(day, reference, input, limits)?.ok()
+            }
+        }
+    }
+}
+
+/// Runs the day's parser, then `part` when it accepts the input. `None` when either rejects
+/// the input, otherwise the part's answer, or `Err(true)` when it panicked and `Err(false)`
+/// when it ran out of budget.
+fn run(day: &Day, part: Part, input: &str, limits: Limits) -> Option<Result<String, bool>> {
+    match panic::catch_unwind(|| (day.parse)(input)) {
+        Ok(Ok(())) => {}
+        _ => return None,
+    }
+    let result = panic::catch_unwind(AssertUnwindSafe(|| runner::run_part(part, input, limits)));
+    Some(match result {
+        Ok(result) => match result.outcome {
+            Outcome::Answer(answer) => Ok(answer),
+            Outcome::InvalidInput(_) => return None,
+            Outcome::BudgetExceeded(_) => Err(false),
+            Outcome::Panicked(_) => Err(true),
+        },
+        Err(_) => Err(true),
+    })
+}
+
+impl Predicate {
+    /// Whether part `n` of `day` still misbehaves on `input`. Panics are expected, silence the
+    /// panic hook around a minimization.
+    pub fn holds(&self, day: &Day, n: u8, input: &str, limits: Limits) -> bool {
+        let Some((_, part)) = day.parts().find(|(m, _)| *m == n) else {
+            return false;
+        };
+        match (self, run(day, part, input, limits)) {
+            (_, None) => false,
+            (Predicate::Panics, Some(result)) => result == Err(true),
+            (Predicate::ExceedsBudget, Some(result)) => result == Err(false),
+            (Predicate::DiffersFrom(reference), Some(Ok(answer))) => reference
+                .answer(day, n, input, limits)
+                .is_some_and(|other| other != answer),
+            (Predicate::DiffersFrom(_), Some(Err(_))) => false,
+        }
+    }
+}
+
+/// The smallest input found by `passes` for which `holds` is still true. `progress` hears
+/// about every pass that got the input smaller, with the new length in bytes.
+pub fn minimize(
+    input: &str,
+    passes: &[Pass],
+    mut holds: impl FnMut(&str) -> bool,
+    mut progress: impl FnMut(Pass, usize),
+) -> String {
+    let mut blocks = Blocks::parse(input);
+    // Candidates that did not reproduce, so later rounds do not run them again.
+    let mut rejected: HashSet<String> = HashSet::new();
+    let mut test = |blocks: &Blocks| {
+        let candidate = blocks.render();
+        if rejected.contains(&candidate) {
+            return false;
+        }
+        let kept = holds(&candidate);
+        if !kept {
+            rejected.insert(candidate);
+        }
+        kept
+    };
+
+    loop {
+        let mut shrunk = false;
+        for &pass in passes {
+            let before = blocks.render().len();
+            blocks = apply(pass, blocks, &mut test);
+            let after = blocks.render().len();
+            if after < before {
+                progress(pass, after);
+                shrunk = true;
+            }
+        }
+        if !shrunk {
+            return blocks.render();
+        }
+    }
+}
+
+/// Turns the indices of the units a candidate keeps into the lines of its block.
+type Rebuild = Box<dyn Fn(&[usize]) -> Vec<String>>;
+
+fn apply(pass: Pass, mut blocks: Blocks, test: &mut impl FnMut(&Blocks) -> bool) -> Blocks {
+    for b in 0..blocks.0.len() {
+        let lines = blocks.0[b].clone();
+        let rebuild: Rebuild = match pass {
+            Pass::Lines => {
+                let lines = lines.clone();
+                Box::new(move |kept| kept.iter().map(|&i| lines[i].clone()).collect())
+            }
+            Pass::Columns => {
+                let grid: Vec<Vec<char>> =
+                    lines.iter().map(|line| line.chars().collect()).collect();
+                let width = grid.first().map_or(0, Vec::len);
+                if grid.len() < 2 || width < 2 || grid.iter().any(|row| row.len() != width) {
+                    continue;
+                }
+                Box::new(move |kept| {
+                    grid.iter()
+                        .map(|row| kept.iter().map(|&x| row[x]).collect())
+                        .collect()
+                })
+            }
+            Pass::Characters { block } if block == b => {
+                let width = lines
+                    .iter()
+                    .map(|line| line.chars().count())
+                    .max()
+                    .unwrap_or(0)
+                    .max(1);
+                let chars: Vec<char> = lines.iter().flat_map(|line| line.chars()).collect();
+                Box::new(move |kept| {
+                    let chars: Vec<char> = kept.iter().map(|&i| chars[i]).collect();
+                    chars
+                        .chunks(width)
+                        .map(|line| line.iter().collect())
+                        .collect()
+                })
+            }
+            Pass::Characters { .. } => continue,
+        };
+
+        let units = match pass {
+            Pass::Lines => lines.len(),
+            Pass::Columns => lines[0].chars().count(),
+            Pass::Characters { .. } => lines.iter().map(|line| line.chars().count()).sum(),
+        };
+        let kept = ddmin(units, |kept| {
+            let mut candidate = blocks.clone();
+            candidate.0[b] = rebuild(kept);
+            test(&candidate)
+        });
+        blocks.0[b] = rebuild(&kept);
+    }
+
+    blocks
+}
+
+/// The indices of `0..units` left after removing every chunk `test` allows to go, from
+/// halves down to single units. At least one unit is always kept.
+fn ddmin(units: usize, mut test: impl FnMut(&[usize]) -> bool) -> Vec<usize> {
+    let mut kept: Vec<usize> = (0..units).collect();
+    let mut chunk = units / 2;
+    while chunk > 0 {
+        let mut start = 0;
+        while start < kept.len() {
+            let end = (start + chunk).min(kept.len());
+            if kept.len() - (end - start) == 0 {
+                break;
+            }
+            let candidate: Vec<usize> = kept[..start].iter().chain(&kept[end..]).copied().collect();
+            match test(&candidate) {
+                true => kept = candidate,
+                false => start = end,
+            }
+        }
+        chunk /= 2;
+    }
+    kept
+}
+
+/// An input as blocks of lines, split at blank lines.
+#[derive(Debug, Clone, PartialEq)]
+struct Blocks(Vec<Vec<String>>);
+
+impl Blocks {
+    fn parse(input: &str) -> Blocks {
+        Blocks(
+            input
+                .trim()
+                .split("\n\n")
+                .map(|block| block.lines().map(str::to_owned).collect())
+                .collect(),
+        )
+    }
+
+    fn render(&self) -> String {
+        let blocks: Vec<String> = self.0.iter().map(|block| block.join("\n")).collect();
+        format!("{}\n", blocks.join("\n\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ddmin, minimize, passes, Pass, Predicate};
+    use crate::{
+        days::{Day, Input},
+        runner::Limits,
+    };
+
+    #[test]
+    fn keeps_what_matters() {
+        assert_eq!(
+            vec![3, 7],
+            ddmin(10, |kept| kept.contains(&3) && kept.contains(&7))
+        );
+        assert_eq!(vec![3], ddmin(4, |_| true));
+    }
+
+    #[test]
+    fn lines() {
+        let input: String = (1..=40).map(|n| format!("{n}\n")).collect();
+        let minimized = minimize(
+            &input,
+            &[Pass::Lines],
+            |candidate| candidate.lines().any(|l| l == "7") && candidate.lines().any(|l| l == "31"),
+            |_, _| {},
+        );
+        assert_eq!("7\n31\n", minimized);
+    }
+
+    #[test]
+    fn grid_and_moves() {
+        let input = "#####\n#.O.#\n#.@.#\n#...#\n#####\n\n<^^>vv\n<<>>^v\n";
+        let minimized = minimize(
+            input,
+            &passes(15),
+            |candidate| {
+                let (grid, moves) = candidate.split_once("\n\n").unwrap();
+                grid.contains('@') && grid.contains('O') && moves.contains("^v")
+            },
+            |_, _| {},
+        );
+        assert_eq!("O\n@\n\n^v\n", minimized);
+    }
+
+    #[test]
+    fn predicates() {
+        let day = Day {
+            number: 99,
+            input: Input::Inline(""),
+            parse: |input| match input.contains('?') {
+                true => Err("unexpected ?".to_owned()),
+                false => Ok(()),
+            },
+            part1: |input, budget| {
+                for _ in input.chars().filter(|&c| c == 'z') {
+                    budget.tick()?;
+                }
+                assert!(!input.contains('x'), "found an x");
+                Ok(input.len().to_string())
+            },
+            part2: None,
+        };
+        let limits = Limits {
+            time: None,
+            steps: Some(2),
+        };
+
+        assert!(Predicate::Panics.holds(&day, 1, "axb", limits));
+        assert!(!Predicate::Panics.holds(&day, 1, "ab", limits));
+        assert!(!Predicate::Panics.holds(&day, 1, "ax?", limits));
+        assert!(!Predicate::Panics.holds(&day, 2, "axb", limits));
+
+        assert!(Predicate::ExceedsBudget.holds(&day, 1, "zzz", limits));
+        assert!(!Predicate::ExceedsBudget.holds(&day, 1, "zz", limits));
+
+        let minimized = minimize(
+            "ab\nzz\ncz\nzd\n",
+            &[Pass::Lines, Pass::Characters { block: 0 }],
+            |candidate| Predicate::ExceedsBudget.holds(&day, 1, candidate, limits),
+            |_, _| {},
+        );
+        assert_eq!("zz\nz\n", minimized);
+    }
+}
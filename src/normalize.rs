@@ -0,0 +1,158 @@
+//! Cleans up inputs before any parser sees them.
+//!
+//! Inputs saved on Windows end lines with `\r\n`, editors add a byte order mark and
+//! browsers pad lines with spaces. Parsers are written against the input as Advent of
+//! Code serves it: `\n` line ends and no trailing newline.
+
+use std::{
+    borrow::Cow,
+    str::FromStr,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+const BOM: char = '\u{feff}';
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Whitespace {
+    /// Only line ends, the byte order mark and trailing newlines are touched.
+    Strict,
+    /// Also trims the end of every line, drops leading blank lines and collapses
+    /// runs of blank lines into one.
+    #[default]
+    Tolerant,
+}
+
+impl FromStr for Whitespace {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "strict" => Ok(Whitespace::Strict),
+            "tolerant" => Ok(Whitespace::Tolerant),
+            _ => Err(format!(
+                "Unknown whitespace handling {s:?}, expected strict or tolerant"
+            )),
+        }
+    }
+}
+
+static STRICT: AtomicBool = AtomicBool::new(false);
+
+/// Sets the whitespace handling [`normalize`] uses for the rest of the process.
+pub fn set_whitespace(whitespace: Whitespace) {
+    STRICT.store(whitespace == Whitespace::Strict, Ordering::Relaxed);
+}
+
+pub fn whitespace() -> Whitespace {
+    if STRICT.load(Ordering::Relaxed) {
+        Whitespace::Strict
+    } else {
+        Whitespace::Tolerant
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Normalize {
+    pub whitespace: Whitespace,
+}
+
+impl Normalize {
+    /// Borrows `input` when it is already clean, which is the common case.
+    pub fn apply<'a>(&self, input: &'a str) -> Cow<'a, str> {
+        if self.is_normalized(input) {
+            return Cow::Borrowed(input);
+        }
+
+        let input = input.strip_prefix(BOM).unwrap_or(input);
+        let mut normalized = String::with_capacity(input.len());
+        let mut blank_lines = 0;
+
+        for line in input.lines() {
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            match self.whitespace {
+                Whitespace::Strict => {
+                    normalized.push_str(line);
+                    normalized.push('\n');
+                }
+                Whitespace::Tolerant => {
+                    let line = line.trim_end();
+                    if line.is_empty() {
+                        blank_lines += 1;
+                        continue;
+                    }
+                    if blank_lines > 0 && !normalized.is_empty() {
+                        normalized.push('\n');
+                    }
+                    blank_lines = 0;
+                    normalized.push_str(line);
+                    normalized.push('\n');
+                }
+            }
+        }
+
+        normalized.truncate(normalized.trim_end_matches('\n').len());
+        Cow::Owned(normalized)
+    }
+
+    fn is_normalized(&self, input: &str) -> bool {
+        if input.starts_with(BOM) || input.contains('\r') || input.ends_with('\n') {
+            return false;
+        }
+
+        match self.whitespace {
+            Whitespace::Strict => true,
+            Whitespace::Tolerant => {
+                !input.starts_with('\n')
+                    && !input.contains("\n\n\n")
+                    && input
+                        .lines()
+                        .all(|line| line.len() == line.trim_end().len())
+            }
+        }
+    }
+}
+
+/// Normalizes with the process wide whitespace handling, tolerant unless
+/// [`set_whitespace`] says otherwise.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    Normalize {
+        whitespace: whitespace(),
+    }
+    .apply(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{normalize, Normalize, Whitespace};
+    use std::borrow::Cow;
+
+    #[test]
+    fn clean_input_is_borrowed() {
+        assert!(matches!(normalize("1   2\n3   4"), Cow::Borrowed(_)));
+        assert!(matches!(normalize("a\n\nb"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn line_ends_and_bom() {
+        assert_eq!("a\n\nb", normalize("\u{feff}a\r\n\r\nb\r\n"));
+        assert_eq!("125 17", normalize("125 17\n"));
+    }
+
+    #[test]
+    fn whitespace() {
+        let padded = "\n  \nab  \t\n   \n\n\ncd \n\n";
+        assert_eq!("ab\n\ncd", normalize(padded));
+
+        let strict = Normalize {
+            whitespace: Whitespace::Strict,
+        };
+        assert_eq!("\n  \nab  \t\n   \n\n\ncd ", strict.apply(padded));
+    }
+
+    #[test]
+    fn parse_whitespace() {
+        assert_eq!(Ok(Whitespace::Strict), "strict".parse());
+        assert_eq!(Ok(Whitespace::Tolerant), "tolerant".parse());
+        assert!("loose".parse::<Whitespace>().is_err());
+    }
+}
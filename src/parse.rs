@@ -0,0 +1,423 @@
+//! Parser combinators for the line formats the puzzles use, like `Button A: X+94, Y+34`.
+//!
+//! A parser is any `Fn(Input<'a>) -> Parsed<'a, T>`: it reads from the start of the input and
+//! returns the value along with the rest, or a [`ParseError`] pointing at where it got stuck.
+//! The functions here build parsers out of smaller ones; [`parse_all`] runs one over a whole
+//! input and [`find_all`] picks every match out of noise.
+
+use std::{any::type_name, fmt, str::FromStr};
+
+/// Byte offsets into the parsed input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Why a parser stopped. These are cheap to make since alternatives and scans fail all the
+/// time; [`parse_all`] turns the one that ends parsing into a [`ParseError`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure {
+    pub span: Span,
+    pub expected: Expected,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expected {
+    Literal(&'static str),
+    Unsigned,
+    Signed,
+    /// Digits too large for the named integer type.
+    Fits(&'static str),
+    EndOfInput,
+    Message(String),
+    Either(Box<Expected>, Box<Expected>),
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expected::Literal(literal) => write!(f, "{literal:?}"),
+            Expected::Unsigned => f.write_str("unsigned integer"),
+            Expected::Signed => f.write_str("integer"),
+            Expected::Fits(integer) => write!(f, "integer that fits in {integer}"),
+            Expected::EndOfInput => f.write_str("end of input"),
+            Expected::Message(message) => f.write_str(message),
+            Expected::Either(first, second) => write!(f, "{first} or {second}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub expected: String,
+    /// The rest of the line from where parsing stopped.
+    pub found: String,
+    /// 1-based, like an editor shows it.
+    pub line: usize,
+    /// 1-based, in characters.
+    pub column: usize,
+    pub span: Span,
+}
+
+impl ParseError {
+    fn new(source: &str, failure: Failure) -> Self {
+        let before = &source[..failure.span.start];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        ParseError {
+            expected: failure.expected.to_string(),
+            found: source[failure.span.start..]
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .to_owned(),
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            span: failure.span,
+        }
+    }
+}
+
+/// The input with a position in it, so failures can say where they happened.
+#[derive(Debug, Clone, Copy)]
+pub struct Input<'a> {
+    source: &'a str,
+    offset: usize,
+}
+
+pub type Parsed<'a, T> = Result<(T, Input<'a>), Failure>;
+
+impl<'a> Input<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Input { source, offset: 0 }
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.source[self.offset..]
+    }
+
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    fn advance(self, len: usize) -> Self {
+        Input {
+            offset: self.offset + len,
+            ..self
+        }
+    }
+
+    /// A failure for the `len` bytes at the current position.
+    pub fn fail(&self, len: usize, expected: Expected) -> Failure {
+        Failure {
+            span: Span {
+                start: self.offset,
+                end: self.offset + len,
+            },
+            expected,
+        }
+    }
+
+    /// A failure for the next character, or an empty span at the end of the input.
+    fn fail_here(&self, expected: Expected) -> Failure {
+        let len = self.rest().chars().next().map_or(0, char::len_utf8);
+        self.fail(len, expected)
+    }
+}
+
+/// Runs `parser` over all of `source`, allowing trailing whitespace.
+pub fn parse_all<'a, T>(
+    source: &'a str,
+    parser: impl Fn(Input<'a>) -> Parsed<'a, T>,
+) -> Result<T, ParseError> {
+    let (value, rest) = parser(Input::new(source)).map_err(|err| ParseError::new(source, err))?;
+    let trailing = rest.rest().len() - rest.rest().trim_start().len();
+    let rest = rest.advance(trailing);
+    match rest.rest().is_empty() {
+        true => Ok(value),
+        false => Err(ParseError::new(
+            source,
+            rest.fail_here(Expected::EndOfInput),
+        )),
+    }
+}
+
+/// Every match of `parser` in `source`, scanning one character at a time past text that
+/// doesn't match and continuing after each match.
+pub fn find_all<'a, T>(source: &'a str, parser: impl Fn(Input<'a>) -> Parsed<'a, T>) -> Vec<T> {
+    let mut found = Vec::new();
+    let mut input = Input::new(source);
+    while let Some(c) = input.rest().chars().next() {
+        match parser(input) {
+            Ok((value, rest)) if rest.offset > input.offset => {
+                found.push(value);
+                input = rest;
+            }
+            _ => input = input.advance(c.len_utf8()),
+        }
+    }
+    found
+}
+
+/// Exactly `literal`.
+pub fn tag<'a>(literal: &'static str) -> impl Fn(Input<'a>) -> Parsed<'a, &'a str> {
+    move |input| match input.rest().starts_with(literal) {
+        true => Ok((&input.rest()[..literal.len()], input.advance(literal.len()))),
+        false => Err(input.fail_here(Expected::Literal(literal))),
+    }
+}
+
+fn integer<'a, T: FromStr>(signed: bool) -> impl Fn(Input<'a>) -> Parsed<'a, T> {
+    move |input| {
+        let rest = input.rest();
+        let sign = usize::from(signed && rest.starts_with(['-', '+']));
+        let digits = rest[sign..].bytes().take_while(u8::is_ascii_digit).count();
+        if digits == 0 {
+            return Err(input.fail_here(match signed {
+                true => Expected::Signed,
+                false => Expected::Unsigned,
+            }));
+        }
+
+        let text = &rest[..sign + digits];
+        match text.parse() {
+            Ok(value) => Ok((value, input.advance(text.len()))),
+            Err(_) => Err(input.fail(text.len(), Expected::Fits(type_name::<T>()))),
+        }
+    }
+}
+
+/// Decimal digits, without a sign.
+pub fn unsigned<'a, T: FromStr>() -> impl Fn(Input<'a>) -> Parsed<'a, T> {
+    integer(false)
+}
+
+/// Decimal digits with an optional `-` or `+`.
+pub fn signed<'a, T: FromStr>() -> impl Fn(Input<'a>) -> Parsed<'a, T> {
+    integer(true)
+}
+
+pub fn map<'a, A, B>(
+    parser: impl Fn(Input<'a>) -> Parsed<'a, A>,
+    f: impl Fn(A) -> B,
+) -> impl Fn(Input<'a>) -> Parsed<'a, B> {
+    move |input| parser(input).map(|(value, rest)| (f(value), rest))
+}
+
+/// Like [`map`] for conversions that can fail; the error spans everything `parser` read.
+pub fn try_map<'a, A, B>(
+    parser: impl Fn(Input<'a>) -> Parsed<'a, A>,
+    f: impl Fn(A) -> Result<B, String>,
+) -> impl Fn(Input<'a>) -> Parsed<'a, B> {
+    move |input| {
+        let (value, rest) = parser(input)?;
+        match f(value) {
+            Ok(value) => Ok((value, rest)),
+            Err(message) => Err(input.fail(rest.offset - input.offset, Expected::Message(message))),
+        }
+    }
+}
+
+pub fn pair<'a, A, B>(
+    first: impl Fn(Input<'a>) -> Parsed<'a, A>,
+    second: impl Fn(Input<'a>) -> Parsed<'a, B>,
+) -> impl Fn(Input<'a>) -> Parsed<'a, (A, B)> {
+    move |input| {
+        let (a, input) = first(input)?;
+        let (b, input) = second(input)?;
+        Ok(((a, b), input))
+    }
+}
+
+/// `parser` after `prefix`, keeping only what `parser` read.
+pub fn preceded<'a, P, T>(
+    prefix: impl Fn(Input<'a>) -> Parsed<'a, P>,
+    parser: impl Fn(Input<'a>) -> Parsed<'a, T>,
+) -> impl Fn(Input<'a>) -> Parsed<'a, T> {
+    move |input| {
+        let (_, input) = prefix(input)?;
+        parser(input)
+    }
+}
+
+/// `parser` before `suffix`, keeping only what `parser` read.
+pub fn terminated<'a, T, S>(
+    parser: impl Fn(Input<'a>) -> Parsed<'a, T>,
+    suffix: impl Fn(Input<'a>) -> Parsed<'a, S>,
+) -> impl Fn(Input<'a>) -> Parsed<'a, T> {
+    move |input| {
+        let (value, input) = parser(input)?;
+        let (_, input) = suffix(input)?;
+        Ok((value, input))
+    }
+}
+
+/// `first`, or `second` where `first` fails. When both fail the error is the one that got
+/// further, or both expectations when they failed at the same place.
+pub fn alt<'a, T>(
+    first: impl Fn(Input<'a>) -> Parsed<'a, T>,
+    second: impl Fn(Input<'a>) -> Parsed<'a, T>,
+) -> impl Fn(Input<'a>) -> Parsed<'a, T> {
+    move |input| {
+        let first_err = match first(input) {
+            Ok(parsed) => return Ok(parsed),
+            Err(err) => err,
+        };
+        second(input).map_err(
+            |second_err| match first_err.span.start.cmp(&second_err.span.start) {
+                std::cmp::Ordering::Greater => first_err,
+                std::cmp::Ordering::Less => second_err,
+                std::cmp::Ordering::Equal => Failure {
+                    expected: Expected::Either(
+                        Box::new(first_err.expected),
+                        Box::new(second_err.expected),
+                    ),
+                    ..second_err
+                },
+            },
+        )
+    }
+}
+
+/// One or more `item`s with `separator` between them.
+pub fn separated<'a, T, S>(
+    item: impl Fn(Input<'a>) -> Parsed<'a, T>,
+    separator: impl Fn(Input<'a>) -> Parsed<'a, S>,
+) -> impl Fn(Input<'a>) -> Parsed<'a, Vec<T>> {
+    move |input| {
+        let (first, mut input) = item(input)?;
+        let mut items = vec![first];
+        while let Ok((_, next)) = separator(input) {
+            let (value, rest) = item(next)?;
+            items.push(value);
+            input = rest;
+        }
+        Ok((items, input))
+    }
+}
+
+/// `literal` when more than line breaks follow it, so a list of lines ends at a blank line
+/// or at trailing newlines instead of failing on them.
+fn line_break<'a>(literal: &'static str) -> impl Fn(Input<'a>) -> Parsed<'a, &'a str> {
+    move |input| {
+        let (text, rest) = tag(literal)(input)?;
+        match rest.rest().starts_with('\n') || rest.rest().is_empty() {
+            true => Err(input.fail_here(Expected::Literal(literal))),
+            false => Ok((text, rest)),
+        }
+    }
+}
+
+/// One `item` per line, up to a blank line or the end of the input.
+pub fn lines<'a, T>(
+    item: impl Fn(Input<'a>) -> Parsed<'a, T>,
+) -> impl Fn(Input<'a>) -> Parsed<'a, Vec<T>> {
+    separated(item, line_break("\n"))
+}
+
+/// Blocks of lines separated by a blank line, like day 13's machines.
+pub fn blocks<'a, T>(
+    block: impl Fn(Input<'a>) -> Parsed<'a, T>,
+) -> impl Fn(Input<'a>) -> Parsed<'a, Vec<T>> {
+    separated(block, line_break("\n\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        alt, blocks, find_all, lines, map, pair, parse_all, preceded, separated, signed, tag,
+        terminated, try_map, unsigned, Span,
+    };
+
+    #[test]
+    fn integers() {
+        assert_eq!(Ok(42u8), parse_all("42", unsigned()));
+        assert_eq!(Ok(-3i32), parse_all("-3", signed()));
+        assert_eq!(Ok(3i32), parse_all("+3", signed()));
+
+        let err = parse_all::<u32>("-3", unsigned()).unwrap_err();
+        assert_eq!("unsigned integer", err.expected);
+
+        let err = parse_all::<u8>("x=300", preceded(tag("x="), unsigned())).unwrap_err();
+        assert_eq!("integer that fits in u8", err.expected);
+        assert_eq!(Span { start: 2, end: 5 }, err.span);
+    }
+
+    #[test]
+    fn errors_point_at_the_line_and_column() {
+        let point = || pair(signed::<i32>(), preceded(tag(","), signed::<i32>()));
+        let err = parse_all("1,2\n3,4\n5;6\n", lines(point())).unwrap_err();
+
+        assert_eq!("\",\"", err.expected);
+        assert_eq!(";6", err.found);
+        assert_eq!((3, 2), (err.line, err.column));
+        assert_eq!(Span { start: 9, end: 10 }, err.span);
+    }
+
+    #[test]
+    fn lines_stop_at_a_blank_line() {
+        let register = preceded(tag("R="), unsigned::<u32>());
+        let program = preceded(tag("P: "), separated(unsigned::<u8>(), tag(",")));
+        let parser = pair(terminated(lines(register), tag("\n\n")), program);
+
+        assert_eq!(
+            Ok((vec![1, 2], vec![3, 4, 5])),
+            parse_all("R=1\nR=2\n\nP: 3,4,5\n", parser)
+        );
+    }
+
+    #[test]
+    fn blank_line_separated_blocks() {
+        let block = || lines(unsigned::<u32>());
+        assert_eq!(
+            Ok(vec![vec![1, 2], vec![3]]),
+            parse_all("1\n2\n\n3", blocks(block()))
+        );
+        assert_eq!(Ok(vec![1, 2]), parse_all("1\n2\n", block()));
+
+        let err = parse_all("1\n2\n\nx", blocks(block())).unwrap_err();
+        assert_eq!("unsigned integer", err.expected);
+        assert_eq!((4, 1), (err.line, err.column));
+    }
+
+    #[test]
+    fn alternatives() {
+        let switch = || alt(map(tag("on"), |_| true), map(tag("off"), |_| false));
+        assert_eq!(
+            Ok(vec![true, false]),
+            parse_all("on,off", separated(switch(), tag(",")))
+        );
+
+        let err = parse_all("of", switch()).unwrap_err();
+        assert_eq!("\"on\" or \"off\"", err.expected);
+    }
+
+    #[test]
+    fn conversions_report_the_whole_span() {
+        let digit = try_map(unsigned::<u8>(), |n| match n < 8 {
+            true => Ok(n),
+            false => Err("a digit below 8".to_owned()),
+        });
+        let err = parse_all("7,12", separated(digit, tag(","))).unwrap_err();
+
+        assert_eq!("a digit below 8", err.expected);
+        assert_eq!(Span { start: 2, end: 4 }, err.span);
+    }
+
+    #[test]
+    fn finds_matches_in_noise() {
+        let mul = || {
+            preceded(
+                tag("mul("),
+                pair(
+                    unsigned::<u32>(),
+                    preceded(tag(","), terminated(unsigned::<u32>(), tag(")"))),
+                ),
+            )
+        };
+        assert_eq!(
+            vec![(2, 4), (3, 4)],
+            find_all("xmul(2,4)%mul(2,mul(3,4)mul[1,1]", mul())
+        );
+    }
+}
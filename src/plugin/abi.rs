@@ -0,0 +1,190 @@
+//! The C ABI between the runner and a plugin.
+//!
+//! A plugin exports [`ENTRY`], a function returning a pointer to a static [`Solution`]. Only
+//! `#[repr(C)]` types and plain integers cross the boundary. Input goes in as a borrowed
+//! pointer and length; every [`Output`] the plugin returns is handed back to its
+//! [`Solution::free`], because the host must not free memory from another allocator.
+//!
+//! Bump [`ABI_VERSION`] on any change to these types, the runner refuses other versions.
+
+use crate::{
+    budget::{Budget, BudgetExceeded, Limit},
+    days::{Parse, Part, PartError},
+    runner,
+};
+use std::{
+    panic::{self, AssertUnwindSafe},
+    time::Duration,
+};
+
+pub const ABI_VERSION: u32 = 2;
+
+/// Name of the symbol every plugin exports, see [`crate::export_day`].
+pub const ENTRY: &str = "aoc_plugin_v1";
+
+/// [`Limits`] field value meaning "no limit".
+pub const NO_LIMIT: u64 = u64::MAX;
+
+/// [`Output::status`] values.
+pub mod status {
+    pub const OK: u32 = 0;
+    /// The parser rejected the input, or the solution panicked. The text says why.
+    pub const ERROR: u32 = 1;
+    pub const TIME_EXCEEDED: u32 = 2;
+    pub const STEPS_EXCEEDED: u32 = 3;
+    /// A part was given an input the day's parser rejects. The text is the parser's error.
+    pub const INVALID_INPUT: u32 = 4;
+}
+
+pub type Entry = unsafe extern "C" fn() -> *const Solution;
+pub type ParseFn = unsafe extern "C" fn(input: *const u8, len: usize) -> Output;
+pub type PartFn = unsafe extern "C" fn(input: *const u8, len: usize, limits: Limits) -> Output;
+
+#[repr(C)]
+pub struct Solution {
+    pub abi_version: u32,
+    pub day: u8,
+    pub parse: ParseFn,
+    pub part1: PartFn,
+    /// Null for days with only part 1 solved.
+    pub part2: Option<PartFn>,
+    pub free: unsafe extern "C" fn(output: Output),
+}
+
+/// The [`Budget`] a part gets, in nanoseconds and steps.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Limits {
+    pub time_ns: u64,
+    pub steps: u64,
+}
+
+impl Limits {
+    pub fn of(budget: &Budget) -> Limits {
+        Limits {
+            time_ns: budget.time_limit().map_or(NO_LIMIT, |time| {
+                time.as_nanos().min(NO_LIMIT as u128 - 1) as u64
+            }),
+            steps: budget.step_limit().unwrap_or(NO_LIMIT),
+        }
+    }
+
+    pub fn budget(self) -> Budget {
+        Budget::new(
+            (self.time_ns != NO_LIMIT).then(|| Duration::from_nanos(self.time_ns)),
+            (self.steps != NO_LIMIT).then_some(self.steps),
+        )
+    }
+}
+
+#[repr(C)]
+pub struct Output {
+    pub status: u32,
+    /// UTF-8: the answer, the error, or the progress made before the budget ran out.
+    pub text: *mut u8,
+    pub len: usize,
+    /// With an exceeded status, the limit that was reached, in nanoseconds or steps.
+    pub limit: u64,
+    pub steps: u64,
+    pub elapsed_ns: u64,
+}
+
+impl Output {
+    fn new(status: u32, text: String) -> Output {
+        let text = Box::into_raw(text.into_bytes().into_boxed_slice());
+        Output {
+            status,
+            text: text.cast(),
+            len: text.len(),
+            limit: 0,
+            steps: 0,
+            elapsed_ns: 0,
+        }
+    }
+
+    fn exceeded(exceeded: BudgetExceeded) -> Output {
+        let (status, limit) = match exceeded.limit {
+            Limit::Time(time) => (status::TIME_EXCEEDED, time.as_nanos() as u64),
+            Limit::Steps(steps) => (status::STEPS_EXCEEDED, steps),
+        };
+        Output {
+            limit,
+            steps: exceeded.steps,
+            elapsed_ns: exceeded.elapsed.as_nanos() as u64,
+            ..Output::new(status, exceeded.progress)
+        }
+    }
+
+    /// The text, copied out so the output can be freed.
+    ///
+    /// # Safety
+    ///
+    /// `text` and `len` must describe live memory, as they do in outputs from a plugin.
+    pub unsafe fn text(&self) -> String {
+        let bytes = unsafe { std::slice::from_raw_parts(self.text, self.len) };
+        String::from_utf8_lossy(bytes).into_owned()
+    }
+
+    /// Turns an exceeded status back into what the solution returned inside the plugin.
+    pub fn budget_exceeded(&self, progress: String) -> Option<BudgetExceeded> {
+        let limit = match self.status {
+            status::TIME_EXCEEDED => Limit::Time(Duration::from_nanos(self.limit)),
+            status::STEPS_EXCEEDED => Limit::Steps(self.limit),
+            _ => return None,
+        };
+        Some(BudgetExceeded {
+            limit,
+            steps: self.steps,
+            elapsed: Duration::from_nanos(self.elapsed_ns),
+            progress,
+        })
+    }
+}
+
+// The plugin side, called from the functions `export_day!` generates.
+
+/// # Safety
+///
+/// `input` and `len` must describe a live UTF-8 string.
+#[doc(hidden)]
+pub unsafe fn export_parse(parse: Parse, input: *const u8, len: usize) -> Output {
+    let input = unsafe { input_str(input, len) };
+    match panic::catch_unwind(|| parse(input)) {
+        Ok(Ok(())) => Output::new(status::OK, String::new()),
+        Ok(Err(err)) => Output::new(status::ERROR, err),
+        Err(payload) => Output::new(status::ERROR, panic_message(payload)),
+    }
+}
+
+/// # Safety
+///
+/// `input` and `len` must describe a live UTF-8 string.
+#[doc(hidden)]
+pub unsafe fn export_part(part: Part, input: *const u8, len: usize, limits: Limits) -> Output {
+    let input = unsafe { input_str(input, len) };
+    let budget = limits.budget();
+    match panic::catch_unwind(AssertUnwindSafe(|| part(input, &budget))) {
+        Ok(Ok(answer)) => Output::new(status::OK, answer),
+        Ok(Err(PartError::InvalidInput(err))) => Output::new(status::INVALID_INPUT, err),
+        Ok(Err(PartError::BudgetExceeded(exceeded))) => Output::exceeded(exceeded),
+        Err(payload) => Output::new(status::ERROR, panic_message(payload)),
+    }
+}
+
+/// # Safety
+///
+/// `output` must come from [`export_parse`] or [`export_part`] and not have been freed.
+#[doc(hidden)]
+pub unsafe extern "C" fn export_free(output: Output) {
+    let text = std::ptr::slice_from_raw_parts_mut(output.text, output.len);
+    drop(unsafe { Box::from_raw(text) });
+}
+
+unsafe fn input_str<'a>(input: *const u8, len: usize) -> &'a str {
+    // The host only ever passes `&str`s; checking again would cost a pass over every input.
+    unsafe { std::str::from_utf8_unchecked(std::slice::from_raw_parts(input, len)) }
+}
+
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    format!("panicked: {}", runner::panic_message(&*payload))
+}
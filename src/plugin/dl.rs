@@ -0,0 +1,47 @@
+//! Just enough of `dlopen(3)` to find a plugin's entry point. Libraries are never closed: the
+//! days they back hold plain function pointers into them for the rest of the process.
+
+use std::{
+    ffi::{c_char, c_int, c_void, CStr, CString},
+    os::unix::ffi::OsStrExt,
+    path::Path,
+};
+
+const RTLD_NOW: c_int = 2;
+
+extern "C" {
+    fn dlopen(filename: *const c_char, flags: c_int) -> *mut c_void;
+    fn dlsym(handle: *mut c_void, symbol: *const c_char) -> *mut c_void;
+    fn dlerror() -> *mut c_char;
+}
+
+/// Loads the library at `path` and looks up `symbol` in it.
+///
+/// # Safety
+///
+/// Loading runs the library's initializers, so `path` must be a library that is safe to load.
+pub unsafe fn symbol(path: &Path, symbol: &str) -> Result<*mut c_void, String> {
+    let path = CString::new(path.as_os_str().as_bytes()).map_err(|err| err.to_string())?;
+    let symbol = CString::new(symbol).map_err(|err| err.to_string())?;
+
+    let handle = unsafe { dlopen(path.as_ptr(), RTLD_NOW) };
+    if handle.is_null() {
+        return Err(unsafe { last_error() });
+    }
+
+    let address = unsafe { dlsym(handle, symbol.as_ptr()) };
+    match address.is_null() {
+        true => Err(unsafe { last_error() }),
+        false => Ok(address),
+    }
+}
+
+unsafe fn last_error() -> String {
+    let message = unsafe { dlerror() };
+    match message.is_null() {
+        true => "unknown dlopen error".to_owned(),
+        false => unsafe { CStr::from_ptr(message) }
+            .to_string_lossy()
+            .into_owned(),
+    }
+}
@@ -0,0 +1,380 @@
+//! Day solutions loaded at runtime from shared libraries, so an experimental solution can be
+//! tried against the runner without rebuilding it.
+//!
+//! A plugin is a `cdylib` crate depending on this one that hands a [`Day`] to
+//! [`export_day!`](crate::export_day); `plugins/example` is one. [`load_dir`] turns every
+//! library in a directory back into a `&'static Day`, which goes through the runner, batch
+//! runs and fixture checks like the built-in days.
+//!
+//! `Day` holds plain function pointers, so each loaded plugin takes one of [`MAX_PLUGINS`]
+//! slots, each with its own set of functions forwarding to the plugin in that slot.
+
+pub mod abi;
+#[cfg(unix)]
+mod dl;
+
+use crate::{
+    budget::Budget,
+    days::{self, Day, Input, Parse, Part, PartError},
+};
+use std::{
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        OnceLock,
+    },
+};
+
+/// How many plugins one process can load.
+pub const MAX_PLUGINS: usize = 8;
+
+/// Exports `$day`, a [`Day`](crate::days::Day), from a `cdylib` through the plugin ABI.
+///
+/// ```ignore
+/// advent_of_code::export_day!(Day {
+///     number: 1,
+///     input: Input::File("./files/01/lists.txt"),
+///     parse: |input| ...,
+///     part1: |input, budget| ...,
+///     part2: None,
+/// });
+/// ```
+#[macro_export]
+macro_rules! export_day {
+    ($day:expr) => {
+        #[no_mangle]
+        pub extern "C" fn aoc_plugin_v1() -> *const $crate::plugin::abi::Solution {
+            use $crate::plugin::abi;
+
+            const DAY: $crate::days::Day = $day;
+
+            unsafe extern "C" fn parse(input: *const u8, len: usize) -> abi::Output {
+                unsafe { abi::export_parse(DAY.parse, input, len) }
+            }
+
+            unsafe extern "C" fn part1(
+                input: *const u8,
+                len: usize,
+                limits: abi::Limits,
+            ) -> abi::Output {
+                unsafe { abi::export_part(DAY.part1, input, len, limits) }
+            }
+
+            unsafe extern "C" fn part2(
+                input: *const u8,
+                len: usize,
+                limits: abi::Limits,
+            ) -> abi::Output {
+                match DAY.part2 {
+                    Some(part) => unsafe { abi::export_part(part, input, len, limits) },
+                    None => unreachable!("the host only calls part 2 when it is exported"),
+                }
+            }
+
+            static SOLUTION: abi::Solution = abi::Solution {
+                abi_version: abi::ABI_VERSION,
+                day: DAY.number,
+                parse,
+                part1,
+                part2: match DAY.part2 {
+                    Some(_) => Some(part2),
+                    None => None,
+                },
+                free: abi::export_free,
+            };
+
+            &SOLUTION
+        }
+    };
+}
+
+/// A loaded plugin. The library stays loaded until the process exits.
+pub struct Plugin {
+    pub path: PathBuf,
+    solution: &'static abi::Solution,
+}
+
+impl Plugin {
+    /// Loads the library at `path` and checks its ABI version.
+    ///
+    /// # Safety
+    ///
+    /// The library must be a plugin built by [`export_day!`](crate::export_day), or at least
+    /// export its entry point with the same signature and behavior.
+    #[cfg(unix)]
+    pub unsafe fn load(path: &Path) -> Result<Plugin, String> {
+        let entry = unsafe { dl::symbol(path, abi::ENTRY) }
+            .map_err(|err| format!("Could not load plugin {}: {err}", path.display()))?;
+        let entry: abi::Entry = unsafe { std::mem::transmute(entry) };
+        unsafe { Plugin::from_entry(path, entry) }
+    }
+
+    #[cfg(not(unix))]
+    pub unsafe fn load(path: &Path) -> Result<Plugin, String> {
+        Err(format!(
+            "Could not load plugin {}: plugins are only supported on unix",
+            path.display()
+        ))
+    }
+
+    /// Wraps a plugin whose entry point is already at hand.
+    ///
+    /// # Safety
+    ///
+    /// `entry` must return a pointer to a [`abi::Solution`] that lives for the whole process.
+    pub unsafe fn from_entry(path: &Path, entry: abi::Entry) -> Result<Plugin, String> {
+        let solution = unsafe { entry().as_ref() }
+            .ok_or_else(|| format!("Plugin {} has no solution", path.display()))?;
+        if solution.abi_version != abi::ABI_VERSION {
+            return Err(format!(
+                "Plugin {} uses ABI version {}, this runner uses {}",
+                path.display(),
+                solution.abi_version,
+                abi::ABI_VERSION
+            ));
+        }
+
+        Ok(Plugin {
+            path: path.to_owned(),
+            solution,
+        })
+    }
+
+    pub fn day(&self) -> u8 {
+        self.solution.day
+    }
+
+    pub fn parse(&self, input: &str) -> Result<(), String> {
+        let output = unsafe { (self.solution.parse)(input.as_ptr(), input.len()) };
+        let (status, text) = self.take(output);
+        match status {
+            abi::status::OK => Ok(()),
+            _ => Err(text),
+        }
+    }
+
+    /// Runs part `n` like a built-in [`Part`]: a solution that failed inside the plugin
+    /// panics here with the plugin's message.
+    pub fn part(&self, n: u8, input: &str, budget: &Budget) -> Result<String, PartError> {
+        let part = match n {
+            1 => self.solution.part1,
+            _ => self
+                .solution
+                .part2
+                .unwrap_or_else(|| panic!("{} has no part {n}", self.path.display())),
+        };
+
+        let limits = abi::Limits::of(budget);
+        let output = unsafe { part(input.as_ptr(), input.len(), limits) };
+        let exceeded = output.budget_exceeded(String::new());
+        let (status, text) = self.take(output);
+        match (status, exceeded) {
+            (abi::status::OK, _) => Ok(text),
+            (abi::status::INVALID_INPUT, _) => Err(PartError::InvalidInput(text)),
+            (_, Some(exceeded)) => Err(exceeded.with_progress(text).into()),
+            _ => panic!("{}: {text}", self.path.display()),
+        }
+    }
+
+    /// Copies the text out of `output` and frees it.
+    fn take(&self, output: abi::Output) -> (u32, String) {
+        let status = output.status;
+        let text = unsafe { output.text() };
+        unsafe { (self.solution.free)(output) };
+        (status, text)
+    }
+
+    fn has_part2(&self) -> bool {
+        self.solution.part2.is_some()
+    }
+}
+
+// Safety: a plugin is a static table of functions, which the ABI requires to be callable from
+// any thread.
+unsafe impl Send for Plugin {}
+unsafe impl Sync for Plugin {}
+
+static LOADED: [OnceLock<Plugin>; MAX_PLUGINS] = [const { OnceLock::new() }; MAX_PLUGINS];
+static NEXT_SLOT: AtomicUsize = AtomicUsize::new(0);
+
+fn loaded(slot: usize) -> &'static Plugin {
+    LOADED[slot]
+        .get()
+        .expect("trampolines are only handed out for filled slots")
+}
+
+fn parse_slot<const SLOT: usize>(input: &str) -> Result<(), String> {
+    loaded(SLOT).parse(input)
+}
+
+fn part_slot<const SLOT: usize, const PART: u8>(
+    input: &str,
+    budget: &Budget,
+) -> Result<String, PartError> {
+    loaded(SLOT).part(PART, input, budget)
+}
+
+const fn slot<const SLOT: usize>() -> (Parse, Part, Part) {
+    (
+        parse_slot::<SLOT>,
+        part_slot::<SLOT, 1>,
+        part_slot::<SLOT, 2>,
+    )
+}
+
+const SLOTS: [(Parse, Part, Part); MAX_PLUGINS] = [
+    slot::<0>(),
+    slot::<1>(),
+    slot::<2>(),
+    slot::<3>(),
+    slot::<4>(),
+    slot::<5>(),
+    slot::<6>(),
+    slot::<7>(),
+];
+
+/// Registers `plugin` for the rest of the process and returns the day it solves.
+///
+/// The day reads the same input as the built-in day with its number.
+pub fn register(plugin: Plugin) -> Result<&'static Day, String> {
+    let slot = NEXT_SLOT.fetch_add(1, Ordering::Relaxed);
+    if slot >= MAX_PLUGINS {
+        return Err(format!(
+            "Could not register {}: at most {MAX_PLUGINS} plugins can be loaded",
+            plugin.path.display()
+        ));
+    }
+
+    let number = plugin.day();
+    let has_part2 = plugin.has_part2();
+    let input = match days::get(number) {
+        Some(day) => day.input,
+        None => Input::File(String::leak(format!("./files/{number:02}.txt"))),
+    };
+    if LOADED[slot].set(plugin).is_err() {
+        unreachable!("slot {slot} was handed out twice");
+    }
+
+    let (parse, part1, part2) = SLOTS[slot];
+    Ok(Box::leak(Box::new(Day {
+        number,
+        input,
+        parse,
+        part1,
+        part2: has_part2.then_some(part2),
+    })))
+}
+
+/// Loads and registers every shared library in `dir`, in file name order.
+///
+/// # Safety
+///
+/// See [`Plugin::load`]; every library in `dir` must be a plugin.
+pub unsafe fn load_dir(dir: &Path) -> Result<Vec<(PathBuf, &'static Day)>, String> {
+    let entries = std::fs::read_dir(dir)
+        .map_err(|err| format!("Could not read plugin directory {}: {err}", dir.display()))?;
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == std::env::consts::DLL_EXTENSION)
+        })
+        .collect();
+    paths.sort();
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let plugin = unsafe { Plugin::load(&path) }?;
+            Ok((path, register(plugin)?))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{abi, register, Plugin};
+    use crate::{
+        budget::{Budget, Limit},
+        days::{self, Day, Input, PartError},
+        runner::{self, Limits, Outcome},
+    };
+    use std::path::Path;
+
+    crate::export_day!(Day {
+        number: 17,
+        input: Input::Inline("Register A: 1"),
+        parse: |input| match input.starts_with("Register") {
+            true => Ok(()),
+            false => Err(format!("not a computer: {input}")),
+        },
+        part1: |input, budget| {
+            if input.is_empty() {
+                return Err(PartError::InvalidInput("no registers".to_owned()));
+            }
+            for _ in 0..input.len() {
+                budget.tick()?;
+            }
+            Ok(input.len().to_string())
+        },
+        part2: Some(|_, _| panic!("not solved yet")),
+    });
+
+    fn plugin() -> Plugin {
+        unsafe { Plugin::from_entry(Path::new("test.so"), aoc_plugin_v1) }.unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        let plugin = plugin();
+        assert_eq!(17, plugin.day());
+        assert_eq!(Ok(()), plugin.parse("Register A: 7"));
+        assert_eq!(Err("not a computer: 1,2".to_owned()), plugin.parse("1,2"));
+        assert_eq!(
+            Ok("5".to_owned()),
+            plugin.part(1, "hello", &Budget::unlimited())
+        );
+        assert_eq!(
+            Err(PartError::InvalidInput("no registers".to_owned())),
+            plugin.part(1, "", &Budget::unlimited())
+        );
+    }
+
+    #[test]
+    fn budget_and_panics_cross_the_boundary() {
+        let plugin = plugin();
+        let Err(PartError::BudgetExceeded(exceeded)) =
+            plugin.part(1, "hello", &Budget::new(None, Some(2)))
+        else {
+            panic!("the step limit should stop the plugin");
+        };
+        assert_eq!(Limit::Steps(2), exceeded.limit);
+        assert_eq!(3, exceeded.steps);
+
+        let panic = std::panic::catch_unwind(|| plugin.part(2, "hello", &Budget::unlimited()));
+        let message = panic.unwrap_err().downcast::<String>().unwrap();
+        assert_eq!("test.so: panicked: not solved yet", *message);
+    }
+
+    #[test]
+    fn registered_days_run_like_built_in_ones() {
+        let day = register(plugin()).unwrap();
+        assert_eq!(
+            days::get(17).unwrap().load_input().ok(),
+            day.load_input().ok()
+        );
+        assert!(day.part2.is_some());
+
+        let result = runner::run_part(day.part1, "abc", Limits::default());
+        assert_eq!(Outcome::Answer("3".to_owned()), result.outcome);
+    }
+
+    #[test]
+    fn limits() {
+        let budget = Budget::new(Some(std::time::Duration::from_millis(3)), None);
+        let limits = abi::Limits::of(&budget);
+        assert_eq!(3_000_000, limits.time_ns);
+        assert_eq!(abi::NO_LIMIT, limits.steps);
+        assert_eq!(None, limits.budget().step_limit());
+    }
+}
@@ -0,0 +1,149 @@
+//! Example inputs and their answers, pulled out of a puzzle page.
+
+use super::{
+    html::{self, Token},
+    markdown::{articles, emphasized_code},
+};
+use crate::fixtures::Answers;
+use std::path::Path;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example {
+    pub input: String,
+    pub answers: Answers,
+}
+
+/// Every `<pre><code>` block that an answer could be matched to.
+///
+/// Each part's answer is taken to be the last `<code><em>…</em></code>` of its article, worked
+/// out from the last code block before it. Part two usually reuses the example of part one, so
+/// its answer lands on that block too. These are guesses to review, not facts.
+pub fn examples(page: &str) -> Vec<Example> {
+    let tokens = html::tokenize(page);
+    let mut examples: Vec<Example> = Vec::new();
+
+    for (part, article) in articles(&tokens).into_iter().enumerate().take(2) {
+        let mut answer = None;
+        let mut answer_example = None;
+        let mut i = 0;
+        while i < article.len() {
+            if let Some(text) = emphasized_code(article, i) {
+                answer = Some(html::unescape(text));
+                answer_example = examples.len().checked_sub(1);
+                i += 5;
+                continue;
+            }
+            if let Some((input, len)) = code_block(&article[i..]) {
+                examples.push(Example {
+                    input,
+                    answers: Answers::default(),
+                });
+                i += len;
+                continue;
+            }
+            i += 1;
+        }
+
+        if let (Some(answer), Some(example)) = (answer, answer_example) {
+            let answers = &mut examples[example].answers;
+            match part {
+                0 => answers.part1 = Some(answer),
+                _ => answers.part2 = Some(answer),
+            }
+        }
+    }
+
+    examples.retain(|example| example.answers != Answers::default());
+    examples
+}
+
+/// The text of a `<pre><code>` block at the start of `tokens`, and how many tokens it spans.
+fn code_block(tokens: &[Token]) -> Option<(String, usize)> {
+    let [Token::Open { name: pre, .. }, Token::Open { name: code, .. }, ..] = tokens else {
+        return None;
+    };
+    if pre != "pre" || code != "code" {
+        return None;
+    }
+
+    let mut text = String::new();
+    for (i, token) in tokens.iter().enumerate().skip(2) {
+        match token {
+            Token::Text(raw) => text.push_str(&html::unescape(raw)),
+            Token::Close { name } if name == "pre" => {
+                return Some((text.trim_end_matches('\n').to_owned(), i + 1));
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Writes examples to `dir` as `example-N.txt` with `example-N.answers` sidecars, skipping
+/// those whose input is already in `existing`. Returns the paths of the new inputs.
+pub fn write(dir: &Path, examples: &[Example], existing: &[String]) -> Result<Vec<String>, String> {
+    let mut written = Vec::new();
+    let fresh = examples.iter().filter(|example| {
+        !existing
+            .iter()
+            .any(|input| input.trim_end() == example.input)
+    });
+
+    for (n, example) in fresh.enumerate() {
+        if written.is_empty() {
+            std::fs::create_dir_all(dir)
+                .map_err(|err| format!("Could not create {}: {err}", dir.display()))?;
+        }
+        let path = dir.join(format!("example-{}.txt", n + 1));
+        let mut answers = String::new();
+        for (part, answer) in [(1, &example.answers.part1), (2, &example.answers.part2)] {
+            if let Some(answer) = answer {
+                answers.push_str(&format!("part{part}: {answer}\n"));
+            }
+        }
+
+        std::fs::write(&path, format!("{}\n", example.input))
+            .and_then(|()| std::fs::write(path.with_extension("answers"), answers))
+            .map_err(|err| format!("Could not write {}: {err}", path.display()))?;
+        written.push(path.display().to_string());
+    }
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{examples, write, Example};
+    use crate::{fixtures::Answers, puzzle::tests::PAGE};
+
+    fn example() -> Example {
+        Example {
+            input: "3   4\n4   3".to_owned(),
+            answers: Answers {
+                part1: Some("11".to_owned()),
+                part2: Some("31".to_owned()),
+            },
+        }
+    }
+
+    #[test]
+    fn answers_go_to_the_last_block() {
+        assert_eq!(vec![example()], examples(PAGE));
+    }
+
+    #[test]
+    fn writes_new_examples_only() {
+        let dir = std::env::temp_dir().join(format!("aoc-extract-{}", std::process::id()));
+
+        let written = write(&dir, &[example()], &["3   4\n4   3\n".to_owned()]).unwrap();
+        assert!(written.is_empty());
+        assert!(!dir.exists());
+
+        let written = write(&dir, &[example()], &[]).unwrap();
+        let answers = std::fs::read_to_string(dir.join("example-1.answers")).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(1, written.len());
+        assert_eq!("part1: 11\npart2: 31\n", answers);
+    }
+}
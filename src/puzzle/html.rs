@@ -0,0 +1,139 @@
+//! Just enough HTML for puzzle pages: tags, text and character references.
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token<'a> {
+    Open {
+        name: String,
+        attrs: &'a str,
+    },
+    Close {
+        name: String,
+    },
+    /// Raw text, still escaped.
+    Text(&'a str),
+}
+
+/// Splits `html` into tags and text. Comments and doctypes are dropped.
+pub fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+        rest = &rest[start..];
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+        let Some(end) = rest.find('>') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+        let tag = &rest[1..end];
+        rest = &rest[end + 1..];
+
+        if tag.starts_with('!') || tag.starts_with('?') {
+            continue;
+        }
+        let (closing, tag) = match tag.strip_prefix('/') {
+            Some(tag) => (true, tag),
+            None => (false, tag.trim_end_matches('/')),
+        };
+        let (name, attrs) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+        let name = name.to_ascii_lowercase();
+        tokens.push(match closing {
+            true => Token::Close { name },
+            false => Token::Open {
+                name,
+                attrs: attrs.trim(),
+            },
+        });
+    }
+
+    tokens
+}
+
+/// Replaces character references like `&lt;` and `&#39;` with the characters they stand for.
+pub fn unescape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(i) = rest.find('&') {
+        out.push_str(&rest[..i]);
+        rest = &rest[i..];
+        let decoded = rest.find(';').filter(|end| *end <= 10).and_then(|end| {
+            let c = match &rest[1..end] {
+                "lt" => '<',
+                "gt" => '>',
+                "amp" => '&',
+                "quot" => '"',
+                "apos" => '\'',
+                "nbsp" => ' ',
+                reference => {
+                    let number = reference.strip_prefix('#')?;
+                    let code = match number.strip_prefix(['x', 'X']) {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => number.parse().ok()?,
+                    };
+                    char::from_u32(code)?
+                }
+            };
+            Some((c, end + 1))
+        });
+        match decoded {
+            Some((c, len)) => {
+                out.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{tokenize, unescape, Token};
+
+    #[test]
+    fn tokens() {
+        assert_eq!(
+            vec![
+                Token::Open {
+                    name: "p".to_owned(),
+                    attrs: ""
+                },
+                Token::Text("a &lt; b"),
+                Token::Open {
+                    name: "br".to_owned(),
+                    attrs: ""
+                },
+                Token::Close {
+                    name: "p".to_owned()
+                },
+            ],
+            tokenize("<!DOCTYPE html><!-- hi --><P>a &lt; b<br/></p>")
+        );
+    }
+
+    #[test]
+    fn references() {
+        assert_eq!(
+            "3 < 4 & 'x' -> \"y\" é",
+            unescape("3 &lt; 4 &amp; &#39;x&#39; -&gt; &quot;y&quot; &#xe9;")
+        );
+        assert_eq!("AT&T; R&D", unescape("AT&T; R&D"));
+    }
+}
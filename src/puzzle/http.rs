@@ -0,0 +1,111 @@
+//! Fetching a page. Plain `http://` is spoken directly; std has no TLS, so `https://` goes
+//! through `curl`.
+
+use std::{
+    io::{Read, Write},
+    net::TcpStream,
+    process::Command,
+    time::Duration,
+};
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The body of a `200 OK` response to `GET url`, sending `cookie` when there is one.
+pub fn get(url: &str, cookie: Option<&str>) -> Result<String, String> {
+    match url.split_once("://") {
+        Some(("http", rest)) => get_plain(rest, cookie),
+        Some(("https", _)) => get_with_curl(url, cookie),
+        _ => Err(format!(
+            "Unsupported URL {url}, expected http:// or https://"
+        )),
+    }
+}
+
+fn get_plain(url: &str, cookie: Option<&str>) -> Result<String, String> {
+    let (host, path) = match url.find('/') {
+        Some(i) => (&url[..i], &url[i..]),
+        None => (url, "/"),
+    };
+    let address = match host.contains(':') {
+        true => host.to_owned(),
+        false => format!("{host}:80"),
+    };
+
+    let io = |err: std::io::Error| format!("Could not fetch http://{url}: {err}");
+    let mut stream = TcpStream::connect(&address).map_err(io)?;
+    stream.set_read_timeout(Some(TIMEOUT)).map_err(io)?;
+
+    let mut request = format!("GET {path} HTTP/1.1\r\nHost: {host}\r\nConnection: close\r\n");
+    if let Some(cookie) = cookie {
+        request.push_str(&format!("Cookie: {cookie}\r\n"));
+    }
+    request.push_str("\r\n");
+    stream.write_all(request.as_bytes()).map_err(io)?;
+
+    let mut response = Vec::new();
+    stream.read_to_end(&mut response).map_err(io)?;
+    let response = String::from_utf8_lossy(&response);
+
+    let (head, body) = response
+        .split_once("\r\n\r\n")
+        .ok_or_else(|| format!("Malformed response from http://{url}"))?;
+    let status = head.lines().next().unwrap_or_default();
+    if status.split_whitespace().nth(1) != Some("200") {
+        return Err(format!("http://{url} answered {status}"));
+    }
+    if head
+        .lines()
+        .any(|line| line.eq_ignore_ascii_case("transfer-encoding: chunked"))
+    {
+        return unchunk(body).ok_or_else(|| format!("Malformed chunked body from http://{url}"));
+    }
+    Ok(body.to_owned())
+}
+
+fn unchunk(mut body: &str) -> Option<String> {
+    let mut out = String::new();
+    loop {
+        let (size, rest) = body.split_once("\r\n")?;
+        let size = usize::from_str_radix(size.split(';').next()?.trim(), 16).ok()?;
+        if size == 0 {
+            return Some(out);
+        }
+        out.push_str(rest.get(..size)?);
+        body = rest.get(size..)?.strip_prefix("\r\n")?;
+    }
+}
+
+fn get_with_curl(url: &str, cookie: Option<&str>) -> Result<String, String> {
+    let mut command = Command::new("curl");
+    command
+        .args(["--silent", "--show-error", "--fail", "--location"])
+        .args(["--max-time", &TIMEOUT.as_secs().to_string()]);
+    if let Some(cookie) = cookie {
+        command.args(["--cookie", cookie]);
+    }
+
+    let output = command
+        .arg(url)
+        .output()
+        .map_err(|err| format!("Could not run curl to fetch {url}: {err}"))?;
+    if !output.status.success() {
+        return Err(format!(
+            "Could not fetch {url}: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    String::from_utf8(output.stdout).map_err(|_| format!("{url} is not UTF-8"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::unchunk;
+
+    #[test]
+    fn chunked() {
+        assert_eq!(
+            Some("Wikipedia in\r\nchunks.".to_owned()),
+            unchunk("4\r\nWiki\r\n6\r\npedia \r\nB\r\nin\r\nchunks.\r\n0\r\n\r\n")
+        );
+    }
+}
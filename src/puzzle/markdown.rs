@@ -0,0 +1,150 @@
+//! Puzzle descriptions as Markdown that reads well in a terminal.
+
+use super::html::{self, Token};
+
+/// The `<article>`s of a puzzle page, one per part, or the whole page when there are none.
+pub fn articles<'t, 'a>(tokens: &'t [Token<'a>]) -> Vec<&'t [Token<'a>]> {
+    let mut articles = Vec::new();
+    let mut start = None;
+    for (i, token) in tokens.iter().enumerate() {
+        match token {
+            Token::Open { name, .. } if name == "article" => start = Some(i + 1),
+            Token::Close { name } if name == "article" => {
+                if let Some(start) = start.take() {
+                    articles.push(&tokens[start..i]);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if articles.is_empty() {
+        articles.push(tokens);
+    }
+    articles
+}
+
+/// `<code><em>143</em></code>` at `tokens[i]`, the way puzzles mark answers.
+pub fn emphasized_code<'a>(tokens: &[Token<'a>], i: usize) -> Option<&'a str> {
+    match tokens.get(i..i + 5)? {
+        [Token::Open { name: code, .. }, Token::Open { name: em, .. }, Token::Text(text), Token::Close { name: em_end }, Token::Close { name: code_end }]
+            if code == "code" && code_end == "code" && em == "em" && em_end == "em" =>
+        {
+            Some(text)
+        }
+        _ => None,
+    }
+}
+
+/// Renders every article: headings, paragraphs, lists, `inline code`, *emphasis*, **`answers`**
+/// and fenced code blocks. Links keep only their text.
+pub fn to_markdown(page: &str) -> String {
+    let tokens = html::tokenize(page);
+    let mut out = String::new();
+
+    for article in articles(&tokens) {
+        let mut pre = false;
+        let mut i = 0;
+        while i < article.len() {
+            if !pre {
+                if let Some(answer) = emphasized_code(article, i) {
+                    out.push_str(&format!("**`{}`**", html::unescape(answer)));
+                    i += 5;
+                    continue;
+                }
+            }
+
+            match &article[i] {
+                Token::Text(text) if pre => out.push_str(&html::unescape(text)),
+                Token::Text(text) => {
+                    let text = html::unescape(text);
+                    let mut words = text.split_whitespace().peekable();
+                    if text.starts_with(char::is_whitespace) && !at_line_start(&out) {
+                        out.push(' ');
+                    }
+                    while let Some(word) = words.next() {
+                        out.push_str(word);
+                        if words.peek().is_some() || text.ends_with(char::is_whitespace) {
+                            out.push(' ');
+                        }
+                    }
+                }
+                Token::Open { name, .. } => match name.as_str() {
+                    "h2" => out.push_str("## "),
+                    "pre" => {
+                        pre = true;
+                        out.push_str("```\n");
+                    }
+                    "code" if !pre => out.push('`'),
+                    "em" if !pre => out.push('*'),
+                    "li" => out.push_str("- "),
+                    "br" => out.push('\n'),
+                    _ => {}
+                },
+                Token::Close { name } => match name.as_str() {
+                    "h2" | "p" => {
+                        trim_end_spaces(&mut out);
+                        out.push_str("\n\n");
+                    }
+                    "pre" => {
+                        pre = false;
+                        if !out.ends_with('\n') {
+                            out.push('\n');
+                        }
+                        out.push_str("```\n\n");
+                    }
+                    "code" if !pre => out.push('`'),
+                    "em" if !pre => out.push('*'),
+                    "li" => {
+                        trim_end_spaces(&mut out);
+                        out.push('\n');
+                    }
+                    "ul" | "ol" => out.push('\n'),
+                    _ => {}
+                },
+            }
+            i += 1;
+        }
+    }
+
+    out.trim_end().to_owned() + "\n"
+}
+
+fn at_line_start(out: &str) -> bool {
+    out.is_empty() || out.ends_with('\n')
+}
+
+fn trim_end_spaces(out: &mut String) {
+    while out.ends_with(' ') {
+        out.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::to_markdown;
+    use crate::puzzle::tests::PAGE;
+
+    #[test]
+    fn markdown() {
+        let expected = "## --- Day 1: Shelf Sorting ---
+
+The elves keep *two* lists of shelf numbers:
+
+```
+3   4
+4   3
+```
+
+- Pair them up.
+- Sum the `distances`.
+
+Here, the total is **`11`**.
+
+## --- Part Two ---
+
+Now multiply instead, for **`31`**.
+";
+        assert_eq!(expected, to_markdown(PAGE));
+    }
+}
@@ -0,0 +1,174 @@
+//! Puzzle descriptions, fetched once and cached as `puzzles/NN.html`.
+//!
+//! Pages come from `AOC_URL` (default `https://adventofcode.com`) at `/2024/day/N`. Part two
+//! is only on the page once part one is solved, and only for the logged in user, so the
+//! `AOC_SESSION` cookie is sent when set. A `file://` base URL reads pages from a directory
+//! with the same layout, which makes a local mock easy.
+//!
+//! Pages are converted to Markdown for reading, and their examples and emphasized answers
+//! are written to `fixtures/NN/candidates/` to review and promote into fixtures.
+
+use crate::fixtures;
+use std::path::{Path, PathBuf};
+
+pub mod extract;
+pub mod html;
+pub mod http;
+pub mod markdown;
+
+pub const URL_VAR: &str = "AOC_URL";
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const DEFAULT_URL: &str = "https://adventofcode.com";
+pub const CACHE_DIR: &str = "./puzzles";
+
+const YEAR: u16 = 2024;
+
+pub fn base_url() -> String {
+    std::env::var(URL_VAR).unwrap_or_else(|_| DEFAULT_URL.to_owned())
+}
+
+pub fn url(base: &str, day: u8) -> String {
+    format!("{}/{YEAR}/day/{day}", base.trim_end_matches('/'))
+}
+
+pub fn cache_path(cache_dir: &Path, day: u8) -> PathBuf {
+    cache_dir.join(format!("{day:02}.html"))
+}
+
+pub fn candidates_dir(day: u8) -> PathBuf {
+    fixtures::dir(day).join("candidates")
+}
+
+fn fetch(base: &str, day: u8) -> Result<String, String> {
+    let url = url(base, day);
+    if let Some(path) = url.strip_prefix("file://") {
+        return std::fs::read_to_string(path)
+            .map_err(|err| format!("Could not read {path}: {err}"));
+    }
+
+    let session = std::env::var(SESSION_VAR).ok();
+    http::get(
+        &url,
+        session
+            .map(|session| format!("session={session}"))
+            .as_deref(),
+    )
+}
+
+/// The day's page from the cache, fetching it from `base` when it isn't cached yet or when
+/// `refresh` asks for a new copy, e.g. after solving part one.
+pub fn load(day: u8, base: &str, cache_dir: &Path, refresh: bool) -> Result<String, String> {
+    let path = cache_path(cache_dir, day);
+    if !refresh {
+        match std::fs::read_to_string(&path) {
+            Ok(page) => return Ok(page),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => return Err(format!("Could not read {}: {err}", path.display())),
+        }
+    }
+
+    let page = fetch(base, day)?;
+    std::fs::create_dir_all(cache_dir)
+        .and_then(|()| std::fs::write(&path, &page))
+        .map_err(|err| format!("Could not write {}: {err}", path.display()))?;
+    Ok(page)
+}
+
+/// Writes the page's examples that aren't fixtures yet to [`candidates_dir`], replacing
+/// earlier candidates. Returns the new input paths.
+pub fn extract_candidates(day: u8, page: &str) -> Result<Vec<String>, String> {
+    let existing: Vec<String> = fixtures::load(day)?
+        .into_iter()
+        .map(|fixture| fixture.input)
+        .collect();
+
+    let dir = candidates_dir(day);
+    match std::fs::remove_dir_all(&dir) {
+        Err(err) if err.kind() != std::io::ErrorKind::NotFound => {
+            return Err(format!("Could not clear {}: {err}", dir.display()))
+        }
+        _ => {}
+    }
+    extract::write(&dir, &extract::examples(page), &existing)
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{cache_path, load, url};
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+    };
+
+    /// A made-up page shaped like the real ones.
+    pub const PAGE: &str = r#"<!DOCTYPE html>
+<html><head><title>Day 1 - Advent of Code 2024</title></head>
+<body><main>
+<article class="day-desc"><h2>--- Day 1: Shelf Sorting ---</h2><p>The elves keep <em>two</em> lists of shelf numbers:</p>
+<pre><code>3   4
+4   3
+</code></pre>
+<ul>
+<li>Pair them up.</li>
+<li>Sum the <code>distances</code>.</li>
+</ul>
+<p>Here, the total is <code><em>11</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>1234</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Now multiply instead, for <code><em>31</em></code>.</p>
+</article>
+</main></body></html>
+"#;
+
+    /// Serves `PAGE` to one request and returns the request line it got.
+    fn mock_server() -> (String, std::thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = String::new();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            reader.read_line(&mut request).unwrap();
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 2 {
+                line.clear();
+            }
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{PAGE}",
+                PAGE.len()
+            )
+            .unwrap();
+            request
+        });
+        (base, handle)
+    }
+
+    #[test]
+    fn fetches_once_then_caches() {
+        let cache = std::env::temp_dir().join(format!("aoc-puzzles-{}", std::process::id()));
+        let (base, server) = mock_server();
+
+        assert_eq!(PAGE, load(1, &base, &cache, false).unwrap());
+        assert_eq!("GET /2024/day/1 HTTP/1.1\r\n", server.join().unwrap());
+
+        // The server is gone, so this can only come from the cache.
+        assert_eq!(PAGE, load(1, &base, &cache, false).unwrap());
+        assert!(cache_path(&cache, 1).exists());
+        assert!(load(1, &base, &cache, true).is_err());
+        std::fs::remove_dir_all(&cache).unwrap();
+    }
+
+    #[test]
+    fn file_urls() {
+        let mock = std::env::temp_dir().join(format!("aoc-mock-{}", std::process::id()));
+        let cache = mock.join("cache");
+        std::fs::create_dir_all(mock.join("2024/day")).unwrap();
+        std::fs::write(mock.join("2024/day/1"), PAGE).unwrap();
+
+        let base = format!("file://{}", mock.display());
+        assert_eq!(format!("{base}/2024/day/1"), url(&format!("{base}/"), 1));
+        assert_eq!(PAGE, load(1, &base, &cache, false).unwrap());
+        std::fs::remove_dir_all(&mock).unwrap();
+    }
+}
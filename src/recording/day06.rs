@@ -0,0 +1,58 @@
+use super::{Recorder, Recording, State};
+use crate::days::day06::{
+    guard::{Guard, Position},
+    map::{Element, GuardElement, Map},
+};
+use crate::grid::{mask::LayeredMask, GridCell};
+
+/// The map with the guard drawn facing its current direction; visited cells are `X`.
+fn state(guard: &Guard) -> State {
+    let mut grid: Vec<Vec<char>> = guard
+        .map
+        .0
+        .iter()
+        .map(|row| row.iter().map(GridCell::to_char).collect())
+        .collect();
+    if let Position::Map(i, j) = guard.position {
+        grid[i][j] = Element::Guard(GuardElement {
+            direction: guard.direction,
+        })
+        .to_char();
+    }
+
+    State {
+        grid,
+        values: Vec::new(),
+    }
+}
+
+/// The guard's patrol until it leaves the map, or until it is back where it was facing the
+/// same way, when it walks in a loop.
+pub fn record(input: &str, max_steps: Option<usize>) -> Result<Recording, String> {
+    let mut map: Map = input
+        .parse()
+        .map_err(|err| format!("Invalid map: {err:?}"))?;
+    let (i, j, direction) = map.guard().ok_or("Map has no guard")?;
+    let mut seen: LayeredMask<4> = LayeredMask::for_grid(&map.0);
+
+    let mut guard = Guard {
+        position: Position::Map(i, j),
+        map: &mut map,
+        direction,
+    };
+    let mut recorder = Recorder::new(6, state(&guard));
+
+    while let Position::Map(i, j) = guard.position {
+        if !seen.insert(j, i, guard.direction as usize)
+            || max_steps.is_some_and(|max| recorder.steps.len() == max)
+        {
+            break;
+        }
+        guard
+            .step()
+            .map_err(|err| format!("Guard could not step: {err:?}"))?;
+        recorder.record(state(&guard));
+    }
+
+    Ok(recorder.finish())
+}
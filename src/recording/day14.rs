@@ -0,0 +1,43 @@
+use super::{Recorder, Recording, State};
+use crate::days::day14::{grid::Grid, size::Size, SIZE};
+
+/// Seconds recorded unless asked otherwise, as many as part 1 simulates.
+const DEFAULT_SECONDS: usize = 100;
+
+/// One `rN=x,y` value per robot, plus the size of the room.
+fn state(grid: &Grid) -> State {
+    let mut values = vec![(
+        "size".to_owned(),
+        format!("{}x{}", grid.size.width, grid.size.height),
+    )];
+    values.extend(grid.robots.iter().enumerate().map(|(i, robot)| {
+        (
+            format!("r{i}"),
+            format!("{},{}", robot.position.x, robot.position.y),
+        )
+    }));
+
+    State {
+        grid: Vec::new(),
+        values,
+    }
+}
+
+/// Robot positions second by second, in the real room.
+pub fn record(input: &str, max_steps: Option<usize>) -> Result<Recording, String> {
+    record_in(SIZE, input, max_steps)
+}
+
+/// Like [`record`], in a room of another size such as the example's.
+pub fn record_in(size: Size, input: &str, max_steps: Option<usize>) -> Result<Recording, String> {
+    let mut grid =
+        Grid::from_robots_input(input, size).map_err(|err| format!("Invalid robots: {err:?}"))?;
+    let mut recorder = Recorder::new(14, state(&grid));
+
+    for _ in 0..max_steps.unwrap_or(DEFAULT_SECONDS) {
+        grid.wait_one_second();
+        recorder.record(state(&grid));
+    }
+
+    Ok(recorder.finish())
+}
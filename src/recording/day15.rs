@@ -0,0 +1,39 @@
+use super::{Recorder, Recording, State};
+use crate::days::day15::{input::Input, map::Map, robot::Robot};
+
+fn state(map: &Map) -> State {
+    State {
+        grid: map
+            .to_string()
+            .lines()
+            .map(|line| line.chars().collect())
+            .collect(),
+        values: Vec::new(),
+    }
+}
+
+/// The robot's moves in the narrow warehouse; pushed boxes show up as changed cells.
+pub fn record(input: &str, max_steps: Option<usize>) -> Result<Recording, String> {
+    let Input {
+        mut map,
+        directions,
+    } = input
+        .parse()
+        .map_err(|err| format!("Invalid input: {err:?}"))?;
+    let position = map.find_robot_position().ok_or("Warehouse has no robot")?;
+    let mut recorder = Recorder::new(15, state(&map));
+
+    let mut robot = Robot {
+        position,
+        map: &mut map,
+        steps_made: 0,
+    };
+    for direction in directions.into_iter().take(max_steps.unwrap_or(usize::MAX)) {
+        robot
+            .step(direction)
+            .map_err(|err| format!("Robot could not step: {:?}", err.kind))?;
+        recorder.record(state(robot.map));
+    }
+
+    Ok(recorder.finish())
+}
@@ -0,0 +1,52 @@
+use super::{Recorder, Recording, State};
+use crate::days::day17::Computer;
+
+/// Instructions recorded unless asked otherwise, enough for any program that halts.
+const DEFAULT_INSTRUCTIONS: usize = 1_000_000;
+
+/// The registers and instruction pointer, plus one `oN` value per output so a step that
+/// outputs changes only its own.
+fn state(computer: &Computer, instruction_pointer: usize, output: &[u32]) -> State {
+    let mut values = vec![
+        ("a".to_owned(), computer.a.0.to_string()),
+        ("b".to_owned(), computer.b.0.to_string()),
+        ("c".to_owned(), computer.c.0.to_string()),
+        ("ip".to_owned(), instruction_pointer.to_string()),
+    ];
+    values.extend(
+        output
+            .iter()
+            .enumerate()
+            .map(|(i, out)| (format!("o{i}"), out.to_string())),
+    );
+
+    State {
+        grid: Vec::new(),
+        values,
+    }
+}
+
+/// The instruction trace with the registers after every instruction.
+pub fn record(input: &str, max_steps: Option<usize>) -> Result<Recording, String> {
+    let mut computer: Computer = input
+        .parse()
+        .map_err(|err| format!("Invalid program: {err:?}"))?;
+    let program = computer.program.clone();
+    let mut instruction_pointer = 0;
+    let mut output = Vec::new();
+    let mut recorder = Recorder::new(17, state(&computer, instruction_pointer, &output));
+
+    let max_steps = max_steps.unwrap_or(DEFAULT_INSTRUCTIONS);
+    while let Some(instruction) = program.0.get(instruction_pointer / 2) {
+        if recorder.steps.len() == max_steps {
+            break;
+        }
+
+        if let Some(out) = computer.execute_instruction(&mut instruction_pointer, instruction) {
+            output.push(out);
+        }
+        recorder.record(state(&computer, instruction_pointer, &output));
+    }
+
+    Ok(recorder.finish())
+}
@@ -0,0 +1,528 @@
+//! Compact recordings of stepping runs that can be replayed, diffed or rendered later.
+//!
+//! A recording is a header with the initial state followed by one line of changes per step:
+//!
+//! ```text
+//! aoc-recording 1
+//! day 17
+//! value a=729
+//! value ip=0
+//! grid 3 2
+//! .#.
+//! ..^
+//! steps
+//! a=364 ip=2
+//! 1,1=X 1,0=^
+//! -
+//! ```
+//!
+//! A change is either `x,y=c` for a grid cell or `key=value` for a named value; `-` is a
+//! step that changed nothing. Keys and values cannot contain whitespace.
+
+use std::fmt::Write;
+
+mod day06;
+mod day14;
+mod day15;
+mod day17;
+
+const MAGIC: &str = "aoc-recording 1";
+
+/// Days that can be recorded, see [`record`].
+pub const DAYS: [u8; 4] = [6, 14, 15, 17];
+
+/// Everything a renderer needs to draw one moment of a run.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct State {
+    pub grid: Vec<Vec<char>>,
+    /// Named values in the order they first appeared.
+    pub values: Vec<(String, String)>,
+}
+
+impl State {
+    pub fn value(&self, key: &str) -> Option<&str> {
+        self.values
+            .iter()
+            .find_map(|(k, value)| (k == key).then_some(value.as_str()))
+    }
+
+    pub fn set_value(&mut self, key: &str, value: String) {
+        match self.values.iter_mut().find(|(k, _)| k == key) {
+            Some((_, current)) => *current = value,
+            None => self.values.push((key.to_owned(), value)),
+        }
+    }
+
+    pub fn render_grid(&self) -> String {
+        self.grid
+            .iter()
+            .map(|row| row.iter().collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    pub fn apply(&mut self, changes: &[Change]) {
+        for change in changes {
+            match change {
+                Change::Cell { x, y, value } => self.grid[*y][*x] = *value,
+                Change::Value { key, value } => self.set_value(key, value.clone()),
+            }
+        }
+    }
+
+    /// What turns `self` into `next`: changed cells in reading order, then changed values.
+    pub fn changes(&self, next: &State) -> Vec<Change> {
+        let mut changes = Vec::new();
+        for (y, (row, next_row)) in self.grid.iter().zip(&next.grid).enumerate() {
+            for (x, (cell, next_cell)) in row.iter().zip(next_row).enumerate() {
+                if cell != next_cell {
+                    changes.push(Change::Cell {
+                        x,
+                        y,
+                        value: *next_cell,
+                    });
+                }
+            }
+        }
+
+        for (key, value) in &next.values {
+            if self.value(key) != Some(value.as_str()) {
+                changes.push(Change::Value {
+                    key: key.clone(),
+                    value: value.clone(),
+                });
+            }
+        }
+
+        changes
+    }
+}
+
+impl std::fmt::Display for State {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if !self.grid.is_empty() {
+            writeln!(f, "{}", self.render_grid())?;
+        }
+        for (key, value) in &self.values {
+            writeln!(f, "{key}={value}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    Cell { x: usize, y: usize, value: char },
+    Value { key: String, value: String },
+}
+
+impl std::fmt::Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Change::Cell { x, y, value } => write!(f, "{x},{y}={value}"),
+            Change::Value { key, value } => write!(f, "{key}={value}"),
+        }
+    }
+}
+
+impl std::str::FromStr for Change {
+    type Err = ParseRecordingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (key, value) = s
+            .split_once('=')
+            .ok_or_else(|| ParseRecordingError(format!("Expected KEY=VALUE, got {s}")))?;
+
+        let Some((x, y)) = key.split_once(',') else {
+            return Ok(Change::Value {
+                key: key.to_owned(),
+                value: value.to_owned(),
+            });
+        };
+
+        let mut chars = value.chars();
+        match (x.parse(), y.parse(), chars.next(), chars.next()) {
+            (Ok(x), Ok(y), Some(value), None) => Ok(Change::Cell { x, y, value }),
+            _ => Err(ParseRecordingError(format!("Invalid cell change {s}"))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseRecordingError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recording {
+    pub day: u8,
+    pub initial: State,
+    pub steps: Vec<Vec<Change>>,
+}
+
+impl Recording {
+    /// The state after `step` steps, or after the last one when there are fewer.
+    pub fn state_at(&self, step: usize) -> State {
+        let mut state = self.initial.clone();
+        for changes in self.steps.iter().take(step) {
+            state.apply(changes);
+        }
+        state
+    }
+
+    pub fn final_state(&self) -> State {
+        self.state_at(self.steps.len())
+    }
+}
+
+impl std::fmt::Display for Recording {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{MAGIC}")?;
+        writeln!(f, "day {}", self.day)?;
+        for (key, value) in &self.initial.values {
+            writeln!(f, "value {key}={value}")?;
+        }
+        if let Some(first) = self.initial.grid.first() {
+            writeln!(f, "grid {} {}", first.len(), self.initial.grid.len())?;
+            writeln!(f, "{}", self.initial.render_grid())?;
+        }
+
+        writeln!(f, "steps")?;
+        let mut line = String::new();
+        for changes in &self.steps {
+            line.clear();
+            for change in changes {
+                if !line.is_empty() {
+                    line.push(' ');
+                }
+                let _ = write!(line, "{change}");
+            }
+            match line.is_empty() {
+                true => writeln!(f, "-")?,
+                false => writeln!(f, "{line}")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for Recording {
+    type Err = ParseRecordingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = |message: String| ParseRecordingError(message);
+        let mut lines = s.lines();
+        if lines.next() != Some(MAGIC) {
+            return Err(error(format!("Expected `{MAGIC}` on the first line")));
+        }
+
+        let day = lines
+            .next()
+            .and_then(|line| line.strip_prefix("day "))
+            .and_then(|day| day.parse().ok())
+            .ok_or_else(|| error("Expected `day N` on the second line".to_owned()))?;
+
+        let mut initial = State::default();
+        loop {
+            let line = lines
+                .next()
+                .ok_or_else(|| error("Missing `steps` line".to_owned()))?;
+
+            if line == "steps" {
+                break;
+            } else if let Some(value) = line.strip_prefix("value ") {
+                match value.parse()? {
+                    Change::Value { key, value } => initial.set_value(&key, value),
+                    Change::Cell { .. } => return Err(error(format!("Invalid value {value}"))),
+                }
+            } else if let Some(size) = line.strip_prefix("grid ") {
+                let height = size
+                    .split_once(' ')
+                    .and_then(|(_, height)| height.parse().ok())
+                    .ok_or_else(|| error(format!("Expected `grid WIDTH HEIGHT`, got {line}")))?;
+                for _ in 0..height {
+                    let row = lines
+                        .next()
+                        .ok_or_else(|| error("Grid is shorter than its header".to_owned()))?;
+                    initial.grid.push(row.chars().collect());
+                }
+            } else {
+                return Err(error(format!("Unexpected header line {line}")));
+            }
+        }
+
+        let steps = lines
+            .map(|line| match line {
+                "-" => Ok(Vec::new()),
+                line => line.split_whitespace().map(str::parse).collect(),
+            })
+            .collect::<Result<Vec<Vec<Change>>, _>>()?;
+
+        // Changes never resize the grid, so every cell has to exist from the start.
+        for (step, changes) in steps.iter().enumerate() {
+            for change in changes {
+                if let Change::Cell { x, y, .. } = change {
+                    if initial.grid.get(*y).and_then(|row| row.get(*x)).is_none() {
+                        return Err(error(format!(
+                            "Step {} changes {change}, outside the grid",
+                            step + 1
+                        )));
+                    }
+                }
+            }
+        }
+
+        Ok(Recording {
+            day,
+            initial,
+            steps,
+        })
+    }
+}
+
+/// Builds a recording from the states a run goes through.
+#[derive(Debug)]
+pub struct Recorder {
+    day: u8,
+    initial: State,
+    last: State,
+    steps: Vec<Vec<Change>>,
+}
+
+impl Recorder {
+    pub fn new(day: u8, initial: State) -> Recorder {
+        Recorder {
+            day,
+            last: initial.clone(),
+            initial,
+            steps: Vec::new(),
+        }
+    }
+
+    pub fn record(&mut self, state: State) {
+        self.steps.push(self.last.changes(&state));
+        self.last = state;
+    }
+
+    pub fn finish(self) -> Recording {
+        Recording {
+            day: self.day,
+            initial: self.initial,
+            steps: self.steps,
+        }
+    }
+}
+
+/// Records a run of `day` on `input`, stopping after `max_steps` when given.
+pub fn record(day: u8, input: &str, max_steps: Option<usize>) -> Result<Recording, String> {
+    match day {
+        6 => day06::record(input, max_steps),
+        14 => day14::record(input, max_steps),
+        15 => day15::record(input, max_steps),
+        17 => day17::record(input, max_steps),
+        _ => Err(format!("Day {day} cannot be recorded, try one of {DAYS:?}")),
+    }
+}
+
+/// Where two recordings part ways.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Divergence {
+    Day {
+        a: u8,
+        b: u8,
+    },
+    Initial,
+    Step {
+        step: usize,
+        a: Vec<Change>,
+        b: Vec<Change>,
+    },
+    Length {
+        a: usize,
+        b: usize,
+    },
+}
+
+impl std::fmt::Display for Divergence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let join = |changes: &[Change]| {
+            changes
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+
+        match self {
+            Divergence::Day { a, b } => write!(f, "Recordings are of different days: {a} and {b}"),
+            Divergence::Initial => f.write_str("Initial states differ"),
+            Divergence::Step { step, a, b } => write!(
+                f,
+                "Step {step} differs:\n  a: {}\n  b: {}",
+                join(a),
+                join(b)
+            ),
+            Divergence::Length { a, b } => {
+                write!(
+                    f,
+                    "Same up to step {}, then a has {a} steps and b has {b}",
+                    a.min(b)
+                )
+            }
+        }
+    }
+}
+
+/// The first point where `a` and `b` differ, or `None` when they are the same run.
+pub fn diff(a: &Recording, b: &Recording) -> Option<Divergence> {
+    if a.day != b.day {
+        return Some(Divergence::Day { a: a.day, b: b.day });
+    }
+    if a.initial != b.initial {
+        return Some(Divergence::Initial);
+    }
+
+    if let Some((i, (a, b))) = a
+        .steps
+        .iter()
+        .zip(&b.steps)
+        .enumerate()
+        .find(|(_, (a, b))| a != b)
+    {
+        return Some(Divergence::Step {
+            step: i + 1,
+            a: a.clone(),
+            b: b.clone(),
+        });
+    }
+
+    (a.steps.len() != b.steps.len()).then_some(Divergence::Length {
+        a: a.steps.len(),
+        b: b.steps.len(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        day14, diff, record, Change, Divergence, ParseRecordingError, Recorder, Recording, State,
+    };
+    use crate::{
+        days::{
+            day14::{robot::ROBOTS_INPUT_EXAMPLE, size::Size},
+            day15::input::INPUT_EXAMPLE,
+        },
+        normalize::normalize,
+    };
+
+    const EXAMPLE_SIZE: Size = Size {
+        width: 11,
+        height: 7,
+    };
+
+    fn grid(rows: &[&str]) -> State {
+        State {
+            grid: rows.iter().map(|row| row.chars().collect()).collect(),
+            values: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut start = grid(&[".#.", "..^"]);
+        start.set_value("steps", "0".to_owned());
+        let mut recorder = Recorder::new(6, start.clone());
+
+        let mut next = grid(&[".#.", ".^X"]);
+        next.set_value("steps", "1".to_owned());
+        recorder.record(next.clone());
+        recorder.record(next.clone());
+        let recording = recorder.finish();
+
+        let text = recording.to_string();
+        assert_eq!(
+            "aoc-recording 1
+day 6
+value steps=0
+grid 3 2
+.#.
+..^
+steps
+1,1=^ 2,1=X steps=1
+-
+",
+            text
+        );
+        assert_eq!(Ok(recording.clone()), text.parse::<Recording>());
+        assert_eq!(start, recording.state_at(0));
+        assert_eq!(next, recording.final_state());
+        assert!(text
+            .replace("value", "values")
+            .parse::<Recording>()
+            .is_err());
+    }
+
+    #[test]
+    fn cells_outside_the_grid() {
+        let text = "aoc-recording 1\nday 6\ngrid 3 2\n.#.\n..^\nsteps\n-\n3,1=X\n";
+        assert_eq!(
+            Err(ParseRecordingError(
+                "Step 2 changes 3,1=X, outside the grid".to_owned()
+            )),
+            text.parse::<Recording>()
+        );
+        assert!(text.replace("3,1", "2,1").parse::<Recording>().is_ok());
+    }
+
+    #[test]
+    fn replays_match_solutions() {
+        let example = std::fs::read_to_string("./fixtures/06/example.txt").unwrap();
+        let guard = record(6, &normalize(&example), None).unwrap();
+        let visited = guard.final_state().render_grid().matches('X').count();
+        assert_eq!(41, visited);
+        let looping = record(6, ".#..\n.^.#\n#...\n..#.", None).unwrap();
+        assert_eq!(8, looping.steps.len());
+
+        let warehouse = record(15, INPUT_EXAMPLE, None).unwrap();
+        assert_eq!(700, warehouse.steps.len());
+        assert!(warehouse
+            .final_state()
+            .render_grid()
+            .starts_with("##########\n#.O.O.OOO#"));
+
+        let robots = day14::record_in(EXAMPLE_SIZE, ROBOTS_INPUT_EXAMPLE, Some(5)).unwrap();
+        assert_eq!(Some("1,3"), robots.state_at(5).value("r10"));
+
+        let program = record(17, crate::days::day17::EXAMPLE, None).unwrap();
+        let parsed: Recording = program.to_string().parse().unwrap();
+        let last = parsed.final_state();
+        let output: Vec<&str> = (0..).map_while(|i| last.value(&format!("o{i}"))).collect();
+        assert_eq!("4,6,3,5,6,3,5,2,1,0", output.join(","));
+        assert!(record(3, "", None).is_err());
+    }
+
+    #[test]
+    fn divergence() {
+        let a = record(17, crate::days::day17::EXAMPLE, None).unwrap();
+        assert_eq!(None, diff(&a, &a.clone()));
+
+        let mut b = a.clone();
+        b.steps[3].push(Change::Value {
+            key: "a".to_owned(),
+            value: "0".to_owned(),
+        });
+        assert!(matches!(
+            diff(&a, &b),
+            Some(Divergence::Step { step: 4, .. })
+        ));
+
+        b = a.clone();
+        b.steps.pop();
+        assert_eq!(
+            Some(Divergence::Length {
+                a: a.steps.len(),
+                b: a.steps.len() - 1
+            }),
+            diff(&a, &b)
+        );
+    }
+}
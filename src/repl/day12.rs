@@ -0,0 +1,50 @@
+use super::{unknown_command, Output};
+use crate::{days::day12::garden::Garden, table::Table};
+
+pub const HELP: &str = "Day 12:
+    regions   every region with its plant, area, perimeter, price and first plot
+    price     total fence price
+    show      the garden";
+
+pub struct Session {
+    garden: Garden,
+}
+
+impl Session {
+    pub fn load(input: &str) -> Result<Session, String> {
+        let garden: Garden = input
+            .parse()
+            .map_err(|err| format!("Invalid garden: {err:?}"))?;
+        Ok(Session { garden })
+    }
+
+    pub fn eval(&mut self, command: &str, args: &[&str]) -> Result<Output, String> {
+        match (command, args) {
+            ("regions", []) => {
+                let mut table = Table::new(["plant", "area", "perimeter", "price", "x", "y"]);
+                for region in self.garden.regions() {
+                    let first = region.positions[0];
+                    table.push([
+                        region.plant.0.to_string(),
+                        region.area().to_string(),
+                        region.perimeter().to_string(),
+                        region.fence_price().to_string(),
+                        first.x.to_string(),
+                        first.y.to_string(),
+                    ]);
+                }
+                Ok(Output::Rows(table))
+            }
+            ("price", []) => Ok(Output::Text(self.garden.fence_price().to_string())),
+            ("show", []) => Ok(Output::Text(
+                self.garden
+                    .0
+                    .iter()
+                    .map(|row| row.iter().map(|plant| plant.0).collect::<String>())
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            )),
+            _ => Err(unknown_command(12, command, args)),
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use super::{unknown_command, Output};
+use crate::days::day15::{
+    direction::Direction,
+    double::{map::Map as WideMap, robot::Robot as WideRobot},
+    input::Input,
+    map::Map,
+    robot::Robot,
+};
+
+pub const HELP: &str = "Day 15:
+    step [N|MOVES]   make the next N moves from the input (default 1), or the given moves like <<v
+    run              make every remaining move from the input
+    show             the warehouse and how many moves were made
+    gps              sum of the boxes' GPS coordinates
+    wide             restart in the twice-as-wide warehouse of part 2
+    reset            restart in the original warehouse";
+
+enum Warehouse {
+    Narrow(Map),
+    Wide(WideMap),
+}
+
+pub struct Session {
+    initial: Map,
+    warehouse: Warehouse,
+    moves: Vec<Direction>,
+    next_move: usize,
+    steps_made: u32,
+}
+
+impl Session {
+    pub fn load(input: &str) -> Result<Session, String> {
+        let Input { map, directions } = input
+            .parse()
+            .map_err(|err| format!("Invalid warehouse input: {err:?}"))?;
+        map.find_robot_position().ok_or("Warehouse has no robot")?;
+
+        Ok(Session {
+            initial: map.clone(),
+            warehouse: Warehouse::Narrow(map),
+            moves: directions,
+            next_move: 0,
+            steps_made: 0,
+        })
+    }
+
+    pub fn eval(&mut self, command: &str, args: &[&str]) -> Result<Output, String> {
+        match (command, args) {
+            ("step", []) => self.make_input_moves(1),
+            ("step", [arg]) => match arg.parse::<usize>() {
+                Ok(n) => self.make_input_moves(n),
+                Err(_) => {
+                    let moves = arg
+                        .chars()
+                        .map(|ch| ch.to_string().parse::<Direction>())
+                        .collect::<Result<Vec<_>, _>>()
+                        .map_err(|err| format!("Invalid move {:?}", err.0))?;
+                    moves
+                        .into_iter()
+                        .try_for_each(|direction| self.step(direction))?;
+                    Ok(self.show())
+                }
+            },
+            ("run", []) => self.make_input_moves(self.moves.len() - self.next_move),
+            ("show", []) => Ok(self.show()),
+            ("gps", []) => Ok(Output::Text(self.gps().to_string())),
+            ("wide", []) => {
+                self.restart(Warehouse::Wide(WideMap::from(self.initial.clone())));
+                Ok(self.show())
+            }
+            ("reset", []) => {
+                self.restart(Warehouse::Narrow(self.initial.clone()));
+                Ok(self.show())
+            }
+            _ => Err(unknown_command(15, command, args)),
+        }
+    }
+
+    fn restart(&mut self, warehouse: Warehouse) {
+        self.warehouse = warehouse;
+        self.next_move = 0;
+        self.steps_made = 0;
+    }
+
+    fn make_input_moves(&mut self, n: usize) -> Result<Output, String> {
+        for _ in 0..n {
+            let Some(&direction) = self.moves.get(self.next_move) else {
+                break;
+            };
+            self.step(direction)?;
+            self.next_move += 1;
+        }
+
+        Ok(self.show())
+    }
+
+    fn step(&mut self, direction: Direction) -> Result<(), String> {
+        match &mut self.warehouse {
+            Warehouse::Narrow(map) => Robot {
+                position: map.find_robot_position().ok_or("Warehouse has no robot")?,
+                map,
+                steps_made: self.steps_made,
+            }
+            .step(direction)
+            .map_err(|err| format!("Robot could not move: {:?}", err.kind))?,
+            Warehouse::Wide(map) => WideRobot {
+                position: map.find_robot_position().ok_or("Warehouse has no robot")?,
+                map,
+                steps_made: self.steps_made,
+            }
+            .step(direction)
+            .map_err(|err| format!("Robot could not move: {:?}", err.kind))?,
+        }
+
+        self.steps_made += 1;
+        Ok(())
+    }
+
+    fn gps(&self) -> usize {
+        match &self.warehouse {
+            Warehouse::Narrow(map) => map.boxes_gps(),
+            Warehouse::Wide(map) => map.boxes_gps(),
+        }
+    }
+
+    fn show(&self) -> Output {
+        let map = match &self.warehouse {
+            Warehouse::Narrow(map) => map.to_string(),
+            Warehouse::Wide(map) => map.to_string(),
+        };
+
+        Output::Text(format!(
+            "{}\nInput moves made: {}/{}, total steps: {}",
+            map.trim_end(),
+            self.next_move,
+            self.moves.len(),
+            self.steps_made
+        ))
+    }
+}
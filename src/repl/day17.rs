@@ -0,0 +1,136 @@
+use super::{unknown_command, Output};
+use crate::{
+    days::day17::{Computer, RegisterA, RegisterB, RegisterC},
+    table::Table,
+};
+
+pub const HELP: &str = "Day 17:
+    set a|b|c VALUE   set a register
+    step [N]          execute the next N instructions (default 1)
+    run               execute until the program halts, at most 1000000 instructions
+    show              registers, instruction pointer and output so far
+    program           the program's instructions
+    reset             restore the loaded registers and start over";
+
+/// `run` gives up after this many instructions so a looping program can't hang the REPL.
+const MAX_RUN_INSTRUCTIONS: u32 = 1_000_000;
+
+pub struct Session {
+    initial: Computer,
+    computer: Computer,
+    instruction_pointer: usize,
+    output: Vec<u32>,
+}
+
+impl Session {
+    pub fn load(input: &str) -> Result<Session, String> {
+        let computer: Computer = input
+            .parse()
+            .map_err(|err| format!("Invalid computer: {err:?}"))?;
+
+        Ok(Session {
+            initial: computer.clone(),
+            computer,
+            instruction_pointer: 0,
+            output: Vec::new(),
+        })
+    }
+
+    pub fn eval(&mut self, command: &str, args: &[&str]) -> Result<Output, String> {
+        match (command, args) {
+            ("set", [register, value]) => {
+                let value: u32 = value
+                    .parse()
+                    .map_err(|_| format!("Invalid register value: {value}"))?;
+                match register.to_ascii_lowercase().as_str() {
+                    "a" => self.computer.a = RegisterA(value),
+                    "b" => self.computer.b = RegisterB(value),
+                    "c" => self.computer.c = RegisterC(value),
+                    _ => return Err(format!("Unknown register: {register}")),
+                }
+                Ok(self.show())
+            }
+            ("step", []) => self.execute(1),
+            ("step", [n]) => self.execute(n.parse().map_err(|_| format!("Invalid count: {n}"))?),
+            ("run", []) => {
+                self.execute(MAX_RUN_INSTRUCTIONS)?;
+                match self.halted() {
+                    true => Ok(Output::Text(self.joined_output())),
+                    false => Err(format!(
+                        "Program did not halt within {MAX_RUN_INSTRUCTIONS} instructions"
+                    )),
+                }
+            }
+            ("show", []) => Ok(self.show()),
+            ("program", []) => {
+                let mut table = Table::new(["ip", "instruction", "operand"]);
+                for (i, (instruction, operand)) in self.computer.program.0.iter().enumerate() {
+                    table.push([
+                        (i * 2).to_string(),
+                        format!("{instruction:?}").to_lowercase(),
+                        operand.0.to_string(),
+                    ]);
+                }
+                Ok(Output::Rows(table))
+            }
+            ("reset", []) => {
+                self.computer = self.initial.clone();
+                self.instruction_pointer = 0;
+                self.output.clear();
+                Ok(self.show())
+            }
+            _ => Err(unknown_command(17, command, args)),
+        }
+    }
+
+    fn halted(&self) -> bool {
+        self.instruction_pointer / 2 >= self.computer.program.0.len()
+    }
+
+    fn execute(&mut self, n: u32) -> Result<Output, String> {
+        for _ in 0..n {
+            let Some(pair) = self
+                .computer
+                .program
+                .0
+                .get(self.instruction_pointer / 2)
+                .cloned()
+            else {
+                break;
+            };
+
+            if let Some(out) = self
+                .computer
+                .execute_instruction(&mut self.instruction_pointer, &pair)
+            {
+                self.output.push(out);
+            }
+        }
+
+        Ok(self.show())
+    }
+
+    fn joined_output(&self) -> String {
+        self.output
+            .iter()
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    fn show(&self) -> Output {
+        let status = match self.halted() {
+            true => " (halted)",
+            false => "",
+        };
+
+        Output::Text(format!(
+            "A: {}, B: {}, C: {}, ip: {}{status}\nOutput: {}",
+            self.computer.a.0,
+            self.computer.b.0,
+            self.computer.c.0,
+            self.instruction_pointer,
+            self.joined_output()
+        ))
+    }
+}
@@ -0,0 +1,309 @@
+//! Line-based REPL for poking at a day's internals with a loaded input.
+//!
+//! A line is a command optionally followed by filters: `regions | sort area desc | head 5`.
+//! Filters only apply to commands that produce rows.
+
+use crate::{days, normalize::normalize, table::Table};
+use std::cmp::Ordering;
+
+mod day12;
+mod day15;
+mod day17;
+
+const HELP: &str = "Commands:
+    dayNN [COMMAND]   switch to a day, optionally running COMMAND for it
+    load [PATH]       load PATH, or the day's own input when no path is given
+    help              this help and the current day's commands
+    quit              leave the REPL
+Filters (for commands producing rows):
+    sort COLUMN [desc], head N";
+
+/// Days that have a command set, see [`Session`].
+pub const DAYS: [u8; 3] = [12, 15, 17];
+
+/// What a command produced.
+#[derive(Debug, Clone)]
+pub enum Output {
+    Text(String),
+    Rows(Table),
+}
+
+impl std::fmt::Display for Output {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Output::Text(text) => writeln!(f, "{text}"),
+            Output::Rows(table) => write!(f, "{table}"),
+        }
+    }
+}
+
+/// A day with its input loaded.
+enum Session {
+    Day12(day12::Session),
+    Day15(day15::Session),
+    Day17(day17::Session),
+}
+
+impl Session {
+    fn load(day: u8, input: &str) -> Result<Session, String> {
+        match day {
+            12 => day12::Session::load(input).map(Session::Day12),
+            15 => day15::Session::load(input).map(Session::Day15),
+            17 => day17::Session::load(input).map(Session::Day17),
+            _ => Err(format!("Day {day} has no REPL commands")),
+        }
+    }
+
+    fn eval(&mut self, command: &str, args: &[&str]) -> Result<Output, String> {
+        match self {
+            Session::Day12(session) => session.eval(command, args),
+            Session::Day15(session) => session.eval(command, args),
+            Session::Day17(session) => session.eval(command, args),
+        }
+    }
+}
+
+fn day_help(day: u8) -> &'static str {
+    match day {
+        12 => day12::HELP,
+        15 => day15::HELP,
+        17 => day17::HELP,
+        _ => "",
+    }
+}
+
+#[derive(Default)]
+pub struct Repl {
+    day: Option<u8>,
+    session: Option<Session>,
+}
+
+impl Repl {
+    pub fn new() -> Repl {
+        Repl::default()
+    }
+
+    pub fn prompt(&self) -> String {
+        match self.day {
+            Some(day) => format!("day{day:02}> "),
+            None => "> ".to_owned(),
+        }
+    }
+
+    /// Loads `input` for the current day, replacing whatever was loaded before.
+    pub fn load_input(&mut self, input: &str) -> Result<Output, String> {
+        let day = self.day.ok_or("No day selected, use `dayNN` first")?;
+        self.session = Some(Session::load(day, &normalize(input))?);
+        Ok(Output::Text(format!("Loaded day {day} input")))
+    }
+
+    /// Evaluates one line. Empty lines produce no output.
+    pub fn eval(&mut self, line: &str) -> Result<Option<Output>, String> {
+        let mut segments = line.split('|');
+        let command = segments.next().unwrap_or_default();
+        let Some(mut output) = self.command(&command.split_whitespace().collect::<Vec<_>>())?
+        else {
+            return Ok(None);
+        };
+
+        for filter in segments {
+            output = apply_filter(output, &filter.split_whitespace().collect::<Vec<_>>())?;
+        }
+
+        Ok(Some(output))
+    }
+
+    fn command(&mut self, words: &[&str]) -> Result<Option<Output>, String> {
+        let Some((&command, args)) = words.split_first() else {
+            return Ok(None);
+        };
+
+        if let Some(day) = command.strip_prefix("day") {
+            let day: u8 = day.parse().map_err(|_| format!("Invalid day: {command}"))?;
+            if !DAYS.contains(&day) {
+                return Err(format!(
+                    "Day {day} has no REPL commands, try one of {DAYS:?}"
+                ));
+            }
+
+            if self.day != Some(day) {
+                self.day = Some(day);
+                self.session = None;
+            }
+
+            return match args.is_empty() {
+                true => Ok(Some(Output::Text(format!("Switched to day {day}")))),
+                false => self.command(args),
+            };
+        }
+
+        let output = match (command, args) {
+            ("help", _) => {
+                let day_help = self.day.map(day_help).unwrap_or_default();
+                Output::Text(format!("{HELP}\n{day_help}").trim_end().to_owned())
+            }
+            ("load", [path]) => {
+                let input = crate::crypt::read_input(path)
+                    .map_err(|err| format!("Could not read {path}: {err}"))?;
+                self.load_input(&input)?
+            }
+            ("load", []) => {
+                let day = self.day.ok_or("No day selected, use `dayNN` first")?;
+                let input = days::get(day)
+                    .ok_or_else(|| format!("Day {day} is not solved"))?
+                    .load_input()
+                    .map_err(|err| format!("Could not read input for day {day}: {err}"))?;
+                self.load_input(&input)?
+            }
+            (command, args) => self
+                .session
+                .as_mut()
+                .ok_or("No input loaded, use `load [PATH]` first")?
+                .eval(command, args)?,
+        };
+
+        Ok(Some(output))
+    }
+}
+
+fn unknown_command(day: u8, command: &str, args: &[&str]) -> String {
+    let line = std::iter::once(command)
+        .chain(args.iter().copied())
+        .collect::<Vec<_>>()
+        .join(" ");
+    format!("Unknown day {day} command: {line}, see `help`")
+}
+
+fn apply_filter(output: Output, words: &[&str]) -> Result<Output, String> {
+    let Output::Rows(mut table) = output else {
+        return Err("Only commands producing rows can be filtered".to_owned());
+    };
+
+    match words {
+        ["sort", column] | ["sort", column, "asc"] => sort(&mut table, column, false)?,
+        ["sort", column, "desc"] => sort(&mut table, column, true)?,
+        ["head", n] => {
+            let n: usize = n.parse().map_err(|_| format!("Invalid count: {n}"))?;
+            table.rows.truncate(n);
+        }
+        _ => return Err(format!("Unknown filter: {}", words.join(" "))),
+    }
+
+    Ok(Output::Rows(table))
+}
+
+/// Sorts rows by `column`, numerically when both cells are numbers. The sort is stable.
+fn sort(table: &mut Table, column: &str, descending: bool) -> Result<(), String> {
+    let i = table
+        .headers
+        .iter()
+        .position(|header| header == column)
+        .ok_or_else(|| {
+            format!(
+                "Unknown column {column}, expected one of {:?}",
+                table.headers
+            )
+        })?;
+
+    table.rows.sort_by(|a, b| {
+        let ordering = compare_cells(&a[i], &b[i]);
+        match descending {
+            true => ordering.reverse(),
+            false => ordering,
+        }
+    });
+
+    Ok(())
+}
+
+fn compare_cells(a: &str, b: &str) -> Ordering {
+    match (a.parse::<f64>(), b.parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.total_cmp(&b),
+        _ => a.cmp(b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Output, Repl};
+    use crate::days::day15::input::INPUT_EXAMPLE;
+
+    fn eval(repl: &mut Repl, line: &str) -> String {
+        repl.eval(line).unwrap().unwrap().to_string()
+    }
+
+    #[test]
+    fn requires_day_and_input() {
+        let mut repl = Repl::new();
+        assert!(repl.eval("gps").is_err());
+        assert!(repl.eval("day15 gps").is_err());
+        assert!(repl.eval("day03").is_err());
+        assert!(repl.eval("   ").unwrap().is_none());
+    }
+
+    #[test]
+    fn load_looks_for_encrypted_copies() {
+        let mut repl = Repl::new();
+        repl.eval("day12").unwrap();
+        let err = repl.eval("load ./files/missing.txt").unwrap_err();
+        assert!(err.contains("./files/missing.txt.enc"), "{err}");
+        assert!(repl.eval("load ./fixtures/12/example.txt").is_ok());
+    }
+
+    #[test]
+    fn sort_and_head() {
+        let mut repl = Repl::new();
+        repl.eval("day12").unwrap();
+        repl.load_input("AAAA\nBBCD\nBBCC\nEEEC").unwrap();
+
+        assert_eq!(
+            "plant  area  perimeter  price  x  y
+-----  ----  ---------  -----  -  -
+A      4     10         40     0  0
+B      4     8          32     0  1
+C      4     10         40     2  1
+",
+            eval(&mut repl, "regions | sort area desc | head 3")
+        );
+        assert!(matches!(
+            repl.eval("regions | sort plant").unwrap(),
+            Some(Output::Rows(_))
+        ));
+        assert!(repl.eval("price | head 1").is_err());
+        assert!(repl.eval("regions | sort nope").is_err());
+    }
+
+    #[test]
+    fn day15() {
+        let mut repl = Repl::new();
+        repl.eval("day15").unwrap();
+        repl.load_input(INPUT_EXAMPLE).unwrap();
+
+        assert!(eval(&mut repl, "step <<v").ends_with("Input moves made: 0/700, total steps: 3\n"));
+        eval(&mut repl, "reset");
+        assert!(eval(&mut repl, "step 5").ends_with("Input moves made: 5/700, total steps: 5\n"));
+        eval(&mut repl, "run");
+        assert_eq!("10092\n", eval(&mut repl, "gps"));
+        eval(&mut repl, "wide");
+        eval(&mut repl, "run");
+        assert_eq!("9021\n", eval(&mut repl, "gps"));
+        assert!(repl.eval("step x").is_err());
+    }
+
+    #[test]
+    fn day17() {
+        let mut repl = Repl::new();
+        repl.eval("day17").unwrap();
+        repl.load_input("Register A: 2024\nRegister B: 0\nRegister C: 0\n\nProgram: 0,3,5,4,3,0")
+            .unwrap();
+
+        assert_eq!(
+            "A: 253, B: 0, C: 0, ip: 2\nOutput: \n",
+            eval(&mut repl, "step")
+        );
+        eval(&mut repl, "reset");
+        eval(&mut repl, "set a 117440");
+        assert_eq!("0,3,5,4,3,0\n", eval(&mut repl, "run"));
+        assert!(repl.eval("set d 1").is_err());
+    }
+}
@@ -0,0 +1,158 @@
+//! Renderings of the days whose answers are easier to believe when you can see them.
+
+use super::html;
+use crate::{
+    days::{
+        day12::garden::Garden,
+        day14::{self, grid::Grid},
+        day15::{self, input::Input},
+        day16::{self, map::Map},
+    },
+    runner::Limits,
+};
+use std::fmt::Write;
+
+/// A rendering embedded in a day's page, with whatever styles it needs on top of [`html::STYLE`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Figure {
+    pub title: String,
+    pub html: String,
+    pub style: String,
+}
+
+impl Figure {
+    fn new(title: impl Into<String>, html: String) -> Figure {
+        Figure {
+            title: title.into(),
+            html,
+            style: String::new(),
+        }
+    }
+}
+
+/// Figures for `day` drawn from `input`. Days without renderings have none.
+pub fn for_day(day: u8, input: &str, limits: Limits) -> Result<Vec<Figure>, String> {
+    let figure = match day {
+        12 => garden(input)?,
+        14 => tree(input, limits)?,
+        15 => warehouse(input)?,
+        16 => best_path(input)?,
+        _ => return Ok(Vec::new()),
+    };
+
+    Ok(vec![figure])
+}
+
+/// Regions coloured by plant, one hue per letter.
+fn garden(input: &str) -> Result<Figure, String> {
+    let garden: Garden = input
+        .parse()
+        .map_err(|err| format!("Invalid garden: {err:?}"))?;
+    let text = garden
+        .0
+        .iter()
+        .map(|row| row.iter().map(|plant| plant.0).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let mut plants: Vec<char> = text.chars().filter(char::is_ascii_alphabetic).collect();
+    plants.sort_unstable();
+    plants.dedup();
+
+    let mut style = String::new();
+    for plant in plants {
+        // Stepping by the golden angle keeps neighbouring letters far apart on the colour wheel.
+        let hue = (plant as u32 - 'A' as u32) * 137 % 360;
+        let _ = writeln!(
+            style,
+            ".plant-{plant} {{ background: hsl({hue}, 65%, 72%); }}"
+        );
+    }
+
+    let html = html::grid(&text, garden.0.len() < 40, |c| {
+        c.is_ascii_alphabetic().then(|| format!("plant-{c}"))
+    });
+
+    Ok(Figure {
+        title: "Regions by plant".to_owned(),
+        html,
+        style,
+    })
+}
+
+/// The first frame of the robots showing the Christmas tree.
+fn tree(input: &str, limits: Limits) -> Result<Figure, String> {
+    let grid = Grid::from_robots_input(input, day14::SIZE)
+        .map_err(|err| format!("Invalid robots: {err:?}"))?;
+    let (grid, seconds_passed) = day14::part2_with_budget(grid, &limits.budget())
+        .map_err(|err| err.to_string())?
+        .ok_or("The robots never draw a tree")?;
+    let html = html::grid(&grid.to_string(), false, |c| {
+        c.is_ascii_digit().then(|| "robot".to_owned())
+    });
+
+    Ok(Figure::new(
+        format!("Christmas tree after {seconds_passed} seconds"),
+        html,
+    ))
+}
+
+/// The doubled warehouse after every move.
+fn warehouse(input: &str) -> Result<Figure, String> {
+    let input: Input = input
+        .parse()
+        .map_err(|err| format!("Invalid warehouse: {err:?}"))?;
+    let map = day15::final_double_map(input);
+    let html = html::grid(&map.to_string(), map.0.len() < 40, |c| match c {
+        '#' => Some("wall".to_owned()),
+        '[' | ']' => Some("box".to_owned()),
+        '@' => Some("player".to_owned()),
+        _ => None,
+    });
+
+    Ok(Figure::new("Final warehouse (part 2)", html))
+}
+
+/// Every tile on at least one of the best paths through the maze.
+fn best_path(input: &str) -> Result<Figure, String> {
+    let map: Map = input
+        .parse()
+        .map_err(|err| format!("Invalid maze: {err:?}"))?;
+    let tiles = day16::best_path_tiles(&map);
+    let html = html::grid(&tiles.to_string(), tiles.0.len() < 40, |c| match c {
+        '#' => Some("wall".to_owned()),
+        'O' => Some("tile".to_owned()),
+        _ => None,
+    });
+
+    Ok(Figure::new("Best path tiles", html))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::for_day;
+    use crate::{days::day15::input::INPUT_EXAMPLE, runner::Limits};
+
+    #[test]
+    fn garden_colours() {
+        let figures = for_day(12, "AAB\nACB", Limits::default()).unwrap();
+        assert_eq!(
+            "<pre class=\"grid large\"><span class=\"plant-A\">AA</span><span class=\"plant-B\">B</span>\n<span class=\"plant-A\">A</span><span class=\"plant-C\">C</span><span class=\"plant-B\">B</span></pre>",
+            figures[0].html
+        );
+        assert_eq!(
+            ".plant-A { background: hsl(0, 65%, 72%); }
+.plant-B { background: hsl(137, 65%, 72%); }
+.plant-C { background: hsl(274, 65%, 72%); }
+",
+            figures[0].style
+        );
+    }
+
+    #[test]
+    fn warehouse() {
+        let figures = for_day(15, INPUT_EXAMPLE, Limits::default()).unwrap();
+        assert!(figures[0].html.contains("<span class=\"player\">@</span>"));
+        assert!(for_day(3, "", Limits::default()).unwrap().is_empty());
+    }
+}
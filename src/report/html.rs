@@ -0,0 +1,141 @@
+//! Just enough HTML to build the report pages by hand.
+
+use std::fmt::Write;
+
+/// Styles shared by every page. Pages are self-contained, so this is inlined into each one.
+pub const STYLE: &str = "body { font-family: system-ui, sans-serif; margin: 2rem auto; max-width: 72rem; padding: 0 1rem; color: #222; }
+nav { margin-bottom: 1rem; }
+nav a { margin-right: 1rem; }
+table { border-collapse: collapse; margin-bottom: 1.5rem; }
+th, td { border: 1px solid #ccc; padding: 0.25rem 0.75rem; text-align: left; }
+th { background: #f3f3f3; }
+td.num { text-align: right; font-variant-numeric: tabular-nums; }
+.pass { color: #1a7f37; }
+.fail { color: #cf222e; }
+.muted { color: #777; }
+pre.grid { font-size: 8px; line-height: 1; background: #fafafa; border: 1px solid #ddd; padding: 0.5rem; overflow-x: auto; }
+pre.grid.large { font-size: 12px; }
+.robot { background: #2da44e; color: #fff; }
+.wall { background: #57606a; color: #57606a; }
+.box { background: #d4a72c; }
+.player { background: #cf222e; color: #fff; }
+.tile { background: #0969da; color: #fff; }";
+
+pub fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Renders a text grid as a `<pre>` block. Runs of characters on a row that share a class
+/// are wrapped in a single span, which keeps large grids small.
+pub fn grid(text: &str, large: bool, class: impl Fn(char) -> Option<String>) -> String {
+    let mut html = match large {
+        true => String::from("<pre class=\"grid large\">"),
+        false => String::from("<pre class=\"grid\">"),
+    };
+
+    for (i, line) in text.lines().enumerate() {
+        if i > 0 {
+            html.push('\n');
+        }
+
+        let mut chars = line.chars().peekable();
+        while let Some(c) = chars.next() {
+            let mut run = String::from(c);
+            let run_class = class(c);
+            while let Some(&next) = chars.peek() {
+                if class(next) != run_class {
+                    break;
+                }
+                run.push(next);
+                chars.next();
+            }
+
+            match run_class {
+                Some(run_class) => {
+                    let _ = write!(html, "<span class=\"{run_class}\">{}</span>", escape(&run));
+                }
+                None => html.push_str(&escape(&run)),
+            }
+        }
+    }
+
+    html.push_str("</pre>");
+    html
+}
+
+/// A table with `headers`; each cell is already HTML.
+pub fn table(headers: &[&str], rows: &[Vec<String>]) -> String {
+    let mut html = String::from("<table>\n<tr>");
+    for header in headers {
+        let _ = write!(html, "<th>{}</th>", escape(header));
+    }
+    html.push_str("</tr>\n");
+
+    for row in rows {
+        html.push_str("<tr>");
+        for cell in row {
+            html.push_str(cell);
+        }
+        html.push_str("</tr>\n");
+    }
+
+    html.push_str("</table>\n");
+    html
+}
+
+pub fn td(text: &str) -> String {
+    format!("<td>{}</td>", escape(text))
+}
+
+pub fn td_class(class: &str, text: &str) -> String {
+    format!("<td class=\"{class}\">{}</td>", escape(text))
+}
+
+pub fn page(title: &str, style: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>
+<html lang=\"en\">
+<head>
+<meta charset=\"utf-8\">
+<title>{}</title>
+<style>
+{STYLE}
+{style}
+</style>
+</head>
+<body>
+{body}</body>
+</html>
+",
+        escape(title)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{escape, grid};
+
+    #[test]
+    fn escapes() {
+        assert_eq!("&lt;b&gt; &amp; &quot;", escape("<b> & \""));
+    }
+
+    #[test]
+    fn grid_runs() {
+        let class = |c: char| (c == '#').then(|| "wall".to_owned());
+        assert_eq!(
+            "<pre class=\"grid\"><span class=\"wall\">##</span>.&lt;\n.<span class=\"wall\">#</span></pre>",
+            grid("##.<\n.#", false, class)
+        );
+    }
+}
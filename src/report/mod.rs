@@ -0,0 +1,272 @@
+//! Static HTML report: an index plus one self-contained page per day.
+//!
+//! Pages use inline CSS only, so the output directory can be opened straight from disk.
+
+use crate::{
+    days::Day,
+    fixtures::{self, Check},
+    memory::AllocStats,
+    normalize::normalize,
+    runner::{self, Limits, Outcome, ParseResult, PartResult},
+};
+use figures::Figure;
+use std::{path::Path, time::Duration};
+
+pub mod figures;
+pub mod html;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayReport {
+    pub number: u8,
+    pub parse: ParseResult,
+    pub parts: Vec<(u8, PartResult)>,
+    pub examples: Vec<Check>,
+    pub figures: Vec<Figure>,
+    /// Why a figure could not be drawn, shown in place of the figures.
+    pub figure_error: Option<String>,
+}
+
+impl DayReport {
+    /// Runs every part of `day` on `input` and against its fixtures.
+    pub fn collect(day: &Day, input: &str, limits: Limits) -> Result<DayReport, String> {
+        let parse = runner::run_parse(day.parse, input);
+        let parts = match parse.result {
+            Ok(()) => day
+                .parts()
+                .map(|(n, part)| (n, runner::run_part(part, input, limits)))
+                .collect(),
+            Err(_) => Vec::new(),
+        };
+
+        let mut examples = Vec::new();
+        for fixture in fixtures::load(day.number)? {
+            examples.extend(fixtures::check(day, &fixture, limits));
+        }
+
+        let (figures, figure_error) = match parse.result {
+            Ok(()) => match figures::for_day(day.number, &normalize(input), limits) {
+                Ok(figures) => (figures, None),
+                Err(err) => (Vec::new(), Some(err)),
+            },
+            Err(_) => (Vec::new(), None),
+        };
+
+        Ok(DayReport {
+            number: day.number,
+            parse,
+            parts,
+            examples,
+            figures,
+            figure_error,
+        })
+    }
+
+    pub fn examples_passed(&self) -> usize {
+        self.examples.iter().filter(|check| check.passed()).count()
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.parse.elapsed
+            + self
+                .parts
+                .iter()
+                .map(|(_, part)| part.elapsed)
+                .sum::<Duration>()
+    }
+}
+
+fn page_name(day: u8) -> String {
+    format!("day{day:02}.html")
+}
+
+/// Writes `index.html` and a page per day into `out`, creating it if needed.
+pub fn write(out: &Path, reports: &[DayReport]) -> Result<(), String> {
+    std::fs::create_dir_all(out)
+        .map_err(|err| format!("Could not create {}: {err}", out.display()))?;
+
+    let mut pages = vec![("index.html".to_owned(), index(reports))];
+    for (i, report) in reports.iter().enumerate() {
+        let previous = i.checked_sub(1).map(|i| reports[i].number);
+        let next = reports.get(i + 1).map(|report| report.number);
+        pages.push((page_name(report.number), day_page(report, previous, next)));
+    }
+
+    for (name, page) in pages {
+        let path = out.join(name);
+        std::fs::write(&path, page)
+            .map_err(|err| format!("Could not write {}: {err}", path.display()))?;
+    }
+
+    Ok(())
+}
+
+fn index(reports: &[DayReport]) -> String {
+    let rows: Vec<Vec<String>> = reports
+        .iter()
+        .map(|report| {
+            let answer = |n: u8| {
+                report
+                    .parts
+                    .iter()
+                    .find(|(part, _)| *part == n)
+                    .map_or(html::td_class("muted", "-"), |(_, result)| {
+                        outcome_cell(&result.outcome)
+                    })
+            };
+
+            vec![
+                format!(
+                    "<td><a href=\"{}\">Day {}</a></td>",
+                    page_name(report.number),
+                    report.number
+                ),
+                answer(1),
+                answer(2),
+                html::td_class("num", &format!("{:?}", report.elapsed())),
+                examples_cell(report),
+            ]
+        })
+        .collect();
+
+    let body = format!(
+        "<h1>Advent of Code 2024</h1>\n{}",
+        html::table(&["Day", "Part 1", "Part 2", "Time", "Examples"], &rows)
+    );
+    html::page("Advent of Code 2024", "", &body)
+}
+
+fn day_page(report: &DayReport, previous: Option<u8>, next: Option<u8>) -> String {
+    let mut body = String::from("<nav><a href=\"index.html\">All days</a>");
+    if let Some(previous) = previous {
+        body.push_str(&format!(
+            "<a href=\"{}\">&larr; Day {previous}</a>",
+            page_name(previous)
+        ));
+    }
+    if let Some(next) = next {
+        body.push_str(&format!(
+            "<a href=\"{}\">Day {next} &rarr;</a>",
+            page_name(next)
+        ));
+    }
+    body.push_str("</nav>\n");
+    body.push_str(&format!("<h1>Day {}</h1>\n", report.number));
+
+    body.push_str("<h2>Answers</h2>\n");
+    let mut rows = vec![vec![
+        html::td("Parse"),
+        match &report.parse.result {
+            Ok(()) => html::td_class("pass", "ok"),
+            Err(err) => html::td_class("fail", err),
+        },
+        html::td_class("num", &format!("{:?}", report.parse.elapsed)),
+        allocations_cell(report.parse.allocations),
+    ]];
+    for (n, result) in &report.parts {
+        rows.push(vec![
+            html::td(&format!("Part {n}")),
+            outcome_cell(&result.outcome),
+            html::td_class("num", &format!("{:?}", result.elapsed)),
+            allocations_cell(result.allocations),
+        ]);
+    }
+    body.push_str(&html::table(&["", "Answer", "Time", "Allocations"], &rows));
+    body.push_str(
+        "<p class=\"muted\">Parts parse the input themselves, so their time and allocations include parsing it.</p>\n",
+    );
+
+    body.push_str("<h2>Examples</h2>\n");
+    match report.examples.is_empty() {
+        true => body.push_str("<p class=\"muted\">No example fixtures.</p>\n"),
+        false => {
+            let rows: Vec<Vec<String>> = report
+                .examples
+                .iter()
+                .map(|check| {
+                    vec![
+                        html::td(&check.fixture),
+                        html::td(&format!("Part {}", check.part)),
+                        html::td(&check.expected),
+                        html::td(&check.result.outcome.to_string()),
+                        match check.passed() {
+                            true => html::td_class("pass", "pass"),
+                            false => html::td_class("fail", "fail"),
+                        },
+                    ]
+                })
+                .collect();
+            body.push_str(&html::table(
+                &["Fixture", "Part", "Expected", "Got", "Status"],
+                &rows,
+            ));
+        }
+    }
+
+    let mut style = String::new();
+    for figure in &report.figures {
+        body.push_str(&format!(
+            "<h2>{}</h2>\n{}\n",
+            html::escape(&figure.title),
+            figure.html
+        ));
+        style.push_str(&figure.style);
+    }
+    if let Some(err) = &report.figure_error {
+        body.push_str(&format!(
+            "<p class=\"fail\">Could not draw figure: {}</p>\n",
+            html::escape(err)
+        ));
+    }
+
+    html::page(&format!("Day {}", report.number), &style, &body)
+}
+
+fn outcome_cell(outcome: &Outcome) -> String {
+    match outcome {
+        Outcome::Answer(answer) => html::td(answer),
+        outcome => html::td_class("fail", &outcome.to_string()),
+    }
+}
+
+fn allocations_cell(stats: Option<AllocStats>) -> String {
+    match stats {
+        Some(stats) => html::td_class("num", &stats.to_string()),
+        None => html::td_class("muted", "not counted"),
+    }
+}
+
+fn examples_cell(report: &DayReport) -> String {
+    let passed = report.examples_passed();
+    let total = report.examples.len();
+    match (total, passed == total) {
+        (0, _) => html::td_class("muted", "none"),
+        (_, true) => html::td_class("pass", &format!("{passed}/{total} passed")),
+        (_, false) => html::td_class("fail", &format!("{passed}/{total} passed")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{write, DayReport};
+    use crate::{days, fixtures, runner::Limits};
+
+    #[test]
+    fn writes_pages() {
+        let day = days::get(15).unwrap();
+        let input = fixtures::input_or_example(day).unwrap();
+        let report = DayReport::collect(day, &input, Limits::default()).unwrap();
+        assert_eq!(report.examples.len(), report.examples_passed());
+
+        let out = std::env::temp_dir().join(format!("aoc-report-{}", std::process::id()));
+        write(&out, &[report]).unwrap();
+
+        let index = std::fs::read_to_string(out.join("index.html")).unwrap();
+        let page = std::fs::read_to_string(out.join("day15.html")).unwrap();
+        std::fs::remove_dir_all(&out).unwrap();
+
+        assert!(index.contains("<a href=\"day15.html\">Day 15</a>"));
+        assert!(page.contains("<h2>Final warehouse (part 2)</h2>"));
+        assert!(page.contains("<td class=\"pass\">pass</td>"));
+        assert!(!page.contains("<script"));
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{
+    budget::{Budget, BudgetExceeded},
+    days::{Parse, Part, PartError},
+    memory::{self, AllocStats},
+    normalize::normalize,
+};
+use std::time::{Duration, Instant};
+
+/// Limits every part gets when it is run through [`run_part`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Limits {
+    pub time: Option<Duration>,
+    pub steps: Option<u64>,
+}
+
+impl Limits {
+    pub fn budget(&self) -> Budget {
+        Budget::new(self.time, self.steps)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    Answer(String),
+    /// The day's parser rejects the input, with its error.
+    InvalidInput(String),
+    BudgetExceeded(BudgetExceeded),
+    /// The part panicked, with the panic's message. Only [`crate::batch`] catches panics,
+    /// everywhere else they unwind through [`run_part`].
+    Panicked(String),
+}
+
+impl std::fmt::Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Answer(answer) => f.write_str(answer),
+            Outcome::InvalidInput(err) => write!(f, "invalid input: {err}"),
+            Outcome::BudgetExceeded(exceeded) => write!(f, "{exceeded}"),
+            Outcome::Panicked(message) => write!(f, "panicked: {message}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartResult {
+    pub outcome: Outcome,
+    /// Parts parse the input themselves, so this and `allocations` include that parse.
+    pub elapsed: Duration,
+    /// `None` unless allocation counting is enabled, see [`memory::enable`].
+    pub allocations: Option<AllocStats>,
+}
+
+/// Runs `part` on the normalized `input`, see [`normalize`].
+/// The message a panic was raised with.
+pub fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_owned())
+}
+
+pub fn run_part(part: Part, input: &str, limits: Limits) -> PartResult {
+    let input = normalize(input);
+    let budget = limits.budget();
+    let start = Instant::now();
+    let (outcome, allocations) = memory::measure(|| match part(&input, &budget) {
+        Ok(answer) => Outcome::Answer(answer),
+        Err(PartError::InvalidInput(err)) => Outcome::InvalidInput(err),
+        Err(PartError::BudgetExceeded(exceeded)) => Outcome::BudgetExceeded(exceeded),
+    });
+
+    PartResult {
+        outcome,
+        elapsed: start.elapsed(),
+        allocations,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseResult {
+    pub result: Result<(), String>,
+    pub elapsed: Duration,
+    pub allocations: Option<AllocStats>,
+}
+
+pub fn run_parse(parse: Parse, input: &str) -> ParseResult {
+    let input = normalize(input);
+    let start = Instant::now();
+    let (result, allocations) = memory::measure(|| parse(&input));
+
+    ParseResult {
+        result,
+        elapsed: start.elapsed(),
+        allocations,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{run_parse, run_part, Limits, Outcome};
+    use crate::days;
+    use std::time::Duration;
+
+    #[test]
+    fn answer() {
+        let day = days::get(17).unwrap();
+        let result = run_part(day.part1, crate::days::day17::EXAMPLE, Limits::default());
+        assert_eq!(
+            Outcome::Answer("4,6,3,5,6,3,5,2,1,0".to_owned()),
+            result.outcome
+        );
+    }
+
+    #[test]
+    fn budget_exceeded() {
+        let day = days::get(17).unwrap();
+        let looping = "Register A: 1\n\nProgram: 3,0";
+        let limits = Limits {
+            time: Some(Duration::from_secs(10)),
+            steps: Some(100),
+        };
+
+        let Outcome::BudgetExceeded(exceeded) = run_part(day.part1, looping, limits).outcome else {
+            panic!("Program that never halts should exceed its budget");
+        };
+        assert_eq!(101, exceeded.steps);
+        assert!(exceeded.progress.contains("instruction pointer at 0"));
+    }
+
+    #[test]
+    fn invalid_input() {
+        let day = days::get(17).unwrap();
+        let Outcome::InvalidInput(err) =
+            run_part(day.part1, "Register A: x", Limits::default()).outcome
+        else {
+            panic!("A part should reject what the parser rejects");
+        };
+        assert!(!err.is_empty());
+    }
+
+    #[test]
+    fn parse() {
+        let day = days::get(17).unwrap();
+        assert_eq!(
+            Ok(()),
+            run_parse(day.parse, crate::days::day17::EXAMPLE).result
+        );
+        assert!(run_parse(day.parse, "Register A: x").result.is_err());
+    }
+}
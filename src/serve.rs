@@ -0,0 +1,574 @@
+//! A local HTTP/1.1 API over the registered days, for tools that want answers without running
+//! the binary.
+//!
+//! - `GET /days` lists the days and their parts.
+//! - `POST /2024/day/N/part/P` solves the request body as the input and answers with
+//!   `{"day", "part", "answer", "elapsed"}`, `elapsed` in seconds.
+//!
+//! Errors are `{"error": "..."}` with a fitting status: 422 when the day's parser rejects the
+//! input, 503 when the part runs out of its budget or the server is full, 413 for bodies over
+//! the size limit.
+//!
+//! Only the solving route runs under [`Config::limits`]. The budget is cooperative like
+//! `run --time-limit`: it stops the parts that tick a [`Budget`](crate::budget::Budget), which
+//! are both parts of day 6 and day 15, day 14 part 2 and day 17 part 1. Every part runs on a
+//! worker thread, and a part still running [`GIVE_UP_GRACE`] past the time limit gets a 503
+//! in its place; the part can't be stopped and runs on, but its connection is closed.
+//!
+//! [`Config::max_concurrent`] caps how many connections are answered at once, on every route.
+//! Each gets one request and its own thread; connections beyond the cap get a 503 without
+//! being read.
+
+use crate::{
+    days::{self, Day, Part},
+    json::Value,
+    runner::{self, Limits, Outcome, PartResult},
+};
+use std::{
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{Shutdown, TcpListener, TcpStream},
+    panic::AssertUnwindSafe,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, RecvTimeoutError},
+        Arc,
+    },
+    time::Duration,
+};
+
+/// Request line and headers together.
+const MAX_HEAD: u64 = 8 * 1024;
+/// How long a client may take to send its request.
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+/// How long past the time limit a part may take before the server stops waiting for it,
+/// so parts that tick their budget can report how far they got.
+const GIVE_UP_GRACE: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone, Copy)]
+pub struct Config {
+    pub limits: Limits,
+    /// Largest accepted input, in bytes. Real inputs are well under 100 KiB.
+    pub max_body: usize,
+    /// Connections answered at once, see the [module docs](self).
+    pub max_concurrent: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            limits: Limits {
+                time: Some(Duration::from_secs(10)),
+                steps: None,
+            },
+            max_body: 1024 * 1024,
+            max_concurrent: std::thread::available_parallelism()
+                .map_or(4, std::num::NonZeroUsize::get),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub body: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: Value,
+}
+
+impl Response {
+    fn ok(body: Value) -> Response {
+        Response { status: 200, body }
+    }
+
+    fn error(status: u16, message: impl Into<String>) -> Response {
+        Response {
+            status,
+            body: Value::Object(vec![("error".to_owned(), message.into().into())]),
+        }
+    }
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        411 => "Length Required",
+        413 => "Content Too Large",
+        422 => "Unprocessable Content",
+        431 => "Request Header Fields Too Large",
+        503 => "Service Unavailable",
+        _ => "Internal Server Error",
+    }
+}
+
+/// Counts the connections being answered, up to a maximum.
+#[derive(Debug)]
+struct Slots {
+    busy: AtomicUsize,
+    max: usize,
+}
+
+impl Slots {
+    fn new(max: usize) -> Arc<Slots> {
+        Arc::new(Slots {
+            busy: AtomicUsize::new(0),
+            max,
+        })
+    }
+
+    /// A slot that is given back when dropped, or `None` when all are taken.
+    fn try_acquire(self: &Arc<Self>) -> Option<Slot> {
+        self.busy
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |busy| {
+                (busy < self.max).then_some(busy + 1)
+            })
+            .ok()?;
+        Some(Slot(Arc::clone(self)))
+    }
+}
+
+#[derive(Debug)]
+struct Slot(Arc<Slots>);
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        self.0.busy.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// Answers requests on `listener` until accepting fails, logging one line per request.
+pub fn serve(listener: TcpListener, config: Config) -> io::Result<()> {
+    let slots = Slots::new(config.max_concurrent);
+    for stream in listener.incoming() {
+        let stream = stream?;
+        let Some(slot) = slots.try_acquire() else {
+            let response = Response::error(
+                503,
+                format!(
+                    "already answering {} requests, try again later",
+                    config.max_concurrent
+                ),
+            );
+            println!("(busy) -> {}", response.status);
+            reject(&stream, &response, config.max_body);
+            continue;
+        };
+        std::thread::spawn(move || {
+            let _slot = slot;
+            let (line, response) = respond(&stream, config);
+            println!("{line} -> {}", response.status);
+            let _ = write_response(&stream, &response);
+        });
+    }
+    Ok(())
+}
+
+/// Answers without waiting for the request. Whatever already arrived is discarded first,
+/// since closing a socket with unread data resets the connection before the client sees
+/// the response.
+fn reject(mut stream: &TcpStream, response: &Response, max_body: usize) {
+    if stream.set_nonblocking(true).is_err() {
+        return;
+    }
+    let mut discarded = 0;
+    let mut buffer = [0; 4096];
+    while discarded <= MAX_HEAD as usize + max_body {
+        match stream.read(&mut buffer) {
+            Ok(0) | Err(_) => break,
+            Ok(read) => discarded += read,
+        }
+    }
+    let _ = write_response(stream, response);
+    let _ = stream.shutdown(Shutdown::Write);
+}
+
+fn respond(stream: &TcpStream, config: Config) -> (String, Response) {
+    let _ = stream.set_read_timeout(Some(READ_TIMEOUT));
+    let request = match read_request(BufReader::new(stream), config.max_body) {
+        Ok(request) => request,
+        Err(response) => return ("(unreadable request)".to_owned(), response),
+    };
+
+    let line = format!("{} {}", request.method, request.path);
+    let response = std::panic::catch_unwind(AssertUnwindSafe(|| handle(&request, config)))
+        .unwrap_or_else(|_| Response::error(500, "the solution panicked"));
+    (line, response)
+}
+
+/// Reads one request, answering with the error response when it is malformed or too large.
+pub fn read_request(reader: impl BufRead, max_body: usize) -> Result<Request, Response> {
+    let mut head = reader.take(MAX_HEAD);
+    let mut lines = Vec::new();
+    loop {
+        let mut line = String::new();
+        match head.read_line(&mut line) {
+            Ok(0) if head.limit() == 0 => {
+                return Err(Response::error(431, "request head is too large"))
+            }
+            Ok(0) => return Err(Response::error(400, "request ended early")),
+            Ok(_) if !line.ends_with('\n') && head.limit() == 0 => {
+                return Err(Response::error(431, "request head is too large"))
+            }
+            Ok(_) => {}
+            Err(err) => return Err(Response::error(400, format!("could not read: {err}"))),
+        }
+        let line = line.trim_end_matches(['\r', '\n']).to_owned();
+        if line.is_empty() {
+            break;
+        }
+        lines.push(line);
+    }
+
+    let mut request_line = lines.first().into_iter().flat_map(|l| l.split(' '));
+    let (Some(method), Some(target), Some(_version)) = (
+        request_line.next(),
+        request_line.next(),
+        request_line.next(),
+    ) else {
+        return Err(Response::error(400, "malformed request line"));
+    };
+    let path = target.split('?').next().unwrap_or_default().to_owned();
+    let method = method.to_owned();
+
+    let header = |name: &str| {
+        lines[1..].iter().find_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            key.trim().eq_ignore_ascii_case(name).then(|| value.trim())
+        })
+    };
+    if header("transfer-encoding").is_some() {
+        return Err(Response::error(411, "send the input with a Content-Length"));
+    }
+    let length = match header("content-length") {
+        Some(length) => length
+            .parse::<usize>()
+            .map_err(|_| Response::error(400, format!("invalid Content-Length: {length}")))?,
+        None if method == "POST" => {
+            return Err(Response::error(411, "send the input with a Content-Length"))
+        }
+        None => 0,
+    };
+    if length > max_body {
+        return Err(Response::error(
+            413,
+            format!("input is {length} bytes, the limit is {max_body}"),
+        ));
+    }
+
+    let mut body = vec![0; length];
+    head.into_inner()
+        .read_exact(&mut body)
+        .map_err(|err| Response::error(400, format!("could not read the body: {err}")))?;
+    Ok(Request { method, path, body })
+}
+
+fn write_response(mut stream: &TcpStream, response: &Response) -> io::Result<()> {
+    let body = response.body.to_string();
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        response.status,
+        reason(response.status),
+        body.len()
+    )?;
+    stream.flush()
+}
+
+/// Routes a request to its answer.
+pub fn handle(request: &Request, config: Config) -> Response {
+    let segments: Vec<&str> = request.path.trim_start_matches('/').split('/').collect();
+    match (request.method.as_str(), segments.as_slice()) {
+        ("GET", ["days"]) => Response::ok(list_days()),
+        ("POST", ["2024", "day", day, "part", part]) => solve(day, part, &request.body, config),
+        (_, ["days"]) | (_, ["2024", "day", _, "part", _]) => Response::error(
+            405,
+            format!("{} is not allowed on {}", request.method, request.path),
+        ),
+        _ => Response::error(404, format!("no route for {}", request.path)),
+    }
+}
+
+fn list_days() -> Value {
+    let days = days::DAYS
+        .iter()
+        .map(|day| {
+            let parts = day
+                .parts()
+                .map(|(part, _)| u64::from(part).into())
+                .collect();
+            Value::Object(vec![
+                ("day".to_owned(), u64::from(day.number).into()),
+                ("parts".to_owned(), Value::Array(parts)),
+            ])
+        })
+        .collect();
+    Value::Object(vec![("days".to_owned(), Value::Array(days))])
+}
+
+fn solve(day: &str, part: &str, body: &[u8], config: Config) -> Response {
+    let Some(day) = day.parse().ok().and_then(days::get) else {
+        return Response::error(404, format!("day {day} is not solved"));
+    };
+    let Some(solution) = part
+        .parse()
+        .ok()
+        .and_then(|part: u8| day.parts().find(|(number, _)| *number == part))
+    else {
+        return Response::error(404, format!("day {} has no part {part}", day.number));
+    };
+    let Ok(input) = std::str::from_utf8(body) else {
+        return Response::error(400, "input is not UTF-8");
+    };
+
+    if let Err(err) = runner::run_parse(day.parse, input).result {
+        return Response::error(422, format!("invalid input for day {}: {err}", day.number));
+    }
+    let result = match run_worker(solution.1, input, config.limits) {
+        Ok(result) => result,
+        Err(response) => return response,
+    };
+    match result.outcome {
+        Outcome::Answer(answer) => {
+            Response::ok(answer_json(day, solution.0, answer, result.elapsed))
+        }
+        Outcome::InvalidInput(err) => {
+            Response::error(422, format!("invalid input for day {}: {err}", day.number))
+        }
+        Outcome::BudgetExceeded(exceeded) => Response::error(503, exceeded.to_string()),
+        Outcome::Panicked(_) => Response::error(500, "the solution panicked"),
+    }
+}
+
+/// Runs `part` on a thread of its own, waiting for it no longer than the time limit allows.
+fn run_worker(part: Part, input: &str, limits: Limits) -> Result<PartResult, Response> {
+    let (sender, receiver) = mpsc::channel();
+    let input = input.to_owned();
+    std::thread::spawn(move || {
+        let _ = sender.send(runner::run_part(part, &input, limits));
+    });
+
+    let received = match limits.time {
+        Some(time) => receiver.recv_timeout(time + GIVE_UP_GRACE),
+        None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+    };
+    received.map_err(|err| match err {
+        RecvTimeoutError::Timeout => Response::error(
+            503,
+            format!(
+                "gave up after {:?}, this part doesn't check its time limit",
+                limits.time.unwrap_or_default() + GIVE_UP_GRACE
+            ),
+        ),
+        RecvTimeoutError::Disconnected => Response::error(500, "the solution panicked"),
+    })
+}
+
+fn answer_json(day: &Day, part: u8, answer: String, elapsed: Duration) -> Value {
+    Value::Object(vec![
+        ("day".to_owned(), u64::from(day.number).into()),
+        ("part".to_owned(), u64::from(part).into()),
+        ("answer".to_owned(), answer.into()),
+        ("elapsed".to_owned(), Value::Number(elapsed.as_secs_f64())),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{handle, read_request, run_worker, serve, Config, Request, Response, Slots};
+    use crate::{
+        budget::Budget,
+        days::{day17, PartError},
+        json,
+        runner::Limits,
+    };
+    use std::{
+        io::{Read, Write},
+        net::{TcpListener, TcpStream},
+        time::{Duration, Instant},
+    };
+
+    fn post(path: &str, body: &str) -> Request {
+        Request {
+            method: "POST".to_owned(),
+            path: path.to_owned(),
+            body: body.as_bytes().to_vec(),
+        }
+    }
+
+    fn error(response: &Response) -> &str {
+        response.body.get("error").and_then(|e| e.as_str()).unwrap()
+    }
+
+    #[test]
+    fn answers() {
+        let response = handle(
+            &post("/2024/day/17/part/1", day17::EXAMPLE),
+            Config::default(),
+        );
+        assert_eq!(200, response.status);
+        assert_eq!(
+            Some("4,6,3,5,6,3,5,2,1,0"),
+            response.body.get("answer").and_then(|a| a.as_str())
+        );
+        assert_eq!(Some(17), response.body.get("day").and_then(|d| d.as_u64()));
+    }
+
+    #[test]
+    fn errors() {
+        let config = Config::default();
+        let status = |request: &Request| handle(request, config).status;
+
+        assert_eq!(404, status(&post("/2024/day/30/part/1", "")));
+        assert_eq!(404, status(&post("/2024/day/12/part/2", "")));
+        assert_eq!(404, status(&post("/2023/day/1/part/1", "")));
+        assert_eq!(422, status(&post("/2024/day/17/part/1", "Register A: x")));
+        assert_eq!(
+            405,
+            status(&Request {
+                method: "GET".to_owned(),
+                ..post("/2024/day/17/part/1", "")
+            })
+        );
+    }
+
+    #[test]
+    fn budget_exceeded() {
+        let config = Config {
+            limits: Limits {
+                time: None,
+                steps: Some(100),
+            },
+            ..Config::default()
+        };
+        let looping = "Register A: 1\n\nProgram: 3,0";
+        let response = handle(&post("/2024/day/17/part/1", looping), config);
+
+        assert_eq!(503, response.status);
+        assert!(error(&response).starts_with("budget exceeded"));
+    }
+
+    #[test]
+    fn gives_up() {
+        fn stuck(_: &str, _: &Budget) -> Result<String, PartError> {
+            std::thread::sleep(Duration::from_secs(60));
+            Ok(String::new())
+        }
+        fn panics(_: &str, _: &Budget) -> Result<String, PartError> {
+            panic!("no answer")
+        }
+
+        let limits = Limits {
+            time: Some(Duration::ZERO),
+            steps: None,
+        };
+        let start = Instant::now();
+        let response = run_worker(stuck, "", limits).unwrap_err();
+        assert_eq!(503, response.status);
+        assert!(error(&response).starts_with("gave up after 1s"));
+        assert!(start.elapsed() < Duration::from_secs(10));
+
+        assert_eq!(500, run_worker(panics, "", limits).unwrap_err().status);
+    }
+
+    #[test]
+    fn size_limits() {
+        let request = "POST /2024/day/1/part/1 HTTP/1.1\r\nContent-Length: 11\r\n\r\n3   4\n4   3";
+        let response = read_request(request.as_bytes(), 10).unwrap_err();
+        assert_eq!(413, response.status);
+        assert_eq!(
+            "3   4\n4   3".as_bytes(),
+            read_request(request.as_bytes(), 11).unwrap().body
+        );
+
+        let huge_header = format!(
+            "GET /days HTTP/1.1\r\nX-Padding: {}\r\n\r\n",
+            "a".repeat(9000)
+        );
+        assert_eq!(
+            431,
+            read_request(huge_header.as_bytes(), 10).unwrap_err().status
+        );
+
+        let no_length = "POST /2024/day/1/part/1 HTTP/1.1\r\n\r\n";
+        assert_eq!(
+            411,
+            read_request(no_length.as_bytes(), 10).unwrap_err().status
+        );
+    }
+
+    fn exchange(address: &str, request: &str) -> (String, json::Value) {
+        let mut stream = TcpStream::connect(address).unwrap();
+        stream.write_all(request.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        let status = head.lines().next().unwrap().to_owned();
+        (status, json::parse(body).unwrap())
+    }
+
+    #[test]
+    fn over_localhost() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        std::thread::spawn(move || serve(listener, Config::default()));
+
+        let (status, body) = exchange(&address, "GET /days HTTP/1.1\r\nHost: test\r\n\r\n");
+        assert_eq!("HTTP/1.1 200 OK", status);
+        let days = body.get("days").and_then(|days| days.as_array()).unwrap();
+        assert_eq!(Some(1), days[0].get("day").and_then(|d| d.as_u64()));
+
+        let input = day17::EXAMPLE;
+        let (status, body) = exchange(
+            &address,
+            &format!(
+                "POST /2024/day/17/part/1 HTTP/1.1\r\nContent-Length: {}\r\n\r\n{input}",
+                input.len()
+            ),
+        );
+        assert_eq!("HTTP/1.1 200 OK", status);
+        assert_eq!(
+            Some("4,6,3,5,6,3,5,2,1,0"),
+            body.get("answer").and_then(|a| a.as_str())
+        );
+    }
+
+    #[test]
+    fn slots() {
+        let slots = Slots::new(2);
+        let first = slots.try_acquire().unwrap();
+        let _second = slots.try_acquire().unwrap();
+        assert!(slots.try_acquire().is_none());
+        drop(first);
+        assert!(slots.try_acquire().is_some());
+    }
+
+    #[test]
+    fn full_server() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let config = Config {
+            max_concurrent: 1,
+            ..Config::default()
+        };
+        std::thread::spawn(move || serve(listener, config));
+
+        // Holds the only slot while its request is still being read.
+        let idle = TcpStream::connect(&address).unwrap();
+        let (status, body) = exchange(&address, "GET /days HTTP/1.1\r\nHost: test\r\n\r\n");
+        assert_eq!("HTTP/1.1 503 Service Unavailable", status);
+        assert!(body
+            .get("error")
+            .and_then(|e| e.as_str())
+            .unwrap()
+            .starts_with("already answering 1 requests"));
+        drop(idle);
+    }
+}
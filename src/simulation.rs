@@ -0,0 +1,340 @@
+//! State machines that advance one tick at a time, with generic drivers and loop detection.
+//!
+//! Every driver ticks the [`Budget`] once per simulated step, so a simulation that never
+//! reaches what it is looking for stops with [`BudgetExceeded`] instead of hanging.
+
+use crate::budget::{Budget, BudgetExceeded};
+
+pub trait Simulation: Clone {
+    /// Identifies a state for loop detection. States with equal keys must evolve identically.
+    type Key: Eq;
+
+    fn key(&self) -> Self::Key;
+
+    /// Advances the state by one tick. Never called on a terminal state.
+    fn step(&mut self);
+
+    /// Whether the simulation has stopped, e.g. the guard walked off the map.
+    fn is_terminal(&self) -> bool {
+        false
+    }
+
+    /// Steps at most `n` times, stopping early on a terminal state. Returns the steps taken.
+    fn run_n(&mut self, n: u64, budget: &Budget) -> Result<u64, BudgetExceeded> {
+        for taken in 0..n {
+            if self.is_terminal() {
+                return Ok(taken);
+            }
+            budget.tick()?;
+            self.step();
+        }
+        Ok(n)
+    }
+
+    /// Steps until `pred` holds for the current state.
+    ///
+    /// Gives up once the states start repeating, since `pred` has then seen every state
+    /// the simulation will ever reach. Detection follows Brent: a saved state teleports
+    /// forward at power-of-two distances, so no history is kept.
+    fn run_until(
+        &mut self,
+        mut pred: impl FnMut(&Self) -> bool,
+        budget: &Budget,
+    ) -> Result<RunEnd, BudgetExceeded> {
+        let mut saved = self.key();
+        let mut power = 1;
+        let mut length = 0;
+        let mut steps = 0;
+
+        loop {
+            if pred(self) {
+                return Ok(RunEnd::Matched { steps });
+            }
+            if self.is_terminal() {
+                return Ok(RunEnd::Terminal { steps });
+            }
+
+            budget.tick()?;
+            self.step();
+            steps += 1;
+            length += 1;
+
+            let key = self.key();
+            if key == saved {
+                return Ok(RunEnd::Cycle { steps, length });
+            }
+            if length == power {
+                saved = key;
+                power *= 2;
+                length = 0;
+            }
+        }
+    }
+
+    /// Leaves the state as it will be after `n` more ticks, or at its terminal state if it
+    /// stops earlier. Looks for a loop along the way, as [`Simulation::run_until`] does, and
+    /// skips the remaining laps arithmetically once one turns up, so it never takes more
+    /// than `n` ticks.
+    fn skip_to(&mut self, n: u64, budget: &Budget) -> Result<(), BudgetExceeded> {
+        let mut seen = 0;
+        let end = self.run_until(
+            |_| {
+                seen += 1;
+                seen > n
+            },
+            budget,
+        )?;
+
+        match end {
+            RunEnd::Cycle { steps, length } => self.run_n((n - steps) % length, budget).map(drop),
+            RunEnd::Matched { .. } | RunEnd::Terminal { .. } => Ok(()),
+        }
+    }
+}
+
+/// How [`Simulation::run_until`] stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunEnd {
+    Matched {
+        steps: u64,
+    },
+    Terminal {
+        steps: u64,
+    },
+    /// The state after `steps` ticks was already seen `length` ticks earlier.
+    Cycle {
+        steps: u64,
+        length: u64,
+    },
+}
+
+/// A loop in a simulation: the state after `start` ticks repeats every `length` ticks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: u64,
+    pub length: u64,
+}
+
+/// Brent's loop detection from `initial`, or `None` when the simulation terminates.
+pub fn brent<S: Simulation>(initial: &S, budget: &Budget) -> Result<Option<Cycle>, BudgetExceeded> {
+    let mut tortoise = initial.key();
+    let mut hare = initial.clone();
+    let mut power = 1;
+    let mut length = 0;
+
+    loop {
+        if !advance(&mut hare, budget)? {
+            return Ok(None);
+        }
+        length += 1;
+
+        let key = hare.key();
+        if key == tortoise {
+            break;
+        }
+        if length == power {
+            tortoise = key;
+            power *= 2;
+            length = 0;
+        }
+    }
+
+    // Start a second runner `length` ticks ahead, then walk both until they meet.
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    hare.run_n(length, budget)?;
+
+    let mut start = 0;
+    while tortoise.key() != hare.key() {
+        advance(&mut tortoise, budget)?;
+        advance(&mut hare, budget)?;
+        start += 1;
+    }
+
+    Ok(Some(Cycle { start, length }))
+}
+
+/// Floyd's loop detection from `initial`, or `None` when the simulation terminates.
+pub fn floyd<S: Simulation>(initial: &S, budget: &Budget) -> Result<Option<Cycle>, BudgetExceeded> {
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+
+    loop {
+        if !advance(&mut hare, budget)? || !advance(&mut hare, budget)? {
+            return Ok(None);
+        }
+        advance(&mut tortoise, budget)?;
+        if tortoise.key() == hare.key() {
+            break;
+        }
+    }
+
+    let mut tortoise = initial.clone();
+    let mut start = 0;
+    while tortoise.key() != hare.key() {
+        advance(&mut tortoise, budget)?;
+        advance(&mut hare, budget)?;
+        start += 1;
+    }
+
+    let key = tortoise.key();
+    let mut length = 1;
+    advance(&mut hare, budget)?;
+    while hare.key() != key {
+        advance(&mut hare, budget)?;
+        length += 1;
+    }
+
+    Ok(Some(Cycle { start, length }))
+}
+
+/// Steps once unless the state is terminal. Returns whether a step was taken.
+fn advance<S: Simulation>(state: &mut S, budget: &Budget) -> Result<bool, BudgetExceeded> {
+    if state.is_terminal() {
+        return Ok(false);
+    }
+    budget.tick()?;
+    state.step();
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{brent, floyd, Cycle, RunEnd, Simulation};
+    use crate::budget::Budget;
+
+    /// x -> x² + 1 mod m, a classic rho-shaped sequence.
+    #[derive(Debug, Clone)]
+    struct Rho {
+        x: u64,
+        m: u64,
+    }
+
+    impl Simulation for Rho {
+        type Key = u64;
+
+        fn key(&self) -> u64 {
+            self.x
+        }
+
+        fn step(&mut self) {
+            self.x = (self.x * self.x + 1) % self.m;
+        }
+    }
+
+    /// Counts down to zero and stops.
+    #[derive(Debug, Clone)]
+    struct Countdown(u64);
+
+    impl Simulation for Countdown {
+        type Key = u64;
+
+        fn key(&self) -> u64 {
+            self.0
+        }
+
+        fn step(&mut self) {
+            self.0 -= 1;
+        }
+
+        fn is_terminal(&self) -> bool {
+            self.0 == 0
+        }
+    }
+
+    fn naive(initial: &Rho) -> Cycle {
+        let mut seen = vec![initial.x];
+        let mut state = initial.clone();
+        loop {
+            state.step();
+            if let Some(start) = seen.iter().position(|&x| x == state.x) {
+                return Cycle {
+                    start: start as u64,
+                    length: (seen.len() - start) as u64,
+                };
+            }
+            seen.push(state.x);
+        }
+    }
+
+    #[test]
+    fn detectors_agree() {
+        let budget = Budget::unlimited();
+        for m in [7, 100, 255, 1009] {
+            for x in 0..20 {
+                let rho = Rho { x, m };
+                let expected = naive(&rho);
+                assert_eq!(Some(expected), brent(&rho, &budget).unwrap(), "{rho:?}");
+                assert_eq!(Some(expected), floyd(&rho, &budget).unwrap(), "{rho:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn terminal_has_no_cycle() {
+        let budget = Budget::unlimited();
+        assert_eq!(None, brent(&Countdown(5), &budget).unwrap());
+        assert_eq!(None, floyd(&Countdown(5), &budget).unwrap());
+
+        let mut countdown = Countdown(5);
+        assert_eq!(5, countdown.run_n(10, &budget).unwrap());
+        assert!(countdown.is_terminal());
+    }
+
+    #[test]
+    fn run_until() {
+        let budget = Budget::unlimited();
+        let mut rho = Rho { x: 3, m: 100 };
+        assert_eq!(
+            RunEnd::Matched { steps: 2 },
+            rho.run_until(|rho| rho.x == 1, &budget).unwrap()
+        );
+
+        let mut rho = Rho { x: 3, m: 100 };
+        let Cycle { start, length } = naive(&rho);
+        let RunEnd::Cycle {
+            steps,
+            length: found,
+        } = rho.run_until(|rho| rho.x == 99, &budget).unwrap()
+        else {
+            panic!("x never reaches 99");
+        };
+        assert_eq!(length, found);
+        assert!(steps <= 2 * (start + length));
+
+        let mut countdown = Countdown(3);
+        assert_eq!(
+            RunEnd::Terminal { steps: 3 },
+            countdown.run_until(|_| false, &budget).unwrap()
+        );
+    }
+
+    #[test]
+    fn skip_to() {
+        let budget = Budget::unlimited();
+        let mut skipped = Rho { x: 3, m: 1009 };
+        skipped.skip_to(1_000_000_000_000, &budget).unwrap();
+        assert!(budget.steps() < 1_000);
+
+        let mut stepped = Rho { x: 3, m: 1009 };
+        let Cycle { start, length } = naive(&stepped);
+        stepped
+            .run_n(start + (1_000_000_000_000 - start) % length, &budget)
+            .unwrap();
+        assert_eq!(stepped.x, skipped.x);
+
+        // Before the loop shows up, skipping is plain stepping.
+        for n in 0..=2 * (start + length) {
+            let mut skipped = Rho { x: 3, m: 1009 };
+            let budget = Budget::new(None, Some(n));
+            skipped.skip_to(n, &budget).unwrap();
+            let mut stepped = Rho { x: 3, m: 1009 };
+            stepped.run_n(n, &Budget::unlimited()).unwrap();
+            assert_eq!(stepped.x, skipped.x, "{n} ticks");
+        }
+
+        let mut countdown = Countdown(3);
+        countdown.skip_to(10, &Budget::unlimited()).unwrap();
+        assert!(countdown.is_terminal());
+    }
+}
@@ -0,0 +1,81 @@
+//! File-backed snapshots of `Display` renderings.
+//!
+//! A snapshot is written to `snapshots/<name>.snap` the first time it is asserted. Later runs
+//! compare against the stored file and fail with a side-by-side grid diff on mismatch.
+//! Run the tests with `UPDATE_SNAPSHOTS=1` to accept new renderings.
+
+use crate::diff;
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+pub const UPDATE_VAR: &str = "UPDATE_SNAPSHOTS";
+
+pub fn snapshot_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("snapshots")
+        .join(format!("{name}.snap"))
+}
+
+#[track_caller]
+pub fn assert_snapshot(name: &str, actual: impl Display) {
+    let update = std::env::var_os(UPDATE_VAR).is_some_and(|v| v != "0");
+    check(name, &actual.to_string(), update);
+}
+
+#[track_caller]
+fn check(name: &str, actual: &str, update: bool) {
+    let path = snapshot_path(name);
+    let expected = match std::fs::read_to_string(&path) {
+        Ok(expected) if !update => expected,
+        _ => {
+            write_snapshot(&path, actual);
+            return;
+        }
+    };
+
+    if let Some(diff) = diff::grid_diff(&expected, actual, diff::use_color()) {
+        panic!(
+            "snapshot `{name}` does not match {}\n{diff}Set {UPDATE_VAR}=1 to accept the new rendering.",
+            path.display()
+        );
+    }
+}
+
+fn write_snapshot(path: &Path, rendering: &str) {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).unwrap();
+    }
+
+    std::fs::write(path, rendering).unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check, snapshot_path};
+
+    #[test]
+    fn mismatch_panics() {
+        let name = "snapshot_mismatch_panics";
+        check(name, "#.#\n...", true);
+
+        let result = std::panic::catch_unwind(|| check(name, "#O#\n...", false));
+        std::fs::remove_file(snapshot_path(name)).unwrap();
+
+        let message = *result.unwrap_err().downcast::<String>().unwrap();
+        assert!(message.contains("(x=1, y=0): expected '.', actual 'O'"));
+    }
+
+    #[test]
+    fn line_endings_count() {
+        let name = "snapshot_line_endings_count";
+        check(name, "#.#\n...\n", true);
+
+        let result = std::panic::catch_unwind(|| check(name, "#.#\n...", false));
+        std::fs::remove_file(snapshot_path(name)).unwrap();
+
+        let message = *result.unwrap_err().downcast::<String>().unwrap();
+        assert!(message.contains("line endings differ at y=1"));
+    }
+}
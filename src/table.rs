@@ -0,0 +1,74 @@
+/// Plain-text table with left-aligned columns sized to their widest cell.
+#[derive(Debug, Clone, Default)]
+pub struct Table {
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new<S: Into<String>>(headers: impl IntoIterator<Item = S>) -> Table {
+        Table {
+            headers: headers.into_iter().map(Into::into).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn push<S: Into<String>>(&mut self, row: impl IntoIterator<Item = S>) {
+        self.rows.push(row.into_iter().map(Into::into).collect());
+    }
+
+    fn widths(&self) -> Vec<usize> {
+        let mut widths: Vec<usize> = self.headers.iter().map(|h| h.chars().count()).collect();
+        for row in &self.rows {
+            for (i, cell) in row.iter().enumerate() {
+                let width = cell.chars().count();
+                match widths.get_mut(i) {
+                    Some(w) => *w = (*w).max(width),
+                    None => widths.push(width),
+                }
+            }
+        }
+
+        widths
+    }
+}
+
+impl std::fmt::Display for Table {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let widths = self.widths();
+        let write_row = |f: &mut std::fmt::Formatter<'_>, row: &[String]| {
+            let line = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:<width$}"))
+                .collect::<Vec<_>>()
+                .join("  ");
+            writeln!(f, "{}", line.trim_end())
+        };
+
+        write_row(f, &self.headers)?;
+        let separator: Vec<String> = widths.iter().map(|w| "-".repeat(*w)).collect();
+        write_row(f, &separator)?;
+        for row in &self.rows {
+            write_row(f, row)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Table;
+
+    #[test]
+    fn display() {
+        let mut table = Table::new(["Day", "Answer"]);
+        table.push(["1", "1646452"]);
+        table.push(["17", "2,1,4"]);
+        assert_eq!(
+            "Day  Answer\n---  -------\n1    1646452\n17   2,1,4\n",
+            table.to_string()
+        );
+    }
+}
@@ -0,0 +1,119 @@
+//! Structured events that narrate how a solution reached its answer.
+//!
+//! Solutions emit events with [`trace!`](crate::trace!). Nothing is built or stored unless the
+//! current thread is inside [`collect`], so a disabled trace costs one thread-local flag check.
+
+use std::cell::{Cell, RefCell};
+
+thread_local! {
+    static ENABLED: Cell<bool> = const { Cell::new(false) };
+    static EVENTS: RefCell<Vec<Event>> = const { RefCell::new(Vec::new()) };
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Event {
+    pub name: &'static str,
+    pub fields: Vec<(&'static str, String)>,
+}
+
+impl Event {
+    pub fn field(&self, key: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find_map(|(k, value)| (*k == key).then_some(value.as_str()))
+    }
+}
+
+impl std::fmt::Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name)?;
+        for (i, (key, value)) in self.fields.iter().enumerate() {
+            let separator = if i == 0 { ": " } else { " " };
+            match value.contains(char::is_whitespace) {
+                true => write!(f, "{separator}{key}={value:?}")?,
+                false => write!(f, "{separator}{key}={value}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Emits an event when the current thread is collecting, e.g.
+/// `trace!("update reordered", from = pages, middle = middle)`.
+#[macro_export]
+macro_rules! trace {
+    ($name:expr $(, $key:ident = $value:expr)* $(,)?) => {
+        if $crate::trace::is_enabled() {
+            $crate::trace::emit($crate::trace::Event {
+                name: $name,
+                fields: vec![$((stringify!($key), $value.to_string())),*],
+            });
+        }
+    };
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.with(Cell::get)
+}
+
+pub fn emit(event: Event) {
+    EVENTS.with(|events| events.borrow_mut().push(event));
+}
+
+/// Runs `f` with tracing enabled on this thread and returns the events it emitted.
+pub fn collect<T>(f: impl FnOnce() -> T) -> (T, Vec<Event>) {
+    let was_enabled = ENABLED.with(|enabled| enabled.replace(true));
+    let outer = EVENTS.with(|events| events.take());
+
+    let value = f();
+
+    let events = EVENTS.with(|events| events.replace(outer));
+    ENABLED.with(|enabled| enabled.set(was_enabled));
+    (value, events)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{collect, is_enabled, Event};
+
+    #[test]
+    fn disabled_by_default() {
+        assert!(!is_enabled());
+        crate::trace!("never stored", value = 1);
+        let ((), events) = collect(|| {});
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn collects_events() {
+        let ((), events) = collect(|| {
+            crate::trace!("machine solvable", a = 80, b = 40);
+            crate::trace!("equation matched", operators = "81 + 40 * 27");
+            crate::trace!("machine unsolvable");
+        });
+
+        assert_eq!(
+            vec![
+                Event {
+                    name: "machine solvable",
+                    fields: vec![("a", "80".to_owned()), ("b", "40".to_owned())],
+                },
+                Event {
+                    name: "equation matched",
+                    fields: vec![("operators", "81 + 40 * 27".to_owned())],
+                },
+                Event {
+                    name: "machine unsolvable",
+                    fields: vec![],
+                },
+            ],
+            events
+        );
+        assert_eq!("machine solvable: a=80 b=40", events[0].to_string());
+        assert_eq!(
+            "equation matched: operators=\"81 + 40 * 27\"",
+            events[1].to_string()
+        );
+        assert!(!is_enabled());
+    }
+}
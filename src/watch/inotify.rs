@@ -0,0 +1,117 @@
+//! Minimal bindings to Linux inotify, watching directories non-recursively.
+
+use std::{
+    collections::HashMap,
+    ffi::{c_char, c_int, c_short, c_ulong, CString},
+    fs::File,
+    io::{ErrorKind, Read},
+    os::{
+        fd::{AsRawFd, FromRawFd, OwnedFd},
+        unix::ffi::OsStrExt,
+    },
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+const IN_NONBLOCK: c_int = 0o4000;
+const IN_CLOEXEC: c_int = 0o2000000;
+const IN_CLOSE_WRITE: u32 = 0x8;
+const IN_MOVED_TO: u32 = 0x80;
+const IN_CREATE: u32 = 0x100;
+const IN_DELETE: u32 = 0x200;
+const POLLIN: c_short = 0x1;
+
+/// Size of `struct inotify_event` without its trailing name.
+const EVENT_HEADER_LEN: usize = 16;
+
+#[repr(C)]
+struct PollFd {
+    fd: c_int,
+    events: c_short,
+    revents: c_short,
+}
+
+extern "C" {
+    fn inotify_init1(flags: c_int) -> c_int;
+    fn inotify_add_watch(fd: c_int, pathname: *const c_char, mask: u32) -> c_int;
+    fn poll(fds: *mut PollFd, nfds: c_ulong, timeout: c_int) -> c_int;
+}
+
+pub struct Inotify {
+    file: File,
+    /// Watched directory for every watch descriptor.
+    dirs: HashMap<i32, PathBuf>,
+}
+
+impl Inotify {
+    pub fn new() -> std::io::Result<Inotify> {
+        let fd = unsafe { inotify_init1(IN_NONBLOCK | IN_CLOEXEC) };
+        if fd < 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+
+        Ok(Inotify {
+            file: File::from(unsafe { OwnedFd::from_raw_fd(fd) }),
+            dirs: HashMap::new(),
+        })
+    }
+
+    pub fn add_dir(&mut self, dir: &Path) -> std::io::Result<()> {
+        let path = CString::new(dir.as_os_str().as_bytes())
+            .map_err(|err| std::io::Error::new(ErrorKind::InvalidInput, err))?;
+        let mask = IN_CLOSE_WRITE | IN_MOVED_TO | IN_CREATE | IN_DELETE;
+        let wd = unsafe { inotify_add_watch(self.file.as_raw_fd(), path.as_ptr(), mask) };
+        if wd < 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+
+        self.dirs.insert(wd, dir.to_path_buf());
+        Ok(())
+    }
+
+    /// Paths touched within `timeout`; empty when nothing happened.
+    pub fn wait(&mut self, timeout: Duration) -> std::io::Result<Vec<PathBuf>> {
+        let mut fds = PollFd {
+            fd: self.file.as_raw_fd(),
+            events: POLLIN,
+            revents: 0,
+        };
+        let timeout = timeout.as_millis().min(c_int::MAX as u128) as c_int;
+        if unsafe { poll(&mut fds, 1, timeout) } < 0 {
+            let err = std::io::Error::last_os_error();
+            return match err.kind() {
+                ErrorKind::Interrupted => Ok(Vec::new()),
+                _ => Err(err),
+            };
+        }
+
+        let mut paths = Vec::new();
+        let mut buf = [0u8; 4096];
+        loop {
+            let len = match self.file.read(&mut buf) {
+                Ok(len) => len,
+                Err(err) if err.kind() == ErrorKind::WouldBlock => break,
+                Err(err) => return Err(err),
+            };
+
+            let mut offset = 0;
+            while offset + EVENT_HEADER_LEN <= len {
+                let field = |i: usize| {
+                    let start = offset + i * 4;
+                    u32::from_ne_bytes(buf[start..start + 4].try_into().unwrap())
+                };
+                let wd = field(0) as i32;
+                let name_len = field(3) as usize;
+                let name = &buf[offset + EVENT_HEADER_LEN..offset + EVENT_HEADER_LEN + name_len];
+                let name = name.split(|b| *b == 0).next().unwrap_or_default();
+
+                if let Some(dir) = self.dirs.get(&wd) {
+                    paths.push(dir.join(std::ffi::OsStr::from_bytes(name)));
+                }
+                offset += EVENT_HEADER_LEN + name_len;
+            }
+        }
+
+        Ok(paths)
+    }
+}
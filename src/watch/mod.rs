@@ -0,0 +1,514 @@
+//! Re-runs a day's examples and solution whenever its source, input or fixtures change.
+//!
+//! Input and fixture changes are checked in-process. Once a source file changed the running
+//! binary is stale, so checks go through `cargo` in a child process instead.
+
+use crate::{
+    crypt,
+    days::{Day, Input},
+    fixtures,
+    json::{self, Value},
+    runner::{self, Limits},
+};
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+    time::Duration,
+};
+
+#[cfg(target_os = "linux")]
+mod inotify;
+mod polling;
+
+/// How long to keep collecting events after the first one, so one save is one run.
+const DEBOUNCE: Duration = Duration::from_millis(150);
+
+enum Backend {
+    #[cfg(target_os = "linux")]
+    Inotify(inotify::Inotify),
+    Polling(polling::Polling),
+}
+
+pub struct Watcher {
+    roots: Vec<PathBuf>,
+    backend: Backend,
+}
+
+impl Watcher {
+    /// Uses inotify where available and falls back to polling otherwise.
+    pub fn new(roots: Vec<PathBuf>, force_polling: bool) -> Watcher {
+        #[cfg(target_os = "linux")]
+        if !force_polling {
+            if let Ok(inotify) = watch_dirs(&roots) {
+                return Watcher {
+                    roots,
+                    backend: Backend::Inotify(inotify),
+                };
+            }
+        }
+
+        let _ = force_polling;
+        Watcher {
+            backend: Backend::Polling(polling::Polling::new(roots.clone())),
+            roots,
+        }
+    }
+
+    pub fn backend_name(&self) -> &'static str {
+        match self.backend {
+            #[cfg(target_os = "linux")]
+            Backend::Inotify(_) => "inotify",
+            Backend::Polling(_) => "polling",
+        }
+    }
+
+    /// Blocks until something under the roots changes and returns what did.
+    pub fn wait(&mut self) -> std::io::Result<Vec<PathBuf>> {
+        loop {
+            let mut changed = self.wait_timeout(Duration::from_secs(3600))?;
+            if changed.is_empty() {
+                continue;
+            }
+
+            changed.extend(self.wait_timeout(DEBOUNCE)?);
+            changed.sort();
+            changed.dedup();
+            return Ok(changed);
+        }
+    }
+
+    fn wait_timeout(&mut self, timeout: Duration) -> std::io::Result<Vec<PathBuf>> {
+        let paths = match &mut self.backend {
+            #[cfg(target_os = "linux")]
+            Backend::Inotify(inotify) => inotify.wait(timeout)?,
+            Backend::Polling(polling) => polling.wait(timeout),
+        };
+
+        Ok(paths
+            .into_iter()
+            .filter(|path| is_watched(&self.roots, path))
+            .collect())
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn watch_dirs(roots: &[PathBuf]) -> std::io::Result<inotify::Inotify> {
+    fn add_recursive(inotify: &mut inotify::Inotify, dir: &Path) -> std::io::Result<()> {
+        inotify.add_dir(dir)?;
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                add_recursive(inotify, &path)?;
+            }
+        }
+        Ok(())
+    }
+
+    let mut inotify = inotify::Inotify::new()?;
+    for root in roots {
+        match root.is_dir() {
+            true => add_recursive(&mut inotify, root)?,
+            false => {
+                if let Some(parent) = root.parent().filter(|parent| parent.is_dir()) {
+                    inotify.add_dir(parent)?;
+                }
+            }
+        }
+    }
+
+    Ok(inotify)
+}
+
+/// Whether `path` is under one of the roots, skipping editor backups and hidden swap files.
+fn is_watched(roots: &[PathBuf], path: &Path) -> bool {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    if name.starts_with('.') || name.ends_with('~') {
+        return false;
+    }
+
+    roots.iter().any(|root| path.starts_with(root))
+}
+
+/// The day's source directory, its input file (plain or encrypted) and its fixtures.
+pub fn paths(day: &Day) -> Vec<PathBuf> {
+    let mut paths = vec![PathBuf::from(format!("./src/days/day{:02}", day.number))];
+    if let Input::File(path) = day.input {
+        paths.push(PathBuf::from(path));
+        paths.push(crypt::encrypted_path(Path::new(path)));
+    }
+    paths.push(fixtures::dir(day.number));
+    paths
+}
+
+pub fn is_source(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "rs")
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExampleResult {
+    pub fixture: String,
+    pub part: u8,
+    pub expected: String,
+    pub answer: String,
+    pub passed: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartAnswer {
+    pub part: u8,
+    /// The answer, or why there is none.
+    pub answer: String,
+    pub elapsed: Duration,
+}
+
+/// Outcome of one run of a day's examples and solution.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Report {
+    pub examples: Vec<ExampleResult>,
+    pub parts: Vec<PartAnswer>,
+    pub errors: Vec<String>,
+}
+
+/// Checks every fixture and runs the solution in this process.
+pub fn check(day: &Day, limits: Limits) -> Report {
+    let mut report = Report::default();
+
+    match fixtures::load(day.number) {
+        Ok(fixtures) => {
+            for fixture in &fixtures {
+                for check in fixtures::check(day, fixture, limits) {
+                    report.examples.push(ExampleResult {
+                        passed: check.passed(),
+                        fixture: check.fixture,
+                        part: check.part,
+                        expected: check.expected,
+                        answer: check.result.outcome.to_string(),
+                    });
+                }
+            }
+        }
+        Err(err) => report.errors.push(err),
+    }
+
+    match day.load_input() {
+        Ok(input) => {
+            for (n, part) in day.parts() {
+                let result = runner::run_part(part, &input, limits);
+                report.parts.push(PartAnswer {
+                    part: n,
+                    answer: result.outcome.to_string(),
+                    elapsed: result.elapsed,
+                });
+            }
+        }
+        Err(err) => report.errors.push(format!(
+            "Could not read input for day {}: {err}",
+            day.number
+        )),
+    }
+
+    report
+}
+
+/// Rebuilds and runs `watch --once --json` for the day in a child process.
+pub fn check_rebuilt(day: u8, limits: Limits) -> Result<Report, String> {
+    let mut command = Command::new("cargo");
+    command.args(["run", "--release", "-q", "--", "watch", "--once", "--json"]);
+    command.args(["--day", &day.to_string()]);
+    if let Some(time) = limits.time {
+        command.args(["--time-limit", &time.as_secs_f64().to_string()]);
+    }
+    if let Some(steps) = limits.steps {
+        command.args(["--step-limit", &steps.to_string()]);
+    }
+
+    let output = command
+        .output()
+        .map_err(|err| format!("Could not run cargo: {err}"))?;
+    if !output.status.success() {
+        return Err(compiler_errors(&String::from_utf8_lossy(&output.stderr)));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let last_line = stdout.lines().last().unwrap_or_default();
+    json::parse(last_line)
+        .ok()
+        .and_then(|value| Report::from_json(&value))
+        .ok_or_else(|| format!("Unexpected output from the rebuilt binary: {last_line}"))
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TestSummary {
+    pub passed: u32,
+    pub failed: Vec<String>,
+}
+
+/// Runs the day's unit tests through `cargo test`.
+pub fn run_tests(day: u8) -> Result<TestSummary, String> {
+    let output = Command::new("cargo")
+        .args(["test", "-q", "--lib", &format!("days::day{day:02}::")])
+        .output()
+        .map_err(|err| format!("Could not run cargo: {err}"))?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    let summary = parse_test_output(&stdout);
+    if !output.status.success() && summary.failed.is_empty() {
+        return Err(compiler_errors(&String::from_utf8_lossy(&output.stderr)));
+    }
+    Ok(summary)
+}
+
+fn parse_test_output(stdout: &str) -> TestSummary {
+    let mut summary = TestSummary::default();
+    for line in stdout.lines() {
+        if let Some(result) = line.strip_prefix("test result: ") {
+            summary.passed += result
+                .split(';')
+                .find_map(|segment| segment.trim().strip_suffix(" passed"))
+                .and_then(|segment| segment.rsplit(' ').next())
+                .and_then(|n| n.parse::<u32>().ok())
+                .unwrap_or_default();
+        }
+        if let Some(name) = line
+            .strip_prefix("---- ")
+            .and_then(|line| line.strip_suffix(" stdout ----"))
+        {
+            summary.failed.push(name.to_owned());
+        }
+    }
+    summary
+}
+
+/// The compiler's error lines, without the surrounding noise.
+fn compiler_errors(stderr: &str) -> String {
+    let errors: Vec<&str> = stderr
+        .lines()
+        .filter(|line| line.starts_with("error") || line.trim_start().starts_with("-->"))
+        .take(10)
+        .collect();
+    match errors.is_empty() {
+        true => stderr.trim().to_owned(),
+        false => errors.join("\n"),
+    }
+}
+
+impl Report {
+    pub fn to_json(&self) -> Value {
+        Value::Object(vec![
+            (
+                "examples".to_owned(),
+                Value::Array(
+                    self.examples
+                        .iter()
+                        .map(|example| {
+                            Value::Object(vec![
+                                ("fixture".to_owned(), example.fixture.as_str().into()),
+                                ("part".to_owned(), u64::from(example.part).into()),
+                                ("expected".to_owned(), example.expected.as_str().into()),
+                                ("answer".to_owned(), example.answer.as_str().into()),
+                                ("passed".to_owned(), example.passed.into()),
+                            ])
+                        })
+                        .collect(),
+                ),
+            ),
+            (
+                "parts".to_owned(),
+                Value::Array(
+                    self.parts
+                        .iter()
+                        .map(|part| {
+                            Value::Object(vec![
+                                ("part".to_owned(), u64::from(part.part).into()),
+                                ("answer".to_owned(), part.answer.as_str().into()),
+                                (
+                                    "elapsed".to_owned(),
+                                    Value::Number(part.elapsed.as_secs_f64()),
+                                ),
+                            ])
+                        })
+                        .collect(),
+                ),
+            ),
+            (
+                "errors".to_owned(),
+                Value::Array(self.errors.iter().map(|e| e.as_str().into()).collect()),
+            ),
+        ])
+    }
+
+    pub fn from_json(value: &Value) -> Option<Report> {
+        let string = |value: &Value, key: &str| Some(value.get(key)?.as_str()?.to_owned());
+        let part = |value: &Value| u8::try_from(value.get("part")?.as_u64()?).ok();
+
+        let examples = value
+            .get("examples")?
+            .as_array()?
+            .iter()
+            .map(|example| {
+                Some(ExampleResult {
+                    fixture: string(example, "fixture")?,
+                    part: part(example)?,
+                    expected: string(example, "expected")?,
+                    answer: string(example, "answer")?,
+                    passed: matches!(example.get("passed")?, Value::Bool(true)),
+                })
+            })
+            .collect::<Option<_>>()?;
+        let parts = value
+            .get("parts")?
+            .as_array()?
+            .iter()
+            .map(|answer| {
+                Some(PartAnswer {
+                    part: part(answer)?,
+                    answer: string(answer, "answer")?,
+                    elapsed: Duration::try_from_secs_f64(answer.get("elapsed")?.as_f64()?).ok()?,
+                })
+            })
+            .collect::<Option<_>>()?;
+        let errors = value
+            .get("errors")?
+            .as_array()?
+            .iter()
+            .map(|error| Some(error.as_str()?.to_owned()))
+            .collect::<Option<_>>()?;
+
+        Some(Report {
+            examples,
+            parts,
+            errors,
+        })
+    }
+
+    /// Compact pass/fail lines, with answers compared against `previous`.
+    pub fn summary(&self, previous: Option<&Report>) -> String {
+        let mut lines = Vec::new();
+
+        let passed = self.examples.iter().filter(|e| e.passed).count();
+        lines.push(format!("examples: {passed}/{} passed", self.examples.len()));
+        for example in self.examples.iter().filter(|e| !e.passed) {
+            lines.push(format!(
+                "  FAIL {} part {}: expected {}, got {}",
+                example.fixture, example.part, example.expected, example.answer
+            ));
+        }
+
+        for part in &self.parts {
+            let previous = previous
+                .and_then(|report| report.parts.iter().find(|p| p.part == part.part))
+                .map(|p| p.answer.as_str());
+            let change = match previous {
+                None => String::new(),
+                Some(previous) if previous == part.answer => " [unchanged]".to_owned(),
+                Some(previous) => format!(" [was {previous}]"),
+            };
+            lines.push(format!(
+                "Part {}: {} ({:?}){change}",
+                part.part, part.answer, part.elapsed
+            ));
+        }
+
+        lines.extend(self.errors.iter().map(|err| format!("error: {err}")));
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_watched, parse_test_output, ExampleResult, PartAnswer, Report, Watcher};
+    use crate::json;
+    use std::{path::PathBuf, time::Duration};
+
+    fn report(answer: &str, passed: bool) -> Report {
+        Report {
+            examples: vec![ExampleResult {
+                fixture: "example".to_owned(),
+                part: 2,
+                expected: "45".to_owned(),
+                answer: match passed {
+                    true => "45".to_owned(),
+                    false => "44".to_owned(),
+                },
+                passed,
+            }],
+            parts: vec![PartAnswer {
+                part: 2,
+                answer: answer.to_owned(),
+                elapsed: Duration::from_millis(3),
+            }],
+            errors: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn summary() {
+        let first = report("520", false);
+        assert_eq!(
+            "examples: 0/1 passed\n  FAIL example part 2: expected 45, got 44\nPart 2: 520 (3ms)",
+            first.summary(None)
+        );
+        assert_eq!(
+            "examples: 1/1 passed\nPart 2: 523 (3ms) [was 520]",
+            report("523", true).summary(Some(&first))
+        );
+        assert_eq!(
+            "examples: 0/1 passed\n  FAIL example part 2: expected 45, got 44\nPart 2: 520 (3ms) [unchanged]",
+            first.summary(Some(&first))
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let report = report("520", true);
+        let value = json::parse(&report.to_json().to_string()).unwrap();
+        assert_eq!(Some(report), Report::from_json(&value));
+    }
+
+    #[test]
+    fn test_output() {
+        let stdout = "running 3 tests\n..F\nfailures:\n\n---- days::day16::walker::tests::explore_part2 stdout ----\n\ntest result: FAILED. 2 passed; 1 failed; 0 ignored";
+        let summary = parse_test_output(stdout);
+        assert_eq!(2, summary.passed);
+        assert_eq!(
+            vec!["days::day16::walker::tests::explore_part2"],
+            summary.failed
+        );
+    }
+
+    #[test]
+    fn watched_paths() {
+        let roots = [
+            PathBuf::from("./src/days/day16"),
+            PathBuf::from("./files/16.txt"),
+        ];
+        assert!(is_watched(
+            &roots,
+            &PathBuf::from("./src/days/day16/walker.rs")
+        ));
+        assert!(is_watched(&roots, &PathBuf::from("./files/16.txt")));
+        assert!(!is_watched(&roots, &PathBuf::from("./files/15.txt")));
+        assert!(!is_watched(
+            &roots,
+            &PathBuf::from("./src/days/day16/.walker.rs.swp")
+        ));
+    }
+
+    #[test]
+    fn detects_changes() {
+        for force_polling in [false, true] {
+            let dir = std::env::temp_dir()
+                .join(format!("aoc-watch-{}-{force_polling}", std::process::id()));
+            std::fs::create_dir_all(&dir).unwrap();
+            let file = dir.join("input.txt");
+            std::fs::write(&file, "1").unwrap();
+
+            let mut watcher = Watcher::new(vec![file.clone()], force_polling);
+            std::fs::write(&file, "12").unwrap();
+            let changed = watcher.wait_timeout(Duration::from_secs(5)).unwrap();
+            std::fs::remove_dir_all(&dir).unwrap();
+
+            assert_eq!(vec![file], changed, "{}", watcher.backend_name());
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    time::{Duration, Instant, SystemTime},
+};
+
+/// How often files are looked at again.
+const INTERVAL: Duration = Duration::from_millis(300);
+
+/// Watches by comparing modification times and sizes of every file under the roots.
+pub struct Polling {
+    roots: Vec<PathBuf>,
+    seen: HashMap<PathBuf, (Option<SystemTime>, u64)>,
+}
+
+impl Polling {
+    pub fn new(roots: Vec<PathBuf>) -> Polling {
+        let seen = scan(&roots);
+        Polling { roots, seen }
+    }
+
+    /// Paths added, removed or modified within `timeout`; empty when nothing happened.
+    pub fn wait(&mut self, timeout: Duration) -> Vec<PathBuf> {
+        let started = Instant::now();
+        loop {
+            let current = scan(&self.roots);
+            let mut changed: Vec<PathBuf> = current
+                .iter()
+                .filter(|(path, state)| self.seen.get(*path) != Some(state))
+                .map(|(path, _)| path.clone())
+                .chain(
+                    self.seen
+                        .keys()
+                        .filter(|path| !current.contains_key(*path))
+                        .cloned(),
+                )
+                .collect();
+            self.seen = current;
+
+            if !changed.is_empty() || started.elapsed() >= timeout {
+                changed.sort();
+                return changed;
+            }
+            std::thread::sleep(INTERVAL.min(timeout));
+        }
+    }
+}
+
+fn scan(roots: &[PathBuf]) -> HashMap<PathBuf, (Option<SystemTime>, u64)> {
+    let mut files = HashMap::new();
+    roots.iter().for_each(|root| scan_into(root, &mut files));
+    files
+}
+
+fn scan_into(path: &Path, files: &mut HashMap<PathBuf, (Option<SystemTime>, u64)>) {
+    let Ok(metadata) = std::fs::metadata(path) else {
+        return;
+    };
+
+    if metadata.is_dir() {
+        let Ok(entries) = std::fs::read_dir(path) else {
+            return;
+        };
+        entries
+            .flatten()
+            .for_each(|entry| scan_into(&entry.path(), files));
+    } else {
+        files.insert(
+            path.to_path_buf(),
+            (metadata.modified().ok(), metadata.len()),
+        );
+    }
+}